@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 pub const CURVE_CONFIGURATION_SEED: &'static str = "curve_configuration";
 pub const POOL_SEED_PREFIX: &'static str = "bonding_curve";
 pub const SOL_VAULT_PREFIX: &'static str = "liquidity_sol_vault";
@@ -11,7 +13,16 @@ pub const LAUNCHPAD_SEED_PREFIX: &'static str = "launchpad";
 pub const WHITELIST_DATA_SEED_PREFIX: &'static str = "whitelist_data";
 pub const FAIR_LAUNCH_DATA_SEED_PREFIX: &'static str = "fair_launch_data";
 pub const CONTRIBUTION_VAULT_SEED_PREFIX: &'static str = "fair_launch_vault";
+pub const DUTCH_AUCTION_DATA_SEED_PREFIX: &'static str = "dutch_auction_data";
+pub const BATCH_AUCTION_DATA_SEED_PREFIX: &'static str = "batch_auction_data";
+pub const BATCH_BID_SEED_PREFIX: &'static str = "batch_bid";
+pub const WHITELIST_VAULT_SEED_PREFIX: &'static str = "whitelist_vault";
 pub const BUYER_SEED_PREFIX: &'static str = "buyer";
+pub const MAX_SALE_ROUNDS: usize = 4;
+pub const BATCH_AUCTION_PRICE_TICKS: usize = 64;
+pub const BATCH_AUCTION_FINALIZE_WINDOW: i64 = 7 * 24 * 60 * 60; // bids can be refunded when nobody finalized the auction in this time after its end
+pub const MAX_MILESTONES: usize = 8;
+pub const TOKENOMICS_PLAN_SEED_PREFIX: &'static str = "tokenomics_plan";
 
 pub const METEORA_PROGRAM_KEY: &str = "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB";
 pub const METEORA_VAULT_PROGRAM_KEY: &str = "24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi";
@@ -19,3 +30,13 @@ pub const METEORA_VAULT_PROGRAM_KEY: &str = "24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG
 pub const QUOTE_TOKEN_MINT: &str = "So11111111111111111111111111111111111111112";
 
 pub const PUMP_SWAP_PROGRAM_KEY: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+
+pub const METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+pub const METADATA_SEED_PREFIX: &str = "metadata";
+
+// Platform & fee constants
+pub const PLATFORM_CONFIG_SEED: &str = "platform_config";
+pub const REFERRAL_SEED_PREFIX: &str = "referral";
+
+// Maximum number of active fee recipients on a pool
+pub const MAX_FEE_RECIPIENTS: usize = 10;
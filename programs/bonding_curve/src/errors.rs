@@ -68,6 +68,51 @@ pub enum CommonCustomError {
     #[msg("Not enough SOL in vault for rent-exempt")]
     NotEnoughSolInVaultRentExempt,
 
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+
+    #[msg("Fees are still locked")]
+    FeesLocked,
+
+    #[msg("No fees to claim")]
+    NoFeesToClaim,
+
+    #[msg("Buyback fees can't be claimed")]
+    BuybackNotClaimable,
+
+    #[msg("Only one buyback recipient is allowed")]
+    MultipleBuybackRecipients,
+
+    #[msg("Pool has no buyback recipient")]
+    NoBuybackRecipient,
+
+    #[msg("Buybacks aren't supported on this DEX")]
+    BuybackNotSupported,
+
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+
+    #[msg("Invalid migration pool")]
+    InvalidMigrationPool,
+
+    #[msg("Too many fee recipients")]
+    TooManyFeeRecipients,
+
+    #[msg("Can't remove the last fee recipient")]
+    LastFeeRecipient,
+
+    #[msg("Pool is paused")]
+    PoolPaused,
+
+    #[msg("Pool is not paused")]
+    PoolNotPaused,
+
+    #[msg("Trading is halted on the platform")]
+    TradingHalted,
+
+    #[msg("Invalid token metadata")]
+    InvalidTokenMetadata,
+
 
 }
 
@@ -103,6 +148,9 @@ pub enum LaunchPadCustomErrror {
     #[msg("Soft cap not reached")]
     SoftCapNotReached,
 
+    #[msg("Soft cap reached, contributions are not refundable")]
+    SoftCapReached,
+
     #[msg("Distribution delay not reached")]
     DistributionDelayNotReached,
 
@@ -121,6 +169,102 @@ pub enum LaunchPadCustomErrror {
     #[msg("Launchpad is paused")]
     LaunchpadPaused,
 
+    #[msg("User is not whitelisted")]
+    NotWhitelisted,
+
+    #[msg("Purchase limit per wallet exceeded")]
+    PurchaseLimitExceeded,
+
+    #[msg("Not enough tokens left for sale")]
+    InsufficientTokensForSale,
+
+    #[msg("Sale has not ended yet")]
+    SaleNotEnded,
+
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    #[msg("Invalid merkle proof")]
+    InvalidMerkleProof,
+
+    #[msg("No sale round is active")]
+    NoActiveRound,
+
+    #[msg("Invalid sale rounds")]
+    InvalidSaleRounds,
+
+    #[msg("Sale has already started")]
+    SaleAlreadyStarted,
+
+    #[msg("Launchpad vault doesn't hold the tokens for sale yet")]
+    LaunchpadNotFunded,
+
+    #[msg("Fair launch has not been finalized")]
+    NotFinalized,
+
+    #[msg("Fair launch already finalized")]
+    AlreadyFinalized,
+
+    #[msg("Tokens already claimed")]
+    AlreadyClaimed,
+
+    #[msg("Liquidity accounts are required to seed the bonding curve")]
+    MissingLiquidityAccounts,
+
+    #[msg("Invalid auction price")]
+    InvalidAuctionPrice,
+
+    #[msg("Auction already settled")]
+    AuctionSettled,
+
+    #[msg("Auction not settled")]
+    AuctionNotSettled,
+
+    #[msg("Proceeds already withdrawn")]
+    ProceedsAlreadyWithdrawn,
+
+    #[msg("Batch auction was not finalized in time")]
+    AuctionExpired,
+
+    #[msg("Batch auction can still be finalized")]
+    FinalizationWindowOpen,
+
+    #[msg("Batch auction not finalized")]
+    AuctionNotFinalized,
+
+    #[msg("Launch has been cancelled")]
+    LaunchCancelled,
+
+    #[msg("Launch has not been cancelled")]
+    LaunchNotCancelled,
+
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+
+    #[msg("Allocation is not revocable")]
+    AllocationNotRevocable,
+
+    #[msg("Allocation already revoked")]
+    AllocationAlreadyRevoked,
+
+    #[msg("Invalid milestones")]
+    InvalidMilestones,
+
+    #[msg("Milestone already achieved")]
+    MilestoneAlreadyAchieved,
+
+    #[msg("Invalid tokenomics plan")]
+    InvalidTokenomicsPlan,
+
+    #[msg("Category is not in the tokenomics plan")]
+    CategoryNotInPlan,
+
+    #[msg("Allocation exceeds the category budget")]
+    CategoryBudgetExceeded,
+
+    #[msg("Allocation is not transferable")]
+    AllocationNotTransferable,
+
 }
 
 
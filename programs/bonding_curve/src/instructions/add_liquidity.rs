@@ -1,6 +1,6 @@
 use crate::consts::*;
 use crate::errors::CommonCustomError;
-use crate::state::{BondingCurve, BondingCurveAccount, CurveConfiguration};
+use crate::state::{BondingCurve, BondingCurveAccount, CurveConfiguration, PlatformConfig};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -11,6 +11,7 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>, sol_amount: u64, token_amount:
     let bonding_curve = &mut ctx.accounts.bonding_curve_account;
     let bonding_curve_configuration = &ctx.accounts.bonding_curve_configuration;
     let user = &ctx.accounts.user;
+    bonding_curve_configuration.require_not_paused(&ctx.accounts.platform_config)?;
     // check if the user is the creator of the pool
     if bonding_curve.creator != user.key() {
         return Err(CommonCustomError::InvalidAuthority.into());
@@ -73,6 +74,12 @@ pub struct AddLiquidity<'info> {
     )]
     pub pool_sol_vault: AccountInfo<'info>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     #[account(mut,
         associated_token::mint = token_mint,
         associated_token::authority = user,
@@ -8,6 +8,8 @@ pub fn add_fee_recipients(ctx: Context<AddFeeRecipient>, recipients: Vec<Recipie
     let bonding_curve_configuration = &mut ctx.accounts.bonding_curve_configuration;
 
     bonding_curve_configuration.add_fee_recipients(recipients)?;
+    bonding_curve_configuration
+        .realloc_for_recipients(&ctx.accounts.fee_admin, &ctx.accounts.system_program)?;
     Ok(())
 }
 
@@ -18,4 +20,5 @@ pub struct AddFeeRecipient<'info> {
 
     #[account(mut)]
     pub fee_admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
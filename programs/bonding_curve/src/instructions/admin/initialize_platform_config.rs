@@ -0,0 +1,52 @@
+use crate::consts::PLATFORM_CONFIG_SEED;
+use crate::errors::CommonCustomError;
+use crate::state::PlatformConfig;
+use anchor_lang::prelude::*;
+
+pub fn initialize_platform_config(
+    ctx: Context<InitializePlatformConfig>,
+    treasury: Pubkey,
+    protocol_fee_bps: u16,
+    referrer_fee_bps: u16,
+) -> Result<()> {
+    msg!("Initializing platform config");
+
+    ctx.accounts.platform_config.set_inner(PlatformConfig::new(
+        ctx.accounts.admin.key(),
+        treasury,
+        protocol_fee_bps,
+        referrer_fee_bps,
+        ctx.bumps.platform_config,
+    )?);
+
+    msg!("Protocol fee: {} bps", protocol_fee_bps);
+    msg!("Referrer fee: {} bps", referrer_fee_bps);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(
+        init,
+        space = PlatformConfig::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    // Only the program upgrade authority can set up the platform
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ CommonCustomError::OnlyAdmin,
+    )]
+    pub program: Program<'info, crate::program::BondingCurve>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ CommonCustomError::OnlyAdmin,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,8 +1,38 @@
 pub mod add_recipient_fee;
 pub use add_recipient_fee::*;
 
+pub mod remove_fee_recipient;
+pub use remove_fee_recipient::*;
+
+pub mod update_recipient_share;
+pub use update_recipient_share::*;
+
 pub mod change_fee_admin;
 pub use change_fee_admin::*;
 
 pub mod set_target_liquidity;
 pub use set_target_liquidity::*;
+
+pub mod set_creator_fee;
+pub use set_creator_fee::*;
+
+pub mod initialize_platform_config;
+pub use initialize_platform_config::*;
+
+pub mod update_platform_fees;
+pub use update_platform_fees::*;
+
+pub mod set_pauser;
+pub use set_pauser::*;
+
+pub mod pause_pool;
+pub use pause_pool::*;
+
+pub mod unpause_pool;
+pub use unpause_pool::*;
+
+pub mod set_kill_switch;
+pub use set_kill_switch::*;
+
+pub mod set_launch_fee;
+pub use set_launch_fee::*;
@@ -0,0 +1,25 @@
+use crate::errors::CommonCustomError;
+use crate::state::{CurveConfiguration, CurveConfigurationAccount};
+use anchor_lang::prelude::*;
+
+pub fn remove_fee_recipient(ctx: Context<RemoveFeeRecipient>, recipient: Pubkey) -> Result<()> {
+    msg!("Trying to remove fee recipient {}", recipient);
+
+    let bonding_curve_configuration = &mut ctx.accounts.bonding_curve_configuration;
+
+    // the removed share is spread over the remaining recipients, unclaimed fees stay claimable
+    bonding_curve_configuration.remove_fee_recipient(recipient)?;
+    bonding_curve_configuration
+        .realloc_for_recipients(&ctx.accounts.fee_admin, &ctx.accounts.system_program)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveFeeRecipient<'info> {
+    #[account(mut, has_one = fee_admin @ CommonCustomError::InvalidAuthority)]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(mut)]
+    pub fee_admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+use crate::consts::PLATFORM_CONFIG_SEED;
+use crate::errors::CommonCustomError;
+use crate::state::{CurveConfiguration, CurveConfigurationAccount, PlatformConfig};
+use anchor_lang::prelude::*;
+
+pub fn set_creator_fee(ctx: Context<SetCreatorFee>, creator_fee_bps: u16) -> Result<()> {
+    msg!("Setting creator fee to: {} bps", creator_fee_bps);
+
+    let bonding_curve_configuration = &mut ctx.accounts.bonding_curve_configuration;
+
+    bonding_curve_configuration.set_creator_fee(creator_fee_bps)?;
+    bonding_curve_configuration.validate_fee_layers(&ctx.accounts.platform_config)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCreatorFee<'info> {
+    #[account(mut, has_one = fee_admin @ CommonCustomError::InvalidAuthority)]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(mut)]
+    pub fee_admin: Signer<'info>,
+}
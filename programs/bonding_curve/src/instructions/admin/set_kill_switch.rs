@@ -0,0 +1,25 @@
+use crate::consts::PLATFORM_CONFIG_SEED;
+use crate::errors::CommonCustomError;
+use crate::state::PlatformConfig;
+use anchor_lang::prelude::*;
+
+pub fn set_kill_switch(ctx: Context<SetKillSwitch>, halted: bool) -> Result<()> {
+    // claims and refunds keep working, only trading, liquidity and migration are blocked
+    ctx.accounts.platform_config.trading_halted = halted;
+
+    msg!("Platform trading halted: {}", halted);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetKillSwitch<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+        has_one = pauser @ CommonCustomError::InvalidAuthority,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub pauser: Signer<'info>,
+}
@@ -0,0 +1,26 @@
+use crate::consts::PLATFORM_CONFIG_SEED;
+use crate::errors::CommonCustomError;
+use crate::state::PlatformConfig;
+use anchor_lang::prelude::*;
+
+pub fn set_launch_fee(ctx: Context<SetLaunchFee>, launch_fee_bps: u16) -> Result<()> {
+    require!(launch_fee_bps <= 10000, CommonCustomError::InvalidFee);
+
+    ctx.accounts.platform_config.launch_fee_bps = launch_fee_bps;
+
+    msg!("Launch fee set to: {} bps", launch_fee_bps);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLaunchFee<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+        has_one = admin @ CommonCustomError::InvalidAuthority,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,24 @@
+use crate::consts::PLATFORM_CONFIG_SEED;
+use crate::errors::CommonCustomError;
+use crate::state::PlatformConfig;
+use anchor_lang::prelude::*;
+
+pub fn set_pauser(ctx: Context<SetPauser>, new_pauser: Pubkey) -> Result<()> {
+    ctx.accounts.platform_config.pauser = new_pauser;
+
+    msg!("Pauser set to: {}", new_pauser);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPauser<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+        has_one = admin @ CommonCustomError::InvalidAuthority,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub admin: Signer<'info>,
+}
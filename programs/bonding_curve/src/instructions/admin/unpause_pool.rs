@@ -0,0 +1,33 @@
+use crate::consts::{CURVE_CONFIGURATION_SEED, PLATFORM_CONFIG_SEED};
+use crate::errors::CommonCustomError;
+use crate::state::{CurveConfiguration, CurveConfigurationAccount, PlatformConfig};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+pub fn unpause_pool(ctx: Context<UnpausePool>) -> Result<()> {
+    ctx.accounts.bonding_curve_configuration.set_paused(false)?;
+
+    msg!("Pool {} unpaused", ctx.accounts.token_mint.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnpausePool<'info> {
+    #[account(
+        mut,
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+        has_one = pauser @ CommonCustomError::InvalidAuthority,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub pauser: Signer<'info>,
+}
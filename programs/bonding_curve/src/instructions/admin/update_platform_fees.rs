@@ -0,0 +1,37 @@
+use crate::consts::PLATFORM_CONFIG_SEED;
+use crate::errors::CommonCustomError;
+use crate::state::PlatformConfig;
+use anchor_lang::prelude::*;
+
+pub fn update_platform_fees(
+    ctx: Context<UpdatePlatformFees>,
+    treasury: Pubkey,
+    protocol_fee_bps: u16,
+    referrer_fee_bps: u16,
+) -> Result<()> {
+    msg!("Updating platform fees");
+
+    PlatformConfig::validate_fees(protocol_fee_bps, referrer_fee_bps)?;
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    platform_config.treasury = treasury;
+    platform_config.protocol_fee_bps = protocol_fee_bps;
+    platform_config.referrer_fee_bps = referrer_fee_bps;
+
+    msg!("Protocol fee: {} bps", protocol_fee_bps);
+    msg!("Referrer fee: {} bps", referrer_fee_bps);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformFees<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+        has_one = admin @ CommonCustomError::InvalidAuthority,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,24 @@
+use crate::errors::CommonCustomError;
+use crate::state::{CurveConfiguration, CurveConfigurationAccount, RecipientShare};
+use anchor_lang::prelude::*;
+
+pub fn update_recipient_share(
+    ctx: Context<UpdateRecipientShare>,
+    shares: Vec<RecipientShare>,
+) -> Result<()> {
+    msg!("Trying to update fee recipient shares");
+
+    // shares must still add up to 100% once every update is applied
+    ctx.accounts
+        .bonding_curve_configuration
+        .update_recipient_shares(shares)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateRecipientShare<'info> {
+    #[account(mut, has_one = fee_admin @ CommonCustomError::InvalidAuthority)]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    pub fee_admin: Signer<'info>,
+}
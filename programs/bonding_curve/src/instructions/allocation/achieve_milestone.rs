@@ -0,0 +1,62 @@
+use crate::{
+    consts::CURVE_CONFIGURATION_SEED,
+    errors::{CommonCustomError, LaunchPadCustomErrror},
+    state::{allocation::Allocation, CurveConfiguration},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct AchieveMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"allocation", allocation.wallet.as_ref(), token_mint.key().as_ref()],
+        bump = allocation.bump,
+    )]
+    pub allocation: Account<'info, Allocation>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    // Token DAO, a governance proposal signs as `governance`
+    #[account(
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub curve_configuration: Option<Box<Account<'info, CurveConfiguration>>>,
+    // Milestone oracle or DAO governance
+    pub approver: Signer<'info>,
+}
+
+pub fn achieve_milestone(ctx: Context<AchieveMilestone>, index: u8) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let is_governance = ctx
+        .accounts
+        .curve_configuration
+        .as_ref()
+        .is_some_and(|curve_configuration| curve_configuration.governance == approver);
+    let allocation = &mut ctx.accounts.allocation;
+
+    if approver != allocation.milestone_oracle && !is_governance {
+        return Err(CommonCustomError::InvalidAuthority.into());
+    }
+
+    // Vesting is frozen once revoked
+    if allocation.revoked_at.is_some() {
+        return Err(LaunchPadCustomErrror::AllocationAlreadyRevoked.into());
+    }
+
+    let milestone = allocation
+        .milestones
+        .get_mut(index as usize)
+        .ok_or(LaunchPadCustomErrror::InvalidMilestones)?;
+
+    if milestone.achieved {
+        return Err(LaunchPadCustomErrror::MilestoneAlreadyAchieved.into());
+    }
+
+    milestone.achieved = true;
+    let unlock_bps = milestone.unlock_bps;
+
+    msg!("Milestone {} achieved for {}", index, allocation.wallet);
+    msg!("Unlocked: {} bps", unlock_bps);
+
+    Ok(())
+}
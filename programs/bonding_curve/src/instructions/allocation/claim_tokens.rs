@@ -1,6 +1,7 @@
 use crate::{
-    errors::LaunchPadCustomErrror,
-    state::allocation::{Allocation, Vesting},
+    consts::TOKENOMICS_PLAN_SEED_PREFIX,
+    errors::{CommonCustomError, LaunchPadCustomErrror},
+    state::allocation::{Allocation, TokenomicsPlan},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -10,14 +11,34 @@ use anchor_spl::{
 
 #[derive(Accounts)]
 pub struct ClaimTokens<'info> {
-    #[account(mut, has_one = wallet,seeds = [b"allocation", wallet.key().as_ref(), token_mint.key().as_ref()], bump)]
+    #[account(
+        mut,
+        has_one = wallet,
+        seeds = [b"allocation", wallet.key().as_ref(), token_mint.key().as_ref()],
+        bump = allocation.bump,
+    )]
     pub allocation: Account<'info, Allocation>,
+    #[account(
+        seeds = [TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = tokenomics_plan.bump,
+    )]
+    pub tokenomics_plan: Account<'info, TokenomicsPlan>,
     /// CHECK: This is the recipient wallet
     #[account(mut)]
     pub wallet: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        constraint = allocation_vault.owner == allocation.vault_owner(allocation.key(), tokenomics_plan.key()) @ CommonCustomError::InvalidAuthority,
+    )]
     pub allocation_vault: InterfaceAccount<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = wallet,
+    )]
     pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub token_mint: InterfaceAccount<'info, Mint>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -25,40 +46,49 @@ pub struct ClaimTokens<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn claim_tokens(ctx: Context<ClaimTokens>, now: i64) -> Result<u64> {
+pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<u64> {
     let allocation = &mut ctx.accounts.allocation;
-    let claimable: u64;
-    let total_tokens = allocation.total_tokens;
-    if let Some(ref mut vesting) = allocation.vesting {
-        // Cliff check
-        if now < vesting.start_time + vesting.cliff_period {
+    let now = Clock::get()?.unix_timestamp;
+
+    // TGE share, then linear vesting stepped by interval, capped at total_tokens
+    let claimable = allocation.claimable(now)?;
+
+    // Cliff check, a revoked allocation just has nothing more to claim
+    if let Some(vesting) = &allocation.vesting {
+        if claimable == 0 && allocation.revoked_at.is_none() && !vesting.cliff_reached(now) {
             return Err(LaunchPadCustomErrror::CliffNotReached.into()); // Cliff not reached
         }
-        // Linear vesting
-        let elapsed = now.saturating_sub(vesting.start_time + vesting.cliff_period);
-        let total_vesting_time = vesting.duration;
+    }
+
+    allocation.claimed_tokens = allocation
+        .claimed_tokens
+        .checked_add(claimable)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
 
-        let vested = if elapsed >= total_vesting_time {
-            total_tokens
+    // CPI: Transfer claimable tokens from allocation_vault to user_token_account,
+    // signed by the tokenomics plan for a shared vault
+    if claimable > 0 {
+        let wallet_key = ctx.accounts.wallet.key();
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let allocation_bump = [allocation.bump];
+        let plan_bump = [ctx.accounts.tokenomics_plan.bump];
+        let allocation_seeds: &[&[u8]] = &[
+            b"allocation".as_ref(),
+            wallet_key.as_ref(),
+            token_mint_key.as_ref(),
+            &allocation_bump,
+        ];
+        let plan_seeds: &[&[u8]] = &[
+            TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(),
+            token_mint_key.as_ref(),
+            &plan_bump,
+        ];
+        let (vault_authority, signer_seeds) = if allocation.shared_vault {
+            (ctx.accounts.tokenomics_plan.to_account_info(), plan_seeds)
         } else {
-            total_tokens
-                .saturating_mul(elapsed as u64)
-                .checked_div(total_vesting_time as u64)
-                .unwrap_or(0)
+            (allocation.to_account_info(), allocation_seeds)
         };
-        claimable = vested.saturating_sub(vesting.released);
-        vesting.released = vesting.released.saturating_add(claimable);
-    } else {
-        // No vesting, all tokens claimable
-        claimable = allocation
-            .total_tokens
-            .saturating_sub(allocation.claimed_tokens);
-        allocation.claimed_tokens = allocation.total_tokens;
-    }
-    allocation.claimed_tokens = allocation.claimed_tokens.saturating_add(claimable);
 
-    // CPI: Transfer claimable tokens from allocation_vault to user_token_account
-    if claimable > 0 {
         transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
@@ -66,17 +96,17 @@ pub fn claim_tokens(ctx: Context<ClaimTokens>, now: i64) -> Result<u64> {
                     from: ctx.accounts.allocation_vault.to_account_info(),
                     mint: ctx.accounts.token_mint.to_account_info(),
                     to: ctx.accounts.user_token_account.to_account_info(),
-                    authority: ctx.accounts.allocation.to_account_info(),
+                    authority: vault_authority,
                 },
-                &[&[
-                    b"allocation",
-                    ctx.accounts.wallet.key().as_ref(),
-                    &[ctx.bumps.allocation],
-                ]],
+                &[signer_seeds],
             ),
             claimable,
             ctx.accounts.token_mint.decimals,
         )?;
     }
+
+    msg!("Allocation claimed: {}", claimable);
+    msg!("Claimed so far: {} / {}", allocation.claimed_tokens, allocation.total_tokens);
+
     Ok(claimable)
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::allocation::{Allocation, Vesting};
+use crate::consts::TOKENOMICS_PLAN_SEED_PREFIX;
+use crate::errors::{CommonCustomError, LaunchPadCustomErrror};
+use crate::state::allocation::{Allocation, AllocationCategory, TokenomicsPlan, Vesting};
 use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
 
 
@@ -14,6 +16,14 @@ pub struct CreateAllocation<'info> {
     )]
     pub allocation: Account<'info, Allocation>,
 
+    #[account(
+        mut,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+        seeds = [TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = tokenomics_plan.bump,
+    )]
+    pub tokenomics_plan: Account<'info, TokenomicsPlan>,
+
     #[account(
         mint::token_program = token_program
     )]
@@ -29,7 +39,7 @@ pub struct CreateAllocation<'info> {
     pub allocation_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
-    pub authority: Signer<'info>, // Only admin/authority can create allocations
+    pub authority: Signer<'info>, // Only the tokenomics plan authority can create allocations
     /// CHECK: This is the recipient wallet
     pub wallet: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
@@ -42,10 +52,26 @@ pub struct CreateAllocation<'info> {
 
 pub fn create_allocation(
     ctx: Context<CreateAllocation>,
-    percentage: u8,
+    category: AllocationCategory,
     total_tokens: u64,
     vesting: Option<Vesting>,
+    revocable: bool,
 ) -> Result<()> {
+    if let Some(vesting) = &vesting {
+        if !vesting.is_valid() {
+            return Err(LaunchPadCustomErrror::InvalidVestingSchedule.into());
+        }
+    }
+
+    if total_tokens == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // The allocation must fit within its category budget
+    let tokenomics_plan = &mut ctx.accounts.tokenomics_plan;
+    tokenomics_plan.allocate(category, total_tokens)?;
+    let percentage = (total_tokens as u128 * 100 / tokenomics_plan.total_supply as u128) as u8;
+
     let allocation = &mut ctx.accounts.allocation;
     allocation.wallet = ctx.accounts.wallet.key();
     allocation.percentage = percentage;
@@ -53,5 +79,16 @@ pub fn create_allocation(
     allocation.claimed_tokens = 0;
     allocation.vesting = vesting;
     allocation.bump = ctx.bumps.allocation;
+    allocation.authority = ctx.accounts.authority.key();
+    allocation.revocable = revocable;
+    allocation.revoked_at = None;
+    allocation.milestone_oracle = ctx.accounts.authority.key();
+    allocation.milestones = vec![];
+    allocation.category = category;
+    allocation.shared_vault = false;
+    allocation.funded_tokens = 0;
+    allocation.transferable = false;
+
+    msg!("Allocation of {} {:?} tokens created for {}", total_tokens, category, allocation.wallet);
     Ok(())
 } 
\ No newline at end of file
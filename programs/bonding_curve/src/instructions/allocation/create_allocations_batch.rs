@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use crate::consts::TOKENOMICS_PLAN_SEED_PREFIX;
+use crate::errors::{CommonCustomError, LaunchPadCustomErrror};
+use crate::state::allocation::{Allocation, AllocationCategory, AllocationEntry, TokenomicsPlan};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct CreateAllocationsBatch<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+        seeds = [TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = tokenomics_plan.bump,
+    )]
+    pub tokenomics_plan: Box<Account<'info, TokenomicsPlan>>,
+
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // One vault owned by the tokenomics plan for every batch allocation of the mint
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = tokenomics_plan,
+        payer = authority,
+    )]
+    pub shared_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Funds every allocation of the batch when provided
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>, // Only the tokenomics plan authority can create allocations
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Create one allocation per entry in the plan shared vault, the allocation PDAs are passed as
+/// writable remaining accounts in the same order as the entries. Every allocation is funded
+/// in a single transfer when the authority token account is provided
+pub fn create_allocations_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateAllocationsBatch<'info>>,
+    category: AllocationCategory,
+    entries: Vec<AllocationEntry>,
+    revocable: bool,
+) -> Result<()> {
+    if entries.is_empty() || entries.len() != ctx.remaining_accounts.len() {
+        return Err(LaunchPadCustomErrror::InvalidAccountRelationship.into());
+    }
+
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let authority_key = ctx.accounts.authority.key();
+    let funded = ctx.accounts.authority_token_account.is_some();
+    let space = 8 + Allocation::INIT_SPACE;
+    let lamports = Rent::get()?.minimum_balance(space);
+    let mut batch_tokens: u64 = 0;
+
+    for (entry, account) in entries.iter().zip(ctx.remaining_accounts.iter()) {
+        if let Some(vesting) = &entry.vesting {
+            if !vesting.is_valid() {
+                return Err(LaunchPadCustomErrror::InvalidVestingSchedule.into());
+            }
+        }
+
+        if entry.total_tokens == 0 {
+            return Err(CommonCustomError::InvalidAmount.into());
+        }
+
+        let (allocation_key, bump) = Pubkey::find_program_address(
+            &[b"allocation", entry.wallet.as_ref(), token_mint_key.as_ref()],
+            &crate::ID,
+        );
+        if account.key() != allocation_key {
+            return Err(LaunchPadCustomErrror::InvalidAccountRelationship.into());
+        }
+
+        // The allocation must fit within its category budget
+        let tokenomics_plan = &mut ctx.accounts.tokenomics_plan;
+        tokenomics_plan.allocate(category, entry.total_tokens)?;
+        let percentage = (entry.total_tokens as u128 * 100 / tokenomics_plan.total_supply as u128) as u8;
+
+        create_allocation_account(
+            &ctx.accounts.authority,
+            account,
+            &ctx.accounts.system_program,
+            &[b"allocation", entry.wallet.as_ref(), token_mint_key.as_ref(), &[bump]],
+            lamports,
+            space,
+        )?;
+
+        let allocation = Allocation {
+            wallet: entry.wallet,
+            percentage,
+            total_tokens: entry.total_tokens,
+            claimed_tokens: 0,
+            vesting: entry.vesting.clone(),
+            bump,
+            authority: authority_key,
+            revocable,
+            revoked_at: None,
+            milestone_oracle: authority_key,
+            milestones: vec![],
+            category,
+            shared_vault: true,
+            funded_tokens: if funded { entry.total_tokens } else { 0 },
+            transferable: false,
+        };
+        allocation.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+        batch_tokens = batch_tokens
+            .checked_add(entry.total_tokens)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+        msg!("Allocation of {} {:?} tokens created for {}", entry.total_tokens, category, entry.wallet);
+    }
+
+    if let Some(authority_token_account) = &ctx.accounts.authority_token_account {
+        ctx.accounts.tokenomics_plan.fund(category, batch_tokens)?;
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: authority_token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.shared_vault.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            batch_tokens,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    msg!("Batch of {} allocations created", entries.len());
+    msg!("Tokens allocated: {}, funded: {}", batch_tokens, funded);
+
+    Ok(())
+}
+
+/// Create the allocation PDA like Anchor `init` does: a plain `create_account` when it is empty,
+/// otherwise top up the rent and allocate and assign it, so lamports sent to the address beforehand can't block the batch
+fn create_allocation_account<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+    lamports: u64,
+    space: usize,
+) -> Result<()> {
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = lamports.saturating_sub(current_lamports);
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+
+    assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use crate::consts::{POOL_SEED_PREFIX, TOKENOMICS_PLAN_SEED_PREFIX};
+use crate::errors::{CommonCustomError, LaunchPadCustomErrror};
+use crate::state::allocation::{CategoryBudget, CategoryShare, TokenomicsPlan};
+use crate::state::BondingCurve;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CreateTokenomicsPlan<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TokenomicsPlan::INIT_SPACE,
+        seeds = [TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub tokenomics_plan: Account<'info, TokenomicsPlan>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Curve of the mint, its creator stands in for a revoked mint authority only
+    #[account(
+        seeds = [POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = bonding_curve_account.bump,
+    )]
+    pub bonding_curve_account: Option<Box<Account<'info, BondingCurve>>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn create_tokenomics_plan(
+    ctx: Context<CreateTokenomicsPlan>,
+    total_supply: u64,
+    categories: Vec<CategoryShare>,
+) -> Result<()> {
+    // Only the token creator can plan its tokenomics: the mint authority while there is one.
+    // Once it is revoked (launch_token does) the curve creator stands in, anyone can create
+    // a curve for a mint so it can't override a live mint authority
+    let authority = ctx.accounts.authority.key();
+    let is_creator = match (&ctx.accounts.token_mint.mint_authority, &ctx.accounts.bonding_curve_account) {
+        (COption::Some(mint_authority), _) => *mint_authority == authority,
+        (COption::None, Some(bonding_curve_account)) => bonding_curve_account.creator == authority,
+        (COption::None, None) => false,
+    };
+    if !is_creator {
+        return Err(CommonCustomError::InvalidAuthority.into());
+    }
+
+    // The plan can't promise more than the minted supply
+    if total_supply == 0 || total_supply > ctx.accounts.token_mint.supply {
+        return Err(LaunchPadCustomErrror::InvalidTokenomicsPlan.into());
+    }
+
+    // Each category once, all of them adding up to at most 100%
+    let mut total_percentage: u16 = 0;
+    for (i, share) in categories.iter().enumerate() {
+        if share.percentage == 0 || categories[..i].iter().any(|other| other.category == share.category) {
+            return Err(LaunchPadCustomErrror::InvalidTokenomicsPlan.into());
+        }
+        total_percentage += share.percentage as u16;
+    }
+    if total_percentage > 100 {
+        return Err(LaunchPadCustomErrror::InvalidTokenomicsPlan.into());
+    }
+
+    let tokenomics_plan = &mut ctx.accounts.tokenomics_plan;
+    tokenomics_plan.authority = ctx.accounts.authority.key();
+    tokenomics_plan.token_mint = ctx.accounts.token_mint.key();
+    tokenomics_plan.total_supply = total_supply;
+    tokenomics_plan.categories = categories
+        .iter()
+        .map(|share| CategoryBudget {
+            category: share.category,
+            percentage: share.percentage,
+            allocated: 0,
+            funded: 0,
+        })
+        .collect();
+    tokenomics_plan.bump = ctx.bumps.tokenomics_plan;
+
+    msg!("Tokenomics plan created for {}", tokenomics_plan.token_mint);
+    msg!("Total supply: {}", total_supply);
+    msg!("Categories: {:?}", categories);
+
+    Ok(())
+}
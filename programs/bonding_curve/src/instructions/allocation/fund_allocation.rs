@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::consts::TOKENOMICS_PLAN_SEED_PREFIX;
+use crate::errors::CommonCustomError;
+use crate::state::allocation::{Allocation, TokenomicsPlan};
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+pub struct FundAllocation<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+        seeds = [TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = tokenomics_plan.bump,
+    )]
+    pub tokenomics_plan: Account<'info, TokenomicsPlan>,
+
+    #[account(
+        mut,
+        seeds = [b"allocation", allocation.wallet.as_ref(), token_mint.key().as_ref()],
+        bump = allocation.bump,
+    )]
+    pub allocation: Account<'info, Allocation>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        constraint = allocation_vault.owner == allocation.vault_owner(allocation.key(), tokenomics_plan.key()) @ CommonCustomError::InvalidAuthority,
+    )]
+    pub allocation_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Deposit `amount` tokens into an allocation vault, counted against the allocation and its category.
+/// Batch allocations are funded through the tokenomics plan shared vault
+pub fn fund_allocation(ctx: Context<FundAllocation>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    let category = ctx.accounts.allocation.category;
+    ctx.accounts.allocation.fund(amount)?;
+    ctx.accounts.tokenomics_plan.fund(category, amount)?;
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.allocation_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    msg!("Allocation for {} funded with {} tokens", ctx.accounts.allocation.wallet, amount);
+    msg!("Category {:?} funded: {:?}", category, ctx.accounts.tokenomics_plan.category(category)?.funded);
+
+    Ok(())
+}
@@ -1,13 +1,20 @@
 // Allocation and Vesting Instructions module
 
+pub mod create_tokenomics_plan;
+pub use create_tokenomics_plan::*;
 pub mod create_allocation;
 pub use create_allocation::*;
+pub mod create_allocations_batch;
+pub use create_allocations_batch::*;
+pub mod fund_allocation;
+pub use fund_allocation::*;
 pub mod claim_tokens;
 pub use claim_tokens::*;
-// pub mod update_allocation;
-// pub mod achieve_milestone;
-
-// pub use create_allocation::*;
-// pub use update_allocation::*;
-// pub use claim_tokens::*;
-// pub use achieve_milestone::*; 
\ No newline at end of file
+pub mod revoke_allocation;
+pub use revoke_allocation::*;
+pub mod update_allocation;
+pub use update_allocation::*;
+pub mod transfer_allocation;
+pub use transfer_allocation::*;
+pub mod achieve_milestone;
+pub use achieve_milestone::*;
@@ -0,0 +1,112 @@
+use crate::{
+    consts::TOKENOMICS_PLAN_SEED_PREFIX,
+    errors::{CommonCustomError, LaunchPadCustomErrror},
+    state::allocation::{Allocation, TokenomicsPlan},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+pub struct RevokeAllocation<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+        seeds = [b"allocation", allocation.wallet.as_ref(), token_mint.key().as_ref()],
+        bump = allocation.bump,
+    )]
+    pub allocation: Account<'info, Allocation>,
+    #[account(
+        mut,
+        seeds = [TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = tokenomics_plan.bump,
+    )]
+    pub tokenomics_plan: Account<'info, TokenomicsPlan>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        constraint = allocation_vault.owner == allocation.vault_owner(allocation.key(), tokenomics_plan.key()) @ CommonCustomError::InvalidAuthority,
+    )]
+    pub allocation_vault: InterfaceAccount<'info, TokenAccount>,
+    // Receives the unvested tokens
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Freeze vesting at the current vested amount and claw the unvested tokens back to the treasury.
+/// The recipient can still claim what had vested
+pub fn revoke_allocation(ctx: Context<RevokeAllocation>) -> Result<()> {
+    let allocation = &mut ctx.accounts.allocation;
+    let now = Clock::get()?.unix_timestamp;
+
+    if !allocation.revocable {
+        return Err(LaunchPadCustomErrror::AllocationNotRevocable.into());
+    }
+
+    if allocation.revoked_at.is_some() {
+        return Err(LaunchPadCustomErrror::AllocationAlreadyRevoked.into());
+    }
+
+    let vested = allocation.vested(now)?;
+    let unvested = allocation.total_tokens.saturating_sub(vested);
+    allocation.revoked_at = Some(now);
+
+    // Leave the vested but unclaimed tokens in the vault, a shared vault only gives back this allocation's tokens
+    let owed = vested.saturating_sub(allocation.claimed_tokens);
+    let held = allocation.held_tokens(ctx.accounts.allocation_vault.amount);
+    let clawback = unvested.min(held.saturating_sub(owed));
+    allocation.funded_tokens = allocation.funded_tokens.saturating_sub(clawback);
+    if clawback > 0 {
+        let wallet_key = allocation.wallet;
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let allocation_bump = [allocation.bump];
+        let plan_bump = [ctx.accounts.tokenomics_plan.bump];
+        let allocation_seeds: &[&[u8]] = &[
+            b"allocation".as_ref(),
+            wallet_key.as_ref(),
+            token_mint_key.as_ref(),
+            &allocation_bump,
+        ];
+        let plan_seeds: &[&[u8]] = &[
+            TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(),
+            token_mint_key.as_ref(),
+            &plan_bump,
+        ];
+        let (vault_authority, signer_seeds) = if allocation.shared_vault {
+            (ctx.accounts.tokenomics_plan.to_account_info(), plan_seeds)
+        } else {
+            (allocation.to_account_info(), allocation_seeds)
+        };
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.allocation_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: vault_authority,
+                },
+                &[signer_seeds],
+            ),
+            clawback,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    // The unvested tokens go back to the category budget
+    ctx.accounts.tokenomics_plan.release(allocation.category, unvested, clawback)?;
+
+    msg!("Allocation revoked for {}", allocation.wallet);
+    msg!("Vested: {} / {}", vested, allocation.total_tokens);
+    msg!("Clawed back: {}", clawback);
+
+    Ok(())
+}
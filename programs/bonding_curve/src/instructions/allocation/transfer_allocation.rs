@@ -0,0 +1,140 @@
+use crate::{
+    consts::TOKENOMICS_PLAN_SEED_PREFIX,
+    errors::{CommonCustomError, LaunchPadCustomErrror},
+    state::allocation::{Allocation, TokenomicsPlan},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct TransferAllocation<'info> {
+    #[account(
+        mut,
+        has_one = wallet,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+        seeds = [b"allocation", wallet.key().as_ref(), token_mint.key().as_ref()],
+        bump = allocation.bump,
+        close = authority,
+    )]
+    pub allocation: Box<Account<'info, Allocation>>,
+
+    // Same position re-homed under the new wallet seeds
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Allocation::INIT_SPACE,
+        seeds = [b"allocation", new_wallet.key().as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub new_allocation: Box<Account<'info, Allocation>>,
+
+    #[account(
+        seeds = [TOKENOMICS_PLAN_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = tokenomics_plan.bump,
+    )]
+    pub tokenomics_plan: Box<Account<'info, TokenomicsPlan>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        constraint = allocation_vault.owner == allocation.vault_owner(allocation.key(), tokenomics_plan.key()) @ CommonCustomError::InvalidAuthority,
+    )]
+    pub allocation_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Vault of the new allocation, only for allocations not held in the shared vault
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = new_allocation,
+    )]
+    pub new_allocation_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>, // Current recipient, pays for the new allocation
+    /// CHECK: New recipient wallet, doesn't need to sign so a multisig can receive the position
+    pub new_wallet: UncheckedAccount<'info>,
+    /// CHECK: Allocation creator, gets the rent of the closed allocation and vault back
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move the allocation to a new wallet, keeping its schedule, milestones and claimed amount.
+/// A per-allocation vault is emptied into the new allocation vault and closed
+pub fn transfer_allocation(ctx: Context<TransferAllocation>) -> Result<()> {
+    let allocation = &ctx.accounts.allocation;
+    let new_wallet = ctx.accounts.new_wallet.key();
+
+    if !allocation.transferable {
+        return Err(LaunchPadCustomErrror::AllocationNotTransferable.into());
+    }
+
+    if new_wallet == allocation.wallet {
+        return Err(LaunchPadCustomErrror::InvalidAccountRelationship.into());
+    }
+
+    if !allocation.shared_vault {
+        let new_allocation_vault = ctx
+            .accounts
+            .new_allocation_vault
+            .as_ref()
+            .ok_or(LaunchPadCustomErrror::InvalidAccountRelationship)?;
+
+        let wallet_key = allocation.wallet;
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let allocation_seeds = &[
+            b"allocation".as_ref(),
+            wallet_key.as_ref(),
+            token_mint_key.as_ref(),
+            &[allocation.bump],
+        ];
+
+        let amount = ctx.accounts.allocation_vault.amount;
+        if amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.allocation_vault.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to: new_allocation_vault.to_account_info(),
+                        authority: allocation.to_account_info(),
+                    },
+                    &[&allocation_seeds[..]],
+                ),
+                amount,
+                ctx.accounts.token_mint.decimals,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.allocation_vault.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: allocation.to_account_info(),
+            },
+            &[&allocation_seeds[..]],
+        ))?;
+
+        msg!("Vault tokens moved: {}", amount);
+    }
+
+    let new_allocation = &mut ctx.accounts.new_allocation;
+    new_allocation.set_inner(Allocation {
+        wallet: new_wallet,
+        bump: ctx.bumps.new_allocation,
+        ..(***allocation).clone()
+    });
+
+    msg!("Allocation transferred from {} to {}", allocation.wallet, new_wallet);
+    msg!("Claimed so far: {} / {}", new_allocation.claimed_tokens, new_allocation.total_tokens);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::{
+    consts::MAX_MILESTONES,
+    errors::{CommonCustomError, LaunchPadCustomErrror},
+    state::allocation::{Allocation, Milestone},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct UpdateAllocation<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+        seeds = [b"allocation", allocation.wallet.as_ref(), token_mint.key().as_ref()],
+        bump = allocation.bump,
+    )]
+    pub allocation: Account<'info, Allocation>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub authority: Signer<'info>,
+}
+
+/// Set the milestones gating part of the allocation, the oracle allowed to mark them achieved
+/// and whether the recipient can transfer the position. `None` leaves a field unchanged
+pub fn update_allocation(
+    ctx: Context<UpdateAllocation>,
+    milestones: Option<Vec<Milestone>>,
+    milestone_oracle: Option<Pubkey>,
+    transferable: Option<bool>,
+) -> Result<()> {
+    let allocation = &mut ctx.accounts.allocation;
+
+    if allocation.revoked_at.is_some() {
+        return Err(LaunchPadCustomErrror::AllocationAlreadyRevoked.into());
+    }
+
+    if let Some(milestones) = milestones {
+        // Gating tokens behind milestones takes them away from the schedule,
+        // only the authority of a revocable allocation has that power
+        if !allocation.revocable {
+            return Err(LaunchPadCustomErrror::AllocationNotRevocable.into());
+        }
+
+        // Milestones can't move tokens the recipient already earned, claimed or not. Anything
+        // vested (TGE share, elapsed schedule, achieved milestones) locks the layout
+        if allocation.vested(Clock::get()?.unix_timestamp)? > 0 {
+            return Err(LaunchPadCustomErrror::InvalidMilestones.into());
+        }
+
+        if milestones.len() > MAX_MILESTONES {
+            return Err(LaunchPadCustomErrror::InvalidMilestones.into());
+        }
+
+        let total_bps: u32 = milestones.iter().map(|milestone| milestone.unlock_bps as u32).sum();
+        if total_bps > 10000 || milestones.iter().any(|milestone| milestone.unlock_bps == 0) {
+            return Err(LaunchPadCustomErrror::InvalidMilestones.into());
+        }
+
+        allocation.milestones = milestones
+            .into_iter()
+            .map(|milestone| Milestone { achieved: false, ..milestone })
+            .collect();
+        msg!("Milestones set: {} covering {} bps", allocation.milestones.len(), total_bps);
+    }
+
+    if let Some(milestone_oracle) = milestone_oracle {
+        allocation.milestone_oracle = milestone_oracle;
+        msg!("Milestone oracle set to: {}", milestone_oracle);
+    }
+
+    if let Some(transferable) = transferable {
+        allocation.transferable = transferable;
+        msg!("Allocation transferable: {}", transferable);
+    }
+
+    Ok(())
+}
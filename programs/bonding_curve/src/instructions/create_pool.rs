@@ -22,17 +22,25 @@ pub fn create_pool(
     initial_supply: u64,
     fee_recipients: Vec<Recipient>,
     reserve_ratio: u16,
+    launch_fee_schedule: Option<LaunchFeeSchedule>,
 ) -> Result<()> {
-    let bonding_curve_account = &mut ctx.accounts.bonding_curve_account;
-    let bonding_curve_configuration = &mut ctx.accounts.bonding_curve_configuration;
-
-    let current_time = Clock::get()?.unix_timestamp;
-    let liquidity_lock_period = current_time + liquidity_lock_period;
-
-    bonding_curve_configuration.set_inner(CurveConfiguration::new(
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let initial_reserve_amount = initialize_pool(
+        PoolAccounts {
+            bonding_curve_configuration: &mut ctx.accounts.bonding_curve_configuration,
+            bonding_curve_account: &mut ctx.accounts.bonding_curve_account,
+            pool_sol_vault: &ctx.accounts.pool_sol_vault,
+            fee_vault: &mut ctx.accounts.fee_vault,
+            platform_config: &ctx.accounts.platform_config,
+            admin: &ctx.accounts.admin,
+            system_program: &ctx.accounts.system_program,
+        },
+        token_mint_key,
+        ctx.bumps.bonding_curve_account,
+        ctx.accounts.token_mint.decimals,
         admin,
-        initial_quorum,
         fee_percentage,
+        initial_quorum,
         target_liquidity,
         governance,
         dao_quorum,
@@ -44,28 +52,11 @@ pub fn create_pool(
         initial_supply,
         fee_recipients,
         reserve_ratio,
-    )?);
-
-
-    bonding_curve_account.set_inner(BondingCurve::new(
-        ctx.accounts.admin.key(),
-        ctx.accounts.token_mint.key(),
-        ctx.bumps.bonding_curve_account,
-    ));
-
-    // calculate the initial reserve amount based on the initial price (SOL) and initial supply (token)
-    let initial_reserve_amount = calculate_initial_reserve_amount(
-        bonding_curve_configuration.initial_price,
-        bonding_curve_configuration.initial_supply,
-        bonding_curve_configuration.reserve_ratio,
-        ctx.accounts.token_mint.decimals,
+        launch_fee_schedule,
     )?;
-    msg!("initial reserve amount {:?}", initial_reserve_amount);
 
-    let rent = Rent::get()?;
-    let rent_exempt_min = rent.minimum_balance(ctx.accounts.pool_sol_vault.data_len());
-    msg!("Vault rent-exempt minimum: {:?}", rent_exempt_min);
-    require!(initial_reserve_amount >= rent_exempt_min, CommonCustomError::NotEnoughSolInVaultRentExempt);
+    let bonding_curve_account = &mut ctx.accounts.bonding_curve_account;
+    let bonding_curve_configuration = &ctx.accounts.bonding_curve_configuration;
 
     let token_one_accounts = (
         &mut *ctx.accounts.token_mint,
@@ -90,6 +81,101 @@ pub fn create_pool(
     Ok(())
 }
 
+/// Accounts `create_pool` and `launch_token` both set up
+pub(crate) struct PoolAccounts<'a, 'info> {
+    pub bonding_curve_configuration: &'a mut Account<'info, CurveConfiguration>,
+    pub bonding_curve_account: &'a mut Account<'info, BondingCurve>,
+    pub pool_sol_vault: &'a AccountInfo<'info>,
+    pub fee_vault: &'a mut AccountInfo<'info>,
+    pub platform_config: &'a PlatformConfig,
+    pub admin: &'a Signer<'info>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+/// Write the curve configuration and the bonding curve of a new pool and fund the fee vault rent.
+/// Returns the initial SOL reserve the caller deposits next to the initial supply
+pub(crate) fn initialize_pool<'info>(
+    accounts: PoolAccounts<'_, 'info>,
+    token_mint: Pubkey,
+    bump: u8,
+    decimals: u8,
+    admin: Pubkey,
+    fee_percentage: u16,
+    initial_quorum: u64,
+    target_liquidity: u64,
+    governance: Pubkey,
+    dao_quorum: u16,
+    bonding_curve_type: u8,
+    max_token_supply: u64,
+    liquidity_lock_period: i64,
+    liquidity_pool_percentage: u16,
+    initial_price: u64,
+    initial_supply: u64,
+    fee_recipients: Vec<Recipient>,
+    reserve_ratio: u16,
+    launch_fee_schedule: Option<LaunchFeeSchedule>,
+) -> Result<u64> {
+    let PoolAccounts {
+        bonding_curve_configuration,
+        bonding_curve_account,
+        pool_sol_vault,
+        fee_vault,
+        platform_config,
+        admin: payer,
+        system_program,
+    } = accounts;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let liquidity_lock_period = current_time + liquidity_lock_period;
+
+    bonding_curve_configuration.set_inner(CurveConfiguration::new(
+        admin,
+        initial_quorum,
+        fee_percentage,
+        target_liquidity,
+        governance,
+        dao_quorum,
+        bonding_curve_type,
+        max_token_supply,
+        liquidity_lock_period,
+        liquidity_pool_percentage,
+        initial_price,
+        initial_supply,
+        fee_recipients,
+        reserve_ratio,
+        launch_fee_schedule,
+    )?);
+    bonding_curve_configuration.validate_fee_layers(platform_config)?;
+    // grow the account when more recipients are passed than the initial allocation holds
+    bonding_curve_configuration.realloc_for_recipients(payer, system_program)?;
+
+    bonding_curve_account.set_inner(BondingCurve::new(payer.key(), token_mint, bump));
+
+    // calculate the initial reserve amount based on the initial price (SOL) and initial supply (token)
+    let initial_reserve_amount = calculate_initial_reserve_amount(
+        bonding_curve_configuration.initial_price,
+        bonding_curve_configuration.initial_supply,
+        bonding_curve_configuration.reserve_ratio,
+        decimals,
+    )?;
+    msg!("initial reserve amount {:?}", initial_reserve_amount);
+
+    let rent = Rent::get()?;
+    let rent_exempt_min = rent.minimum_balance(pool_sol_vault.data_len());
+    msg!("Vault rent-exempt minimum: {:?}", rent_exempt_min);
+    require!(initial_reserve_amount >= rent_exempt_min, CommonCustomError::NotEnoughSolInVaultRentExempt);
+
+    // fund the fee vault up to the rent-exempt minimum so small fee transfers can land in it
+    let fee_vault_rent = rent
+        .minimum_balance(fee_vault.data_len())
+        .saturating_sub(fee_vault.lamports());
+    if fee_vault_rent > 0 {
+        bonding_curve_account.transfer_sol_to_pool(payer, fee_vault, fee_vault_rent, system_program)?;
+    }
+
+    Ok(initial_reserve_amount)
+}
+
 #[derive(Accounts)]
 pub struct CreateLiquidityPool<'info> {
     #[account(
@@ -132,6 +218,14 @@ pub struct CreateLiquidityPool<'info> {
     )]
     pub pool_sol_vault: AccountInfo<'info>,
 
+    /// CHECK: Vault holding the accrued pool, creator and protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_POOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: AccountInfo<'info>,
+
     #[account(mut,
         associated_token::mint = token_mint,
         associated_token::authority = admin,
@@ -139,6 +233,12 @@ pub struct CreateLiquidityPool<'info> {
     )]
     pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     #[account(mut)]
     pub admin: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
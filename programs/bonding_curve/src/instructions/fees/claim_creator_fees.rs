@@ -0,0 +1,57 @@
+use crate::{
+    consts::{CURVE_CONFIGURATION_SEED, FEE_POOL_VAULT_PREFIX, POOL_SEED_PREFIX},
+    errors::CommonCustomError,
+    state::{BondingCurve, BondingCurveAccount, CurveConfiguration, CurveConfigurationAccount},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(
+        mut,
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = bonding_curve_account.bump,
+        has_one = creator @ CommonCustomError::InvalidAuthority,
+    )]
+    pub bonding_curve_account: Box<Account<'info, BondingCurve>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Vault holding the accrued pool, creator and protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_POOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let amount = ctx
+        .accounts
+        .bonding_curve_configuration
+        .take_creator_fees()?;
+
+    ctx.accounts.bonding_curve_account.transfer_sol_from_pool(
+        &mut ctx.accounts.fee_vault,
+        &ctx.accounts.creator,
+        amount,
+        ctx.bumps.fee_vault,
+        &ctx.accounts.system_program,
+        FEE_POOL_VAULT_PREFIX.as_bytes(),
+    )?;
+
+    msg!("Creator {} claimed {} lamports", ctx.accounts.creator.key(), amount);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::{
+    consts::{CURVE_CONFIGURATION_SEED, FEE_POOL_VAULT_PREFIX, POOL_SEED_PREFIX},
+    state::{BondingCurve, BondingCurveAccount, CurveConfiguration, CurveConfigurationAccount},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct ClaimFees<'info> {
+    #[account(
+        mut,
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = bonding_curve_account.bump,
+    )]
+    pub bonding_curve_account: Box<Account<'info, BondingCurve>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Vault holding the accrued pool, creator and protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_POOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let amount = ctx
+        .accounts
+        .bonding_curve_configuration
+        .take_recipient_fees(ctx.accounts.recipient.key(), current_time)?;
+
+    ctx.accounts.bonding_curve_account.transfer_sol_from_pool(
+        &mut ctx.accounts.fee_vault,
+        &ctx.accounts.recipient,
+        amount,
+        ctx.bumps.fee_vault,
+        &ctx.accounts.system_program,
+        FEE_POOL_VAULT_PREFIX.as_bytes(),
+    )?;
+
+    msg!("Recipient {} claimed {} lamports", ctx.accounts.recipient.key(), amount);
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::{
+    consts::REFERRAL_SEED_PREFIX,
+    errors::CommonCustomError,
+    state::ReferralAccount,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED_PREFIX.as_bytes(), referrer.key().as_ref()],
+        bump = referral_account.bump,
+        has_one = referrer @ CommonCustomError::InvalidAuthority,
+    )]
+    pub referral_account: Box<Account<'info, ReferralAccount>>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
+
+pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let referral_account = &mut ctx.accounts.referral_account;
+
+    let amount = referral_account.claimable()?;
+    require!(amount > 0, CommonCustomError::NoFeesToClaim);
+
+    referral_account.fees_claimed = referral_account
+        .fees_claimed
+        .checked_add(amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    // referrer fees are held as lamports on the program owned referral account
+    referral_account.sub_lamports(amount)?;
+    ctx.accounts.referrer.add_lamports(amount)?;
+
+    msg!("Referrer {} claimed {} lamports", ctx.accounts.referrer.key(), amount);
+    Ok(())
+}
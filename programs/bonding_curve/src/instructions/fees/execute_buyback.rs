@@ -0,0 +1,335 @@
+use crate::{
+    consts::{
+        CURVE_CONFIGURATION_SEED, FEE_POOL_VAULT_PREFIX, PLATFORM_CONFIG_SEED, POOL_SEED_PREFIX,
+        PUMP_SWAP_PROGRAM_KEY, QUOTE_TOKEN_MINT, SOL_VAULT_PREFIX,
+    },
+    errors::CommonCustomError,
+    state::{
+        get_pump_buy_fee_bps, get_pump_pool_buy_ix_data, BondingCurve, BondingCurveAccount,
+        CurveConfiguration, CurveConfigurationAccount, Dex, PlatformConfig,
+    },
+    utils::constant_product_amount_out,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use std::str::FromStr;
+
+#[derive(Accounts)]
+pub struct ExecuteBuyback<'info> {
+    #[account(
+        mut,
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = bonding_curve_account.bump,
+    )]
+    pub bonding_curve_account: Box<Account<'info, BondingCurve>>,
+
+    #[account(mut)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // bought back tokens land here before being burned
+    #[account(mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = bonding_curve_account,
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: AccountInfo<'info>,
+
+    /// CHECK: Vault holding the accrued pool, creator and protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_POOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    // anyone can crank the buyback
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Spend the SOL accrued by the `BuybackBurn` recipient on the token and burn it.
+///
+/// Before migration the tokens are bought from the bonding curve. Once the pool has
+/// migrated to PumpSwap the buy is routed to the DEX, with its accounts passed as remaining accounts:
+/// 0. pool
+/// 1. global_config
+/// 2. quote_mint (WSOL)
+/// 3. buyback WSOL account owned by the bonding curve
+/// 4. pool_base_token_account
+/// 5. pool_quote_token_account
+/// 6. protocol_fee_recipient
+/// 7. protocol_fee_recipient_token_account
+/// 8. quote_token_program
+/// 9. associated_token_program
+/// 10. event_authority
+/// 11. pumpswap_program
+///
+/// Any further remaining accounts are forwarded to PumpSwap. The tokens bought are worked out
+/// from the pool reserves, `min_tokens_out` is only the slippage floor.
+///
+/// Buybacks aren't routed to Meteora, once the pool has migrated there the buyback budget stays
+/// in the fee vault and the instruction fails with `BuybackNotSupported`.
+pub fn execute_buyback<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, ExecuteBuyback<'info>>,
+    min_tokens_out: u64,
+) -> Result<()> {
+    ctx.accounts
+        .bonding_curve_configuration
+        .require_not_paused(&ctx.accounts.platform_config)?;
+
+    let budget = ctx
+        .accounts
+        .bonding_curve_configuration
+        .buyback_recipient_mut()?
+        .amount;
+
+    let migration = ctx.accounts.bonding_curve_account.migration;
+    let (tokens_burned, sol_spent) = match migration {
+        None => buyback_from_curve(&mut ctx, budget, min_tokens_out)?,
+        Some(migration) if migration.dex == Dex::PumpSwap => {
+            buyback_from_pumpswap(&mut ctx, migration.pool, budget, min_tokens_out)?
+        }
+        Some(_) => return Err(CommonCustomError::BuybackNotSupported.into()),
+    };
+    ctx.accounts
+        .bonding_curve_configuration
+        .prune_removed_fee_recipients();
+
+    msg!(
+        "Buyback burned {} tokens for {} lamports, {} burned in total",
+        tokens_burned,
+        sol_spent,
+        ctx.accounts.bonding_curve_account.total_burned
+    );
+    Ok(())
+}
+
+fn buyback_from_curve<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, ExecuteBuyback<'info>>,
+    budget: u64,
+    min_tokens_out: u64,
+) -> Result<(u64, u64)> {
+    let accounts = &mut ctx.accounts;
+
+    // never push the curve past its migration target
+    let room = accounts
+        .bonding_curve_configuration
+        .target_liquidity
+        .saturating_sub(accounts.bonding_curve_account.reserve_balance);
+    let sol_amount = budget.min(room);
+    require!(sol_amount > 0, CommonCustomError::NoFeesToClaim);
+
+    let tokens_burned = accounts.bonding_curve_account.buyback_and_burn(
+        &accounts.bonding_curve_configuration,
+        (&mut *accounts.token_mint, &mut *accounts.pool_token_account),
+        (
+            &mut accounts.pool_sol_vault,
+            &mut accounts.fee_vault,
+            ctx.bumps.fee_vault,
+        ),
+        sol_amount,
+        min_tokens_out,
+        &accounts.token_program,
+        &accounts.system_program,
+    )?;
+
+    accounts.bonding_curve_configuration.buyback_recipient_mut()?.amount -= sol_amount;
+
+    Ok((tokens_burned, sol_amount))
+}
+
+fn buyback_from_pumpswap<'info>(
+    ctx: &mut Context<'_, '_, 'info, 'info, ExecuteBuyback<'info>>,
+    migrated_pool: Pubkey,
+    budget: u64,
+    min_tokens_out: u64,
+) -> Result<(u64, u64)> {
+    let remaining = ctx.remaining_accounts;
+    require!(remaining.len() >= 12, CommonCustomError::InvalidMigrationPool);
+
+    let pool = &remaining[0];
+    let global_config = &remaining[1];
+    let quote_mint = &remaining[2];
+    let buyback_quote_account = &remaining[3];
+    let pool_base_token_account = &remaining[4];
+    let pool_quote_token_account = &remaining[5];
+    let protocol_fee_recipient = &remaining[6];
+    let protocol_fee_recipient_token_account = &remaining[7];
+    let quote_token_program = &remaining[8];
+    let associated_token_program = &remaining[9];
+    let event_authority = &remaining[10];
+    let pumpswap_program = &remaining[11];
+
+    require!(
+        pool.key() == migrated_pool
+            && pumpswap_program.key() == Pubkey::from_str(PUMP_SWAP_PROGRAM_KEY).unwrap(),
+        CommonCustomError::InvalidMigrationPool
+    );
+    require!(
+        quote_mint.key() == Pubkey::from_str(QUOTE_TOKEN_MINT).unwrap(),
+        CommonCustomError::SOLMismatch
+    );
+
+    let accounts = &mut ctx.accounts;
+    let bonding_curve_key = accounts.bonding_curve_account.key();
+
+    // the WSOL account is the buyback float, any quote left over from a previous buyback is reused
+    let quote_balance_before = {
+        let quote_account = InterfaceAccount::<TokenAccount>::try_from(buyback_quote_account)?;
+        require!(
+            quote_account.owner == bonding_curve_key && quote_account.mint == quote_mint.key(),
+            CommonCustomError::InvalidMigrationPool
+        );
+        quote_account.amount
+    };
+
+    if budget > 0 {
+        accounts.bonding_curve_account.transfer_sol_from_pool(
+            &mut accounts.fee_vault,
+            buyback_quote_account,
+            budget,
+            ctx.bumps.fee_vault,
+            &accounts.system_program,
+            FEE_POOL_VAULT_PREFIX.as_bytes(),
+        )?;
+        token_interface::sync_native(CpiContext::new(
+            quote_token_program.to_account_info(),
+            token_interface::SyncNative {
+                account: buyback_quote_account.to_account_info(),
+            },
+        ))?;
+        accounts.bonding_curve_configuration.buyback_recipient_mut()?.amount = 0;
+    }
+
+    let max_quote_amount_in = quote_balance_before
+        .checked_add(budget)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    require!(max_quote_amount_in > 0, CommonCustomError::NoFeesToClaim);
+
+    // buy as many tokens as the quote float pays for at the current pool price
+    let fee_bps = get_pump_buy_fee_bps(&global_config.try_borrow_data()?)?;
+    let base_reserve = InterfaceAccount::<TokenAccount>::try_from(pool_base_token_account)?.amount;
+    let quote_reserve = InterfaceAccount::<TokenAccount>::try_from(pool_quote_token_account)?.amount;
+    let base_amount_out =
+        constant_product_amount_out(max_quote_amount_in, fee_bps, base_reserve, quote_reserve)?;
+    require!(
+        base_amount_out > 0 && base_amount_out >= min_tokens_out,
+        CommonCustomError::SlippageExceeded
+    );
+
+    let base_balance_before = accounts.pool_token_account.amount;
+
+    msg!("Start pumpswap buyback");
+
+    let mut metas = vec![
+        AccountMeta::new_readonly(pool.key(), false),
+        AccountMeta::new(bonding_curve_key, true),
+        AccountMeta::new_readonly(global_config.key(), false),
+        AccountMeta::new_readonly(accounts.token_mint.key(), false),
+        AccountMeta::new_readonly(quote_mint.key(), false),
+        AccountMeta::new(accounts.pool_token_account.key(), false),
+        AccountMeta::new(buyback_quote_account.key(), false),
+        AccountMeta::new(pool_base_token_account.key(), false),
+        AccountMeta::new(pool_quote_token_account.key(), false),
+        AccountMeta::new_readonly(protocol_fee_recipient.key(), false),
+        AccountMeta::new(protocol_fee_recipient_token_account.key(), false),
+        AccountMeta::new_readonly(accounts.token_program.key(), false),
+        AccountMeta::new_readonly(quote_token_program.key(), false),
+        AccountMeta::new_readonly(accounts.system_program.key(), false),
+        AccountMeta::new_readonly(associated_token_program.key(), false),
+        AccountMeta::new_readonly(event_authority.key(), false),
+        AccountMeta::new_readonly(pumpswap_program.key(), false),
+    ];
+
+    metas.extend(remaining[12..].iter().map(|acc| AccountMeta {
+        pubkey: *acc.key,
+        is_signer: false,
+        is_writable: acc.is_writable,
+    }));
+
+    let mut infos = vec![
+        pool.to_account_info(),
+        accounts.bonding_curve_account.to_account_info(),
+        global_config.to_account_info(),
+        accounts.token_mint.to_account_info(),
+        quote_mint.to_account_info(),
+        accounts.pool_token_account.to_account_info(),
+        buyback_quote_account.to_account_info(),
+        pool_base_token_account.to_account_info(),
+        pool_quote_token_account.to_account_info(),
+        protocol_fee_recipient.to_account_info(),
+        protocol_fee_recipient_token_account.to_account_info(),
+        accounts.token_program.to_account_info(),
+        quote_token_program.to_account_info(),
+        accounts.system_program.to_account_info(),
+        associated_token_program.to_account_info(),
+        event_authority.to_account_info(),
+        pumpswap_program.to_account_info(),
+    ];
+    infos.extend(remaining[12..].iter().cloned());
+
+    let instruction = Instruction {
+        program_id: pumpswap_program.key(),
+        accounts: metas,
+        data: get_pump_pool_buy_ix_data(base_amount_out, max_quote_amount_in),
+    };
+
+    let bump = accounts.bonding_curve_account.bump;
+    let mint_key = accounts.token_mint.key();
+    let signer = BondingCurve::get_signer(&bump, &mint_key);
+    invoke_signed(&instruction, &infos, &[&signer[..]])?;
+
+    accounts.pool_token_account.reload()?;
+    let tokens_bought = accounts
+        .pool_token_account
+        .amount
+        .checked_sub(base_balance_before)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    require!(
+        tokens_bought >= min_tokens_out,
+        CommonCustomError::SlippageExceeded
+    );
+
+    let quote_balance_after = InterfaceAccount::<TokenAccount>::try_from(buyback_quote_account)?.amount;
+    let sol_spent = max_quote_amount_in
+        .checked_sub(quote_balance_after)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    accounts.bonding_curve_account.burn_from_pool(
+        &accounts.pool_token_account,
+        &accounts.token_mint,
+        tokens_bought,
+        &accounts.token_program,
+    )?;
+    accounts.bonding_curve_account.buyback_sol_spent = accounts
+        .bonding_curve_account
+        .buyback_sol_spent
+        .checked_add(sol_spent)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    Ok((tokens_bought, sol_spent))
+}
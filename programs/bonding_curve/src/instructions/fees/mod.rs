@@ -0,0 +1,19 @@
+// Fee claiming & referral Instructions module
+
+pub mod register_referrer;
+pub use register_referrer::*;
+
+pub mod claim_fees;
+pub use claim_fees::*;
+
+pub mod claim_creator_fees;
+pub use claim_creator_fees::*;
+
+pub mod claim_protocol_fees;
+pub use claim_protocol_fees::*;
+
+pub mod claim_referral_fees;
+pub use claim_referral_fees::*;
+
+pub mod execute_buyback;
+pub use execute_buyback::*;
@@ -0,0 +1,28 @@
+use crate::{consts::REFERRAL_SEED_PREFIX, state::ReferralAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        space = ReferralAccount::ACCOUNT_SIZE,
+        payer = referrer,
+        seeds = [REFERRAL_SEED_PREFIX.as_bytes(), referrer.key().as_ref()],
+        bump,
+    )]
+    pub referral_account: Box<Account<'info, ReferralAccount>>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+    ctx.accounts.referral_account.set_inner(ReferralAccount::new(
+        ctx.accounts.referrer.key(),
+        ctx.bumps.referral_account,
+    ));
+
+    msg!("Referrer registered: {}", ctx.accounts.referrer.key());
+    Ok(())
+}
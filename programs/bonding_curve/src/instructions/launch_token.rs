@@ -0,0 +1,372 @@
+use crate::consts::*;
+use crate::state::*;
+use crate::errors::CommonCustomError;
+use crate::instructions::create_pool::{initialize_pool, PoolAccounts};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    program_pack::Pack,
+};
+use anchor_lang::system_program::{create_account, transfer, CreateAccount, Transfer};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType, instruction::AuthorityType, state::Mint as SplMint,
+};
+use anchor_spl::token_interface::{
+    initialize_mint2, metadata_pointer_initialize, mint_to, set_authority,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+    token_metadata_update_authority, InitializeMint2, MetadataPointerInitialize, MintTo,
+    SetAuthority, TokenInterface, TokenMetadataInitialize, TokenMetadataUpdateAuthority,
+};
+
+/// Create the token and its bonding curve in one step: the mint is created with the curve as
+/// mint authority and no freeze authority, the metadata is written (Token-2022 metadata extension
+/// or Metaplex for SPL tokens), the curve supply is minted into the pool and the mint authority
+/// is revoked
+pub fn launch_token(
+    ctx: Context<LaunchToken>,
+    decimals: u8,
+    metadata: TokenMetadataArgs,
+    admin: Pubkey,
+    fee_percentage: u16,
+    initial_quorum: u64,
+    target_liquidity: u64,
+    governance: Pubkey,
+    dao_quorum: u16,
+    bonding_curve_type: u8,
+    max_token_supply: u64,
+    liquidity_lock_period: i64,
+    liquidity_pool_percentage: u16,
+    initial_price: u64,
+    initial_supply: u64,
+    fee_recipients: Vec<Recipient>,
+    reserve_ratio: u16,
+    launch_fee_schedule: Option<LaunchFeeSchedule>,
+) -> Result<()> {
+    if !metadata.is_valid() {
+        return Err(CommonCustomError::InvalidTokenMetadata.into());
+    }
+
+    if initial_supply == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let bonding_curve_key = ctx.accounts.bonding_curve_account.key();
+    let bump = ctx.bumps.bonding_curve_account;
+    let signer = BondingCurve::get_signer(&bump, &token_mint_key);
+    let is_token_2022 = ctx.accounts.token_program.key() == anchor_spl::token_2022::ID;
+    let rent = Rent::get()?;
+
+    // create the mint, Token-2022 mints point their metadata to themselves
+    let mint_space = if is_token_2022 {
+        ExtensionType::try_calculate_account_len::<SplMint>(&[ExtensionType::MetadataPointer])?
+    } else {
+        SplMint::LEN
+    };
+    create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.token_mint.to_account_info(),
+            },
+        ),
+        rent.minimum_balance(mint_space),
+        mint_space as u64,
+        &ctx.accounts.token_program.key(),
+    )?;
+
+    if is_token_2022 {
+        metadata_pointer_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MetadataPointerInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+            ),
+            Some(bonding_curve_key),
+            Some(token_mint_key),
+        )?;
+    }
+
+    initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.token_mint.to_account_info(),
+            },
+        ),
+        decimals,
+        &bonding_curve_key,
+        None,
+    )?;
+
+    // write the metadata, signed by the curve as mint authority and left immutable
+    if is_token_2022 {
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey::try_from(Some(bonding_curve_key))?,
+            mint: token_mint_key,
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone(),
+            additional_metadata: vec![],
+        };
+        // the token program reallocs the mint for the metadata but doesn't fund it
+        let metadata_rent = rent
+            .minimum_balance(mint_space + token_metadata.tlv_size_of()?)
+            .saturating_sub(ctx.accounts.token_mint.lamports());
+        if metadata_rent > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.admin.to_account_info(),
+                        to: ctx.accounts.token_mint.to_account_info(),
+                    },
+                ),
+                metadata_rent,
+            )?;
+        }
+
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.token_mint.to_account_info(),
+                    update_authority: ctx.accounts.bonding_curve_account.to_account_info(),
+                    mint_authority: ctx.accounts.bonding_curve_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+                &[&signer],
+            ),
+            metadata.name.clone(),
+            metadata.symbol.clone(),
+            metadata.uri.clone(),
+        )?;
+
+        token_metadata_update_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateAuthority {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.token_mint.to_account_info(),
+                    current_authority: ctx.accounts.bonding_curve_account.to_account_info(),
+                    new_authority: ctx.accounts.bonding_curve_account.to_account_info(),
+                },
+                &[&signer],
+            ),
+            OptionalNonZeroPubkey::default(),
+        )?;
+    } else {
+        let metadata_program_id = METADATA_PROGRAM_ID;
+        let (Some(token_metadata), Some(metadata_program)) =
+            (&ctx.accounts.token_metadata, &ctx.accounts.metadata_program)
+        else {
+            return Err(CommonCustomError::InvalidTokenMetadata.into());
+        };
+
+        // the metadata program is invoked with the curve signature, so it must be the real one
+        let (metadata_key, _) = Pubkey::find_program_address(
+            &[
+                METADATA_SEED_PREFIX.as_bytes(),
+                metadata_program_id.as_ref(),
+                token_mint_key.as_ref(),
+            ],
+            &metadata_program_id,
+        );
+        if metadata_program.key() != metadata_program_id || token_metadata.key() != metadata_key {
+            return Err(CommonCustomError::InvalidTokenMetadata.into());
+        }
+
+        let instruction = Instruction {
+            program_id: metadata_program_id,
+            accounts: vec![
+                AccountMeta::new(token_metadata.key(), false),
+                AccountMeta::new_readonly(token_mint_key, false),
+                AccountMeta::new_readonly(bonding_curve_key, true),
+                AccountMeta::new(ctx.accounts.admin.key(), true),
+                AccountMeta::new_readonly(bonding_curve_key, true),
+                AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.rent.key(), false),
+            ],
+            data: get_create_metadata_v3_ix_data(&metadata, false),
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                token_metadata.to_account_info(),
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.bonding_curve_account.to_account_info(),
+                ctx.accounts.admin.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.rent.to_account_info(),
+                metadata_program.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+    }
+    msg!("Token {} ({}) created: {}", metadata.name, metadata.symbol, token_mint_key);
+
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.admin.to_account_info(),
+            associated_token: ctx.accounts.pool_token_account.to_account_info(),
+            authority: ctx.accounts.bonding_curve_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    let initial_reserve_amount = initialize_pool(
+        PoolAccounts {
+            bonding_curve_configuration: &mut ctx.accounts.bonding_curve_configuration,
+            bonding_curve_account: &mut ctx.accounts.bonding_curve_account,
+            pool_sol_vault: &ctx.accounts.pool_sol_vault,
+            fee_vault: &mut ctx.accounts.fee_vault,
+            platform_config: &ctx.accounts.platform_config,
+            admin: &ctx.accounts.admin,
+            system_program: &ctx.accounts.system_program,
+        },
+        token_mint_key,
+        bump,
+        decimals,
+        admin,
+        fee_percentage,
+        initial_quorum,
+        target_liquidity,
+        governance,
+        dao_quorum,
+        bonding_curve_type,
+        max_token_supply,
+        liquidity_lock_period,
+        liquidity_pool_percentage,
+        initial_price,
+        initial_supply,
+        fee_recipients,
+        reserve_ratio,
+        launch_fee_schedule,
+    )?;
+
+    let bonding_curve_account = &mut ctx.accounts.bonding_curve_account;
+
+    // the curve supply is minted straight into the pool, then nobody can mint any more
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+                authority: bonding_curve_account.to_account_info(),
+            },
+            &[&signer],
+        ),
+        initial_supply,
+    )?;
+
+    set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: bonding_curve_account.to_account_info(),
+                account_or_mint: ctx.accounts.token_mint.to_account_info(),
+            },
+            &[&signer],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    bonding_curve_account.transfer_sol_to_pool(
+        &ctx.accounts.admin,
+        &mut ctx.accounts.pool_sol_vault,
+        initial_reserve_amount,
+        &ctx.accounts.system_program,
+    )?;
+    bonding_curve_account.reserve_token += initial_supply;
+    bonding_curve_account.reserve_balance += initial_reserve_amount;
+
+    msg!("initial supply {:?}", initial_supply);
+    msg!("Mint authority revoked");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LaunchToken<'info> {
+    #[account(
+        init,
+        space = CurveConfiguration::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = BondingCurve::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve_account: Box<Account<'info, BondingCurve>>,
+
+    // New mint keypair, created by the instruction
+    #[account(mut)]
+    pub token_mint: Signer<'info>,
+
+    /// CHECK: Pool ATA of the new mint, created by the instruction
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &bonding_curve_account.key(),
+            &token_mint.key(),
+            &token_program.key(),
+        ),
+    )]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is a vault solana account for the pool
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: AccountInfo<'info>,
+
+    /// CHECK: Vault holding the accrued pool, creator and protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_POOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: AccountInfo<'info>,
+
+    /// CHECK: Metaplex metadata PDA, only for SPL tokens. Checked in the instruction
+    #[account(mut)]
+    pub token_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metaplex token metadata program, only for SPL tokens. Checked in the instruction
+    pub metadata_program: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
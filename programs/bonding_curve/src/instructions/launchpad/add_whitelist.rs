@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{errors::LaunchPadCustomErrror, state::{BuyerAccount, WhitelistLaunchData}, consts::{LAUNCHPAD_SEED_PREFIX, WHITELIST_DATA_SEED_PREFIX, BUYER_SEED_PREFIX}};
+use crate::{errors::{CommonCustomError, LaunchPadCustomErrror}, state::{BuyerAccount, WhitelistLaunchData}, consts::{LAUNCHPAD_SEED_PREFIX, WHITELIST_DATA_SEED_PREFIX, BUYER_SEED_PREFIX}};
 
 #[derive(Accounts)]
 pub struct AddWhitelist<'info> {
@@ -8,6 +8,7 @@ pub struct AddWhitelist<'info> {
         mut,
         seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
         bump = whitelist_data.bump,
+        constraint = whitelist_data.can_manage_whitelist(&authority.key()) @ CommonCustomError::InvalidAuthority,
     )]
     pub whitelist_data: Account<'info, WhitelistLaunchData>,
     
@@ -46,11 +47,14 @@ pub fn add_whitelist(ctx: Context<AddWhitelist>, user: Pubkey) -> Result<()> {
         return Err(LaunchPadCustomErrror::LaunchpadPaused.into());
     }
 
-    // Initialize buyer account
-    buyer_account.buyer = user;
-    buyer_account.amount = 0;
+    // Initialize buyer account, keeping the purchases of a user who is whitelisted again
+    if buyer_account.buyer == Pubkey::default() {
+        buyer_account.buyer = user;
+        buyer_account.amount = 0;
+        buyer_account.launchpad = whitelist_data.key();
+        buyer_account.bump = ctx.bumps.buyer_account;
+    }
     buyer_account.whitelisted = true;
-    buyer_account.bump = ctx.bumps.buyer_account;
 
     // Add user to whitelist if not already present
     if !whitelist_data.whitelisted_users.contains(&user) {
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    consts::{BATCH_AUCTION_DATA_SEED_PREFIX, BATCH_BID_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX},
+    state::{BatchAuctionData, BatchBid},
+    errors::{LaunchPadCustomErrror, CommonCustomError}
+};
+
+#[derive(Accounts)]
+pub struct BidBatchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, BatchAuctionData>>,
+
+    // one bid per wallet
+    #[account(
+        init,
+        seeds = [BATCH_BID_SEED_PREFIX.as_bytes(), auction_data.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = 8 + BatchBid::ACCOUNT_SIZE,
+    )]
+    pub bid: Box<Account<'info, BatchBid>>,
+
+    /// CHECK: This is the vault that escrows the SOL of the bids
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrow `amount` lamports to buy at any clearing price up to `max_price` per token.
+/// The SOL is counted in the demand of its price tick right away, so finalization doesn't need the bids
+pub fn bid_batch_auction(
+    ctx: Context<BidBatchAuction>,
+    max_price: u64,
+    amount: u64,
+) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if auction is paused
+    if auction_data.paused {
+        return Err(LaunchPadCustomErrror::LaunchpadPaused.into());
+    }
+
+    // Check if auction is cancelled
+    if auction_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if the vault holds the tokens for sale
+    if !auction_data.is_funded() {
+        return Err(LaunchPadCustomErrror::LaunchpadNotFunded.into());
+    }
+
+    // Check if auction has started
+    if current_time < auction_data.start_time {
+        return Err(LaunchPadCustomErrror::SaleNotStarted.into());
+    }
+
+    // Check if auction has ended
+    if current_time > auction_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleEnded.into());
+    }
+
+    // Check the bid sits on a price tick at or above the reserve price
+    let tick = auction_data.price_tick(max_price)?;
+
+    // Check minimum bid
+    if amount < auction_data.min_bid {
+        return Err(LaunchPadCustomErrror::ContributionBelowMinimum.into());
+    }
+
+    // Escrow SOL from bidder in the contribution vault
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.contribution_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    ctx.accounts.bid.set_inner(BatchBid {
+        bidder: ctx.accounts.bidder.key(),
+        auction: auction_data.key(),
+        bid_id: auction_data.bid_count,
+        max_price,
+        amount,
+        claimed: false,
+        bump: ctx.bumps.bid,
+    });
+
+    auction_data.bid_count += 1;
+    auction_data.total_escrowed = auction_data.total_escrowed.checked_add(amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    auction_data.tick_demand[tick] = auction_data.tick_demand[tick].checked_add(amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Bid submitted!");
+    msg!("Bidder: {}", ctx.accounts.bidder.key());
+    msg!("Bid id: {}", ctx.accounts.bid.bid_id);
+    msg!("Max price: {}", max_price);
+    msg!("Amount escrowed: {}", amount);
+
+    Ok(())
+}
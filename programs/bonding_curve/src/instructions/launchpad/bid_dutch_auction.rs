@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::Mint;
+use crate::{
+    consts::{DUTCH_AUCTION_DATA_SEED_PREFIX, BUYER_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX},
+    state::{DutchAuctionData, WhitelistLaunchData, BuyerAccount},
+    errors::{LaunchPadCustomErrror, CommonCustomError}
+};
+
+#[derive(Accounts)]
+pub struct BidDutchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [BUYER_SEED_PREFIX.as_bytes(), auction_data.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        payer = bidder,
+        space = 8 + BuyerAccount::ACCOUNT_SIZE,
+    )]
+    pub buyer_account: Box<Account<'info, BuyerAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: This is the vault that locks the SOL of the bids
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Bid for `token_amount` tokens, locking their cost at the current price.
+/// `max_price` protects the bidder from a price above what they expect
+pub fn bid_dutch_auction(
+    ctx: Context<BidDutchAuction>,
+    token_amount: u64,
+    max_price: u64,
+) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let buyer_account = &mut ctx.accounts.buyer_account;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if auction is cancelled
+    if auction_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if auction is paused
+    if auction_data.paused {
+        return Err(LaunchPadCustomErrror::LaunchpadPaused.into());
+    }
+
+    // Check if the vault holds the tokens for sale
+    if !auction_data.is_funded() {
+        return Err(LaunchPadCustomErrror::LaunchpadNotFunded.into());
+    }
+
+    // Check if auction has started
+    if current_time < auction_data.start_time {
+        return Err(LaunchPadCustomErrror::SaleNotStarted.into());
+    }
+
+    // Check if auction has ended
+    if current_time > auction_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleEnded.into());
+    }
+
+    // Check if supply already sold out
+    if auction_data.settled {
+        return Err(LaunchPadCustomErrror::AuctionSettled.into());
+    }
+
+    if token_amount == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Check if enough tokens are left
+    let tokens_bid = auction_data.tokens_bid.checked_add(token_amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    if tokens_bid > auction_data.total_tokens {
+        return Err(LaunchPadCustomErrror::InsufficientTokensForSale.into());
+    }
+
+    let price = auction_data.price_at(current_time);
+    if price > max_price {
+        return Err(CommonCustomError::SlippageExceeded.into());
+    }
+
+    let cost = WhitelistLaunchData::purchase_cost(price, token_amount, ctx.accounts.token_mint.decimals)?;
+    if cost == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Lock SOL from bidder in the contribution vault
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.contribution_vault.to_account_info(),
+            },
+        ),
+        cost,
+    )?;
+
+    // Update buyer account
+    if buyer_account.buyer == Pubkey::default() {
+        buyer_account.buyer = ctx.accounts.bidder.key();
+        buyer_account.launchpad = auction_data.key();
+        buyer_account.bump = ctx.bumps.buyer_account;
+    }
+    buyer_account.amount = buyer_account.amount.checked_add(token_amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    buyer_account.committed = buyer_account.committed.checked_add(cost)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    // Update auction data
+    auction_data.tokens_bid = tokens_bid;
+    auction_data.total_committed = auction_data.total_committed.checked_add(cost)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Bid successful!");
+    msg!("Bidder: {}", ctx.accounts.bidder.key());
+    msg!("Tokens: {}", token_amount);
+    msg!("Price: {}", price);
+    msg!("SOL locked: {}", cost);
+
+    // The bid that sells out the supply sets the clearing price
+    if auction_data.tokens_bid == auction_data.total_tokens {
+        auction_data.clearing_price = price;
+        auction_data.settled = true;
+        msg!("Auction sold out at clearing price: {}", price);
+    }
+
+    Ok(())
+}
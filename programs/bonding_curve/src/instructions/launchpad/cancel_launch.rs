@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, FAIR_LAUNCH_DATA_SEED_PREFIX, DUTCH_AUCTION_DATA_SEED_PREFIX, BATCH_AUCTION_DATA_SEED_PREFIX},
+    state::{WhitelistLaunchData, FairLaunchData, DutchAuctionData, BatchAuctionData},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct CancelLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Option<Box<Account<'info, WhitelistLaunchData>>>,
+
+    #[account(
+        mut,
+        seeds = [FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = fair_launch_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub fair_launch_data: Option<Box<Account<'info, FairLaunchData>>>,
+
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = auction_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub auction_data: Option<Box<Account<'info, DutchAuctionData>>>,
+
+    #[account(
+        mut,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = batch_auction_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub batch_auction_data: Option<Box<Account<'info, BatchAuctionData>>>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // sale token vault of the cancelled launch
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Cancel a whitelist launch, fair launch, Dutch or batch auction: contributors can be refunded right away,
+/// the deposited sale tokens go back to the authority and nothing is distributed anymore
+pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let (launch, seed_prefix, bump) = match (
+        &mut ctx.accounts.whitelist_data,
+        &mut ctx.accounts.fair_launch_data,
+        &mut ctx.accounts.auction_data,
+        &mut ctx.accounts.batch_auction_data,
+    ) {
+        (Some(whitelist_data), None, None, None) => {
+            // Check if already cancelled
+            if whitelist_data.cancelled {
+                return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+            }
+
+            // Buyers claim once the sale has ended
+            if current_time > whitelist_data.end_time {
+                return Err(LaunchPadCustomErrror::SaleEnded.into());
+            }
+
+            whitelist_data.cancelled = true;
+            whitelist_data.tokens_deposited = 0;
+            msg!("Whitelist launch cancelled");
+            (whitelist_data.to_account_info(), WHITELIST_DATA_SEED_PREFIX, whitelist_data.bump)
+        }
+        (None, Some(fair_launch_data), None, None) => {
+            // Check if already cancelled
+            if fair_launch_data.cancelled {
+                return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+            }
+
+            // Contributors claim once the sale is finalized
+            if fair_launch_data.finalized {
+                return Err(LaunchPadCustomErrror::AlreadyFinalized.into());
+            }
+
+            fair_launch_data.cancelled = true;
+            fair_launch_data.tokens_deposited = 0;
+            msg!("Fair launch cancelled");
+            (fair_launch_data.to_account_info(), FAIR_LAUNCH_DATA_SEED_PREFIX, fair_launch_data.bump)
+        }
+        (None, None, Some(auction_data), None) => {
+            // Check if already cancelled
+            if auction_data.cancelled {
+                return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+            }
+
+            // Bidders claim once the clearing price is known
+            if auction_data.settled {
+                return Err(LaunchPadCustomErrror::AuctionSettled.into());
+            }
+
+            if current_time > auction_data.end_time {
+                return Err(LaunchPadCustomErrror::SaleEnded.into());
+            }
+
+            auction_data.cancelled = true;
+            auction_data.tokens_deposited = 0;
+            msg!("Dutch auction cancelled");
+            (auction_data.to_account_info(), DUTCH_AUCTION_DATA_SEED_PREFIX, auction_data.bump)
+        }
+        (None, None, None, Some(batch_auction_data)) => {
+            // Check if already cancelled
+            if batch_auction_data.cancelled {
+                return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+            }
+
+            // Once bidding is over the auction is finalized or its bids refunded
+            if current_time > batch_auction_data.end_time {
+                return Err(LaunchPadCustomErrror::SaleEnded.into());
+            }
+
+            batch_auction_data.cancelled = true;
+            batch_auction_data.tokens_deposited = 0;
+            msg!("Batch auction cancelled");
+            (batch_auction_data.to_account_info(), BATCH_AUCTION_DATA_SEED_PREFIX, batch_auction_data.bump)
+        }
+        _ => return Err(LaunchPadCustomErrror::InvalidLaunchType.into()),
+    };
+
+    if ctx.accounts.launchpad_vault.owner != launch.key() {
+        return Err(LaunchPadCustomErrror::InvalidAccountRelationship.into());
+    }
+
+    // Return the deposited sale tokens to the authority
+    let returned_tokens = ctx.accounts.launchpad_vault.amount;
+    if returned_tokens > 0 {
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let authority_seeds = &[
+            seed_prefix.as_bytes(),
+            token_mint_key.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds = &[&authority_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.launchpad_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.authority_token_account.to_account_info(),
+                    authority: launch,
+                },
+                signer_seeds,
+            ),
+            returned_tokens,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Tokens returned: {}", returned_tokens);
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    consts::{BATCH_AUCTION_DATA_SEED_PREFIX, BATCH_BID_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX},
+    state::{BatchAuctionData, BatchBid},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct ClaimBatchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, BatchAuctionData>>,
+
+    #[account(
+        mut,
+        seeds = [BATCH_BID_SEED_PREFIX.as_bytes(), auction_data.key().as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        constraint = bid.bidder == bidder.key() @ CommonCustomError::InvalidAuthority,
+    )]
+    pub bid: Box<Account<'info, BatchBid>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is the vault that escrows the SOL of the bids
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim the tokens filled at the clearing price and the refund of the unfilled SOL
+pub fn claim_batch_auction(ctx: Context<ClaimBatchAuction>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let bid = &mut ctx.accounts.bid;
+
+    // Check if the clearing price is known
+    if !auction_data.finalized {
+        return Err(LaunchPadCustomErrror::AuctionNotFinalized.into());
+    }
+
+    // Check if already claimed
+    if bid.claimed {
+        return Err(LaunchPadCustomErrror::AlreadyClaimed.into());
+    }
+
+    let (tokens_to_claim, cost) = auction_data.bid_fill(bid, ctx.accounts.token_mint.decimals)?;
+    let refund_amount = bid.amount - cost;
+
+    if tokens_to_claim > 0 {
+        // Transfer tokens from launchpad vault to bidder
+        let token_mint_key = auction_data.token_mint;
+        let authority_seeds = &[
+            BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(),
+            token_mint_key.as_ref(),
+            &[auction_data.bump],
+        ];
+        let signer_seeds = &[&authority_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.launchpad_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.bidder_token_account.to_account_info(),
+                    authority: auction_data.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            tokens_to_claim,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    // Refund the unfilled SOL
+    if refund_amount > 0 {
+        let auction_key = auction_data.key();
+        let vault_seeds = &[
+            CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(),
+            auction_key.as_ref(),
+            &[ctx.bumps.contribution_vault],
+        ];
+        let vault_signer = &[&vault_seeds[..]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.contribution_vault.to_account_info(),
+                    to: ctx.accounts.bidder.to_account_info(),
+                },
+                vault_signer,
+            ),
+            refund_amount,
+        )?;
+    }
+
+    bid.claimed = true;
+    auction_data.proceeds_accrued = auction_data.proceeds_accrued.checked_add(cost)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Batch auction claimed!");
+    msg!("Bidder: {}", ctx.accounts.bidder.key());
+    msg!("Tokens claimed: {}", tokens_to_claim);
+    msg!("Refund: {}", refund_amount);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    consts::{DUTCH_AUCTION_DATA_SEED_PREFIX, BUYER_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX},
+    state::{DutchAuctionData, BuyerAccount},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct ClaimDutchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    #[account(
+        mut,
+        seeds = [BUYER_SEED_PREFIX.as_bytes(), auction_data.key().as_ref(), bidder.key().as_ref()],
+        bump = buyer_account.bump,
+        constraint = buyer_account.buyer == bidder.key() @ CommonCustomError::InvalidAuthority,
+    )]
+    pub buyer_account: Box<Account<'info, BuyerAccount>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is the vault that locks the SOL of the bids
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim the tokens bid for and the refund of the SOL locked above the clearing price
+pub fn claim_dutch_auction(ctx: Context<ClaimDutchAuction>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let buyer_account = &mut ctx.accounts.buyer_account;
+
+    // Check if the clearing price is known
+    if !auction_data.settled {
+        return Err(LaunchPadCustomErrror::AuctionNotSettled.into());
+    }
+
+    // Check if tokens already claimed
+    if buyer_account.claimed {
+        return Err(LaunchPadCustomErrror::AlreadyClaimed.into());
+    }
+
+    if buyer_account.amount == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let tokens_to_claim = buyer_account.amount;
+    let cost = auction_data.settlement_cost(tokens_to_claim, buyer_account.committed, ctx.accounts.token_mint.decimals)?;
+    let refund_amount = buyer_account.committed - cost;
+
+    // Transfer tokens from launchpad vault to bidder
+    let token_mint_key = auction_data.token_mint;
+    let authority_seeds = &[
+        DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(),
+        token_mint_key.as_ref(),
+        &[auction_data.bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.launchpad_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.bidder_token_account.to_account_info(),
+                authority: auction_data.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        tokens_to_claim,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // Refund the SOL locked above the clearing price
+    if refund_amount > 0 {
+        let auction_key = auction_data.key();
+        let vault_seeds = &[
+            CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(),
+            auction_key.as_ref(),
+            &[ctx.bumps.contribution_vault],
+        ];
+        let vault_signer = &[&vault_seeds[..]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.contribution_vault.to_account_info(),
+                    to: ctx.accounts.bidder.to_account_info(),
+                },
+                vault_signer,
+            ),
+            refund_amount,
+        )?;
+    }
+
+    buyer_account.claimed = true;
+    auction_data.proceeds_accrued = auction_data.proceeds_accrued.checked_add(cost)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Auction tokens claimed!");
+    msg!("Bidder: {}", ctx.accounts.bidder.key());
+    msg!("Tokens claimed: {}", tokens_to_claim);
+    msg!("Refund: {}", refund_amount);
+
+    Ok(())
+}
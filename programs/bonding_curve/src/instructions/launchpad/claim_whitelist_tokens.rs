@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, BUYER_SEED_PREFIX},
+    state::{WhitelistLaunchData, BuyerAccount},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct ClaimWhitelistTokens<'info> {
+    #[account(
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    #[account(
+        mut,
+        seeds = [BUYER_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref(), buyer.key().as_ref()],
+        bump = buyer_account.bump,
+        constraint = buyer_account.buyer == buyer.key() @ CommonCustomError::InvalidAuthority,
+    )]
+    pub buyer_account: Box<Account<'info, BuyerAccount>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == whitelist_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = whitelist_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn claim_whitelist_tokens(ctx: Context<ClaimWhitelistTokens>) -> Result<()> {
+    let whitelist_data = &ctx.accounts.whitelist_data;
+    let buyer_account = &mut ctx.accounts.buyer_account;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if whitelist launch is cancelled
+    if whitelist_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if sale has ended
+    if current_time <= whitelist_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+    }
+
+    // Check if tokens already claimed
+    if buyer_account.claimed {
+        return Err(LaunchPadCustomErrror::AlreadyClaimed.into());
+    }
+
+    // Check if buyer has tokens to claim
+    if buyer_account.amount == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    // Release whatever has vested so far
+    let tokens_to_claim = buyer_account.releasable(
+        buyer_account.amount,
+        whitelist_data.vesting.as_ref(),
+        current_time,
+    )?;
+
+    if tokens_to_claim == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    // Transfer tokens from launchpad vault to buyer
+    let token_mint_key = whitelist_data.token_mint;
+    let authority_seeds = &[
+        WHITELIST_DATA_SEED_PREFIX.as_bytes(),
+        token_mint_key.as_ref(),
+        &[whitelist_data.bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.launchpad_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.buyer_token_account.to_account_info(),
+                authority: whitelist_data.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        tokens_to_claim,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    buyer_account.released = buyer_account.released
+        .checked_add(tokens_to_claim)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    buyer_account.claimed = buyer_account.released >= buyer_account.amount;
+
+    msg!("Tokens claimed successfully!");
+    msg!("Buyer: {}", ctx.accounts.buyer.key());
+    msg!("Tokens claimed: {}", tokens_to_claim);
+    msg!("Tokens released: {} / {}", buyer_account.released, buyer_account.amount);
+
+    Ok(())
+}
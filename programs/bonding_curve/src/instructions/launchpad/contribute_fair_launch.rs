@@ -45,11 +45,21 @@ pub fn contribute_fair_launch(
     let buyer_account = &mut ctx.accounts.buyer_account;
     let current_time = Clock::get()?.unix_timestamp;
 
+    // Check if fair launch is cancelled
+    if fair_launch_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
     // Check if fair launch is paused
     if fair_launch_data.paused {
         return Err(LaunchPadCustomErrror::LaunchpadPaused.into());
     }
 
+    // Check if the vault holds the tokens for sale
+    if !fair_launch_data.is_funded() {
+        return Err(LaunchPadCustomErrror::LaunchpadNotFunded.into());
+    }
+
     // Check if sale has started
     if current_time < fair_launch_data.start_time {
         return Err(LaunchPadCustomErrror::SaleNotStarted.into());
@@ -60,8 +70,8 @@ pub fn contribute_fair_launch(
         return Err(LaunchPadCustomErrror::SaleEnded.into());
     }
 
-    // Check if hard cap would be exceeded
-    if fair_launch_data.total_raised.checked_add(amount)
+    // Check if hard cap would be exceeded, oversubscribed sales accept the overflow and refund it pro-rata
+    if !fair_launch_data.oversubscription && fair_launch_data.total_raised.checked_add(amount)
         .ok_or(CommonCustomError::OverFlowUnderFlowOccured)? > fair_launch_data.hard_cap {
         return Err(LaunchPadCustomErrror::HardCapReached.into());
     }
@@ -78,15 +88,12 @@ pub fn contribute_fair_launch(
         return Err(LaunchPadCustomErrror::ContributionExceedsMaximum.into());
     }
 
-    // For fair launch, we calculate tokens based on proportion of total raise
-    // This is a simplified calculation - in practice you might want more complex logic
-    let estimated_tokens = total_contribution
-        .checked_mul(fair_launch_data.max_tokens_per_wallet)
-        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
-        .checked_div(fair_launch_data.max_contribution)
+    // Check the tokens this wallet can end up with, the final raise only grows from here
+    let total_raised = fair_launch_data.total_raised.checked_add(amount)
         .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
-    
-    if estimated_tokens > fair_launch_data.max_tokens_per_wallet {
+    let max_tokens = fair_launch_data.max_token_share(total_contribution, total_raised)?;
+
+    if max_tokens > fair_launch_data.max_tokens_per_wallet {
         return Err(LaunchPadCustomErrror::MaxTokensPerWalletExceeded.into());
     }
 
@@ -112,8 +119,7 @@ pub fn contribute_fair_launch(
         .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
 
     // Update fair launch data
-    fair_launch_data.total_raised = fair_launch_data.total_raised.checked_add(amount)
-        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    fair_launch_data.total_raised = total_raised;
 
     msg!("Contribution successful!");
     msg!("Contributor: {}", ctx.accounts.contributor.key());
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::{
+    consts::BATCH_AUCTION_DATA_SEED_PREFIX,
+    state::BatchAuctionData,
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct CrankBatchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, BatchAuctionData>>,
+
+    #[account(
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // anyone can crank once the auction is over
+    pub payer: Signer<'info>,
+}
+
+/// Finalize the auction from the demand its bids added to each price tick.
+/// No bid accounts are needed, a single crank finds the clearing price whatever the bids
+pub fn crank_batch_auction(ctx: Context<CrankBatchAuction>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if auction is cancelled
+    if auction_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if auction has ended
+    if current_time <= auction_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+    }
+
+    // Check if already finalized
+    if auction_data.finalized {
+        return Err(LaunchPadCustomErrror::AlreadyFinalized.into());
+    }
+
+    // Check if bidders already took their escrow back
+    if auction_data.expired {
+        return Err(LaunchPadCustomErrror::AuctionExpired.into());
+    }
+
+    auction_data.finalize(ctx.accounts.token_mint.decimals)?;
+
+    msg!("Batch auction finalized!");
+    msg!("Bids: {}", auction_data.bid_count);
+    msg!("Clearing price: {}", auction_data.clearing_price);
+    msg!("Tokens sold: {}", auction_data.tokens_sold);
+
+    Ok(())
+}
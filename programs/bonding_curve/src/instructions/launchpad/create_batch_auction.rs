@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{consts::{BATCH_AUCTION_DATA_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX}, state::BatchAuctionData, errors::{LaunchPadCustomErrror, CommonCustomError}};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CreateBatchAuction<'info> {
+
+    #[account(
+        init,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + BatchAuctionData::ACCOUNT_SIZE,
+    )]
+    pub auction_data: Box<Account<'info, BatchAuctionData>>,
+
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+        payer = authority,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is a PDA used as a vault escrowing the SOL of the bids
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn create_batch_auction(
+    ctx: Context<CreateBatchAuction>,
+    start_time: i64,
+    end_time: i64,
+    min_price: u64,
+    tick_size: u64,
+    min_bid: u64,
+    total_tokens: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate time ranges
+    if start_time <= current_time {
+        return Err(LaunchPadCustomErrror::InvalidTimeRange.into());
+    }
+
+    if end_time <= start_time {
+        return Err(LaunchPadCustomErrror::InvalidTimeRange.into());
+    }
+
+    // Validate the reserve price and the price ticks above it
+    if min_price == 0 || tick_size == 0 {
+        return Err(LaunchPadCustomErrror::InvalidAuctionPrice.into());
+    }
+
+    // Validate bid size and tokens for sale
+    if min_bid == 0 || total_tokens == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    let contribution_vault_key = ctx.accounts.contribution_vault.key();
+
+    let auction_data = &mut ctx.accounts.auction_data;
+
+    auction_data.set_inner(BatchAuctionData::new(
+        ctx.accounts.authority.key(),
+        ctx.accounts.token_mint.key(),
+        start_time,
+        end_time,
+        contribution_vault_key,
+        min_price,
+        tick_size,
+        min_bid,
+        total_tokens,
+        ctx.bumps.auction_data,
+    ));
+
+    // fund the contribution vault up to the rent-exempt minimum and keep it there for good, so bids
+    // below it can land and the claims and proceeds can take out exactly what the bids escrowed
+    let vault_rent = Rent::get()?
+        .minimum_balance(ctx.accounts.contribution_vault.data_len())
+        .saturating_sub(ctx.accounts.contribution_vault.lamports());
+    if vault_rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.contribution_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+
+    msg!("Batch auction created successfully");
+    msg!("Reserve price: {}", min_price);
+    msg!("Tick size: {}", tick_size);
+    msg!("Min bid: {}", min_bid);
+    msg!("Start time: {}", start_time);
+    msg!("End time: {}", end_time);
+    msg!("Total tokens: {}", total_tokens);
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{consts::{DUTCH_AUCTION_DATA_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX}, state::{DutchAuctionData, DecayCurve}, errors::{LaunchPadCustomErrror, CommonCustomError}};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CreateDutchAuction<'info> {
+
+    #[account(
+        init,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + DutchAuctionData::ACCOUNT_SIZE,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        token::token_program = token_program,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+        payer = authority,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is a PDA used as a vault for the SOL locked by bids
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn create_dutch_auction(
+    ctx: Context<CreateDutchAuction>,
+    start_time: i64,
+    end_time: i64,
+    start_price: u64,
+    floor_price: u64,
+    decay: DecayCurve,
+    total_tokens: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Validate time ranges
+    if start_time <= current_time {
+        return Err(LaunchPadCustomErrror::InvalidTimeRange.into());
+    }
+
+    if end_time <= start_time {
+        return Err(LaunchPadCustomErrror::InvalidTimeRange.into());
+    }
+
+    // Validate the price falls from the start price to a non-zero floor
+    if floor_price == 0 || start_price <= floor_price {
+        return Err(LaunchPadCustomErrror::InvalidAuctionPrice.into());
+    }
+
+    if let DecayCurve::Exponential { half_life } = decay {
+        if half_life <= 0 {
+            return Err(LaunchPadCustomErrror::InvalidTimeRange.into());
+        }
+    }
+
+    // Validate tokens for sale
+    if total_tokens == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    let contribution_vault_key = ctx.accounts.contribution_vault.key();
+
+    let auction_data = &mut ctx.accounts.auction_data;
+
+    auction_data.set_inner(DutchAuctionData::new(
+        ctx.accounts.authority.key(),
+        ctx.accounts.token_mint.key(),
+        start_time,
+        end_time,
+        contribution_vault_key,
+        start_price,
+        floor_price,
+        decay,
+        total_tokens,
+        ctx.bumps.auction_data,
+    ));
+
+    // fund the contribution vault up to the rent-exempt minimum and keep it there for good, so bids
+    // below it can land and the claims and proceeds can take out exactly what the bids locked
+    let vault_rent = Rent::get()?
+        .minimum_balance(ctx.accounts.contribution_vault.data_len())
+        .saturating_sub(ctx.accounts.contribution_vault.lamports());
+    if vault_rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.contribution_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+
+    msg!("Dutch auction created successfully");
+    msg!("Start price: {}", start_price);
+    msg!("Floor price: {}", floor_price);
+    msg!("Decay: {:?}", decay);
+    msg!("Start time: {}", start_time);
+    msg!("End time: {}", end_time);
+    msg!("Total tokens: {}", total_tokens);
+
+    Ok(())
+}
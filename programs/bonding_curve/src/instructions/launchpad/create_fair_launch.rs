@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{consts::{LAUNCHPAD_SEED_PREFIX, FAIR_LAUNCH_DATA_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX}, state::{FairLaunchData}, errors::{LaunchPadCustomErrror, CommonCustomError}};
+use anchor_lang::system_program;
+use crate::{consts::{LAUNCHPAD_SEED_PREFIX, FAIR_LAUNCH_DATA_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX, PLATFORM_CONFIG_SEED}, state::{FairLaunchData, PlatformConfig}, errors::{LaunchPadCustomErrror, CommonCustomError}};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
@@ -37,6 +38,12 @@ pub struct CreateFairLaunch<'info> {
     )]
     /// CHECK: This is a PDA used as a vault for SOL contributions
     pub contribution_vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -56,6 +63,7 @@ pub fn create_fair_launch(
     max_contribution: u64,
     max_tokens_per_wallet: u64,
     distribution_delay: i64,
+    total_tokens: u64,
 ) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -93,6 +101,11 @@ pub fn create_fair_launch(
         return Err(CommonCustomError::InvalidAmount.into());
     }
 
+    // Validate tokens for sale
+    if total_tokens == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
     let contribution_vault_key = ctx.accounts.contribution_vault.key();
 
     let fair_launch_data = &mut ctx.accounts.fair_launch_data;
@@ -111,9 +124,29 @@ pub fn create_fair_launch(
         max_contribution,
         max_tokens_per_wallet,
         distribution_delay,
+        total_tokens,
+        ctx.accounts.platform_config.launch_fee_bps,
         ctx.bumps.fair_launch_data,
     ));
 
+    // fund the contribution vault up to the rent-exempt minimum and keep it there for good, so contributions
+    // below it can land and the rounded down oversubscription refunds never leave the vault under rent
+    let vault_rent = ctx.accounts.rent
+        .minimum_balance(ctx.accounts.contribution_vault.data_len())
+        .saturating_sub(ctx.accounts.contribution_vault.lamports());
+    if vault_rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.contribution_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+
     msg!("Fair launch created successfully");
     msg!("Soft cap: {}", soft_cap);
     msg!("Hard cap: {}", hard_cap);
@@ -123,6 +156,8 @@ pub fn create_fair_launch(
     msg!("Start time: {}", start_time);
     msg!("End time: {}", end_time);
     msg!("Distribution delay: {} hours", distribution_delay);
+    msg!("Total tokens: {}", total_tokens);
+    msg!("Launch fee: {} bps", ctx.accounts.platform_config.launch_fee_bps);
 
     Ok(())
 } 
\ No newline at end of file
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{consts::{LAUNCHPAD_SEED_PREFIX, WHITELIST_DATA_SEED_PREFIX}, state::{WhitelistLaunchData}};
+use anchor_lang::system_program;
+use crate::{consts::{LAUNCHPAD_SEED_PREFIX, WHITELIST_DATA_SEED_PREFIX, WHITELIST_VAULT_SEED_PREFIX}, state::{WhitelistLaunchData}};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::errors::{LaunchPadCustomErrror, CommonCustomError};
@@ -30,6 +31,14 @@ pub struct CreateWhitelistLaunch<'info> {
         payer = authority,
     )]
     pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is the vault that receives the SOL paid for whitelist purchases
+    #[account(
+        mut,
+        seeds = [WHITELIST_VAULT_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_vault: AccountInfo<'info>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -94,6 +103,24 @@ pub fn create_whitelist_launch(
         ctx.bumps.whitelist_data,
     ));
 
+    // fund the SOL vault up to the rent-exempt minimum so a first purchase below it can land,
+    // the authority gets it back with the proceeds
+    let vault_rent = ctx.accounts.rent
+        .minimum_balance(ctx.accounts.whitelist_vault.data_len())
+        .saturating_sub(ctx.accounts.whitelist_vault.lamports());
+    if vault_rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.whitelist_vault.to_account_info(),
+                },
+            ),
+            vault_rent,
+        )?;
+    }
+
     msg!("Whitelist launch created successfully");
     msg!("Token price: {}", token_price);
     msg!("Purchase limit per wallet: {}", purchase_limit_per_wallet);
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
 use crate::{
-    consts::{LAUNCHPAD_SEED_PREFIX, FAIR_LAUNCH_DATA_SEED_PREFIX, BUYER_SEED_PREFIX}, 
+    consts::{FAIR_LAUNCH_DATA_SEED_PREFIX, BUYER_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX}, 
     state::{FairLaunchData, BuyerAccount}, 
     errors::{CommonCustomError, LaunchPadCustomErrror}
 };
@@ -31,10 +32,9 @@ pub struct DistributeTokens<'info> {
     
     #[account(
         mut,
-        token::token_program = token_program,
-        token::mint = token_mint,
-        token::authority = fair_launch_data,
-        constraint = launchpad_vault.key() == fair_launch_data.vault @ CommonCustomError::InvalidAuthority,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = fair_launch_data,
     )]
     pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     
@@ -46,9 +46,18 @@ pub struct DistributeTokens<'info> {
     )]
     pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     
+    /// CHECK: This is a PDA used as a vault for SOL contributions, it holds the oversubscribed excess
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), fair_launch_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
     #[account(mut)]
     pub recipient: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn distribute_tokens(ctx: Context<DistributeTokens>) -> Result<()> {
@@ -58,12 +67,17 @@ pub fn distribute_tokens(ctx: Context<DistributeTokens>) -> Result<()> {
 
     // Check if sale has ended
     if current_time <= fair_launch_data.end_time {
-        return Err(LaunchPadCustomErrror::SaleNotStarted.into()); // Reusing error, could create SaleNotEnded
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
     }
 
-    // Check if soft cap was reached
-    if fair_launch_data.total_raised < fair_launch_data.soft_cap {
-        return Err(LaunchPadCustomErrror::SoftCapNotReached.into());
+    // Check if fair launch is cancelled
+    if fair_launch_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if the sale result was snapshotted
+    if !fair_launch_data.finalized {
+        return Err(LaunchPadCustomErrror::NotFinalized.into());
     }
 
     // Check distribution delay
@@ -73,55 +87,104 @@ pub fn distribute_tokens(ctx: Context<DistributeTokens>) -> Result<()> {
     }
 
     // Check if tokens already distributed to this buyer
+    if buyer_account.claimed {
+        return Err(LaunchPadCustomErrror::AlreadyClaimed.into());
+    }
+
     if buyer_account.amount == 0 {
-        return Err(CommonCustomError::InvalidAmount.into());
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
     }
 
     // Calculate tokens to distribute based on proportional contribution
-    // In fair launch, tokens are distributed proportionally based on contribution percentage
-    let total_tokens_available = ctx.accounts.launchpad_vault.amount;
-    
-    let tokens_to_distribute = buyer_account.amount
-        .checked_mul(total_tokens_available)
-        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
-        .checked_div(fair_launch_data.total_raised)
-        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    // In fair launch, tokens are distributed proportionally against the finalization snapshot
+    // The per-wallet token cap is enforced on contribution, so the share is always claimable
+    let token_share = fair_launch_data.token_share(buyer_account.amount)?;
+
+    // Release whatever has vested so far
+    let tokens_to_distribute = buyer_account.releasable(
+        token_share,
+        fair_launch_data.vesting.as_ref(),
+        current_time,
+    )?;
 
-    // Ensure we don't exceed max tokens per wallet
-    if tokens_to_distribute > fair_launch_data.max_tokens_per_wallet {
-        return Err(LaunchPadCustomErrror::MaxTokensPerWalletExceeded.into());
+    // The oversubscribed SOL is refunded with the first claim, whatever has vested
+    let refund_amount = if buyer_account.refunded {
+        0
+    } else {
+        fair_launch_data.excess_refund(buyer_account.amount)?
+    };
+
+    // A zero share still goes through once to refund the excess
+    if tokens_to_distribute == 0 && refund_amount == 0 && token_share > 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
     }
 
     // Transfer tokens from launchpad vault to recipient
+    let token_mint_key = fair_launch_data.token_mint;
     let authority_seeds = &[
-        LAUNCHPAD_SEED_PREFIX.as_bytes(),
-        fair_launch_data.authority.as_ref(),
+        FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(),
+        token_mint_key.as_ref(),
         &[fair_launch_data.bump],
     ];
     let signer_seeds = &[&authority_seeds[..]];
 
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.launchpad_vault.to_account_info(),
-                mint: ctx.accounts.token_mint.to_account_info(),
-                to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: fair_launch_data.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        tokens_to_distribute,
-        ctx.accounts.token_mint.decimals,
-    )?;
+    if tokens_to_distribute > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.launchpad_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: fair_launch_data.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            tokens_to_distribute,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
 
-    // Mark buyer as processed by setting amount to 0
-    buyer_account.amount = 0;
+    // Refund the share of the oversubscribed SOL
+    if refund_amount > 0 {
+        let launchpad_key = fair_launch_data.key();
+        let vault_seeds = &[
+            CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(),
+            launchpad_key.as_ref(),
+            &[ctx.bumps.contribution_vault],
+        ];
+        let vault_signer = &[&vault_seeds[..]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.contribution_vault.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                },
+                vault_signer,
+            ),
+            refund_amount,
+        )?;
+    }
+
+    buyer_account.released = buyer_account.released
+        .checked_add(tokens_to_distribute)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    buyer_account.claimed = buyer_account.released >= token_share;
+    buyer_account.refunded = true;
 
     msg!("Tokens distributed successfully!");
     msg!("Recipient: {}", ctx.accounts.recipient.key());
     msg!("Tokens distributed: {}", tokens_to_distribute);
-    msg!("Contribution percentage: {}%", (buyer_account.amount * 100) / fair_launch_data.total_raised);
+    msg!("Tokens released: {} / {}", buyer_account.released, token_share);
+    msg!("Excess refunded: {}", refund_amount);
+    let contribution_percentage = (buyer_account.amount as u128)
+        .checked_mul(100)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+        .checked_div(fair_launch_data.final_raised as u128)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    msg!("Contribution percentage: {}%", contribution_percentage);
 
     Ok(())
 } 
\ No newline at end of file
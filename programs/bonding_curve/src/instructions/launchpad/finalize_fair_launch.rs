@@ -0,0 +1,273 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    consts::{
+        FAIR_LAUNCH_DATA_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX, PLATFORM_CONFIG_SEED,
+        CURVE_CONFIGURATION_SEED, POOL_SEED_PREFIX, SOL_VAULT_PREFIX,
+    },
+    state::{FairLaunchData, PlatformConfig, CurveConfiguration, CurveConfigurationAccount, BondingCurve},
+    errors::{CommonCustomError, LaunchPadCustomErrror},
+    utils::calc::bps_of,
+};
+
+#[derive(Accounts)]
+pub struct FinalizeFairLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(), fair_launch_data.token_mint.key().as_ref()],
+        bump = fair_launch_data.bump,
+    )]
+    pub fair_launch_data: Box<Account<'info, FairLaunchData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == fair_launch_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = fair_launch_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), fair_launch_data.key().as_ref()],
+        bump
+    )]
+    /// CHECK: This is a PDA used as a vault for SOL contributions
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: Platform treasury receiving the launch fee
+    #[account(mut, address = platform_config.treasury @ CommonCustomError::InvalidAuthority)]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Launch authority receiving the remaining proceeds
+    #[account(mut, address = fair_launch_data.authority @ CommonCustomError::InvalidAuthority)]
+    pub authority: AccountInfo<'info>,
+
+    // Bonding curve seeded with the liquidity, created by the launch authority with `create_pool` before
+    // `set_fair_launch_liquidity`. Finalization only seeds it, moving it to a DEX is the separate migration
+    #[account(
+        mut,
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+        constraint = bonding_curve_configuration.global_admin == fair_launch_data.authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub bonding_curve_configuration: Option<Box<Account<'info, CurveConfiguration>>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = bonding_curve_account.bump,
+    )]
+    pub bonding_curve_account: Option<Box<Account<'info, BondingCurve>>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = bonding_curve_account,
+    )]
+    pub pool_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: This is a vault solana account for the pool
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: Option<AccountInfo<'info>>,
+
+    // anyone can finalize once the sale is over
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn finalize_fair_launch(ctx: Context<FinalizeFairLaunch>) -> Result<()> {
+    let fair_launch_data = &mut ctx.accounts.fair_launch_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if sale has ended
+    if current_time <= fair_launch_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+    }
+
+    // Check if fair launch is cancelled
+    if fair_launch_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if already finalized
+    if fair_launch_data.finalized {
+        return Err(LaunchPadCustomErrror::AlreadyFinalized.into());
+    }
+
+    // Check if soft cap was reached, otherwise contributors are refunded
+    if fair_launch_data.total_raised < fair_launch_data.soft_cap {
+        return Err(LaunchPadCustomErrror::SoftCapNotReached.into());
+    }
+
+    // Check if the tokens for sale were deposited
+    if !fair_launch_data.is_funded() {
+        return Err(LaunchPadCustomErrror::LaunchpadNotFunded.into());
+    }
+
+    // Snapshot the distribution so every claim uses the same ratio
+    fair_launch_data.token_pool = fair_launch_data.total_tokens;
+    fair_launch_data.final_raised = fair_launch_data.total_raised;
+    fair_launch_data.finalized = true;
+
+    // When oversubscribed only the hard cap is kept, the excess stays in the vault for the claims to refund
+    fair_launch_data.accepted_raised = if fair_launch_data.oversubscription {
+        fair_launch_data.total_raised.min(fair_launch_data.hard_cap)
+    } else {
+        fair_launch_data.total_raised
+    };
+
+    // Split the accepted SOL between the platform fee, the liquidity and the authority.
+    // The fee was snapshotted at creation and checked against the liquidity share, so the split always fits
+    let accepted_raised = fair_launch_data.accepted_raised;
+    let platform_fee = bps_of(accepted_raised, fair_launch_data.launch_fee_bps)?;
+    let liquidity_sol = bps_of(accepted_raised, fair_launch_data.liquidity_bps)?;
+    let proceeds = accepted_raised
+        .checked_sub(platform_fee)
+        .ok_or(CommonCustomError::InvalidFee)?
+        .checked_sub(liquidity_sol)
+        .ok_or(CommonCustomError::InvalidFee)?;
+
+    let launchpad_key = fair_launch_data.key();
+    let vault_seeds = &[
+        CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(),
+        launchpad_key.as_ref(),
+        &[ctx.bumps.contribution_vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    if liquidity_sol > 0 {
+        let (
+            Some(bonding_curve_configuration),
+            Some(bonding_curve_account),
+            Some(pool_token_account),
+            Some(pool_sol_vault),
+        ) = (
+            ctx.accounts.bonding_curve_configuration.as_mut(),
+            ctx.accounts.bonding_curve_account.as_mut(),
+            ctx.accounts.pool_token_account.as_ref(),
+            ctx.accounts.pool_sol_vault.as_ref(),
+        ) else {
+            return Err(LaunchPadCustomErrror::MissingLiquidityAccounts.into());
+        };
+
+        // Check the curve wasn't migrated already
+        if bonding_curve_account.migration.is_some() {
+            return Err(CommonCustomError::InvalidMigrationPool.into());
+        }
+
+        let liquidity_tokens = fair_launch_data.liquidity_tokens;
+        let token_mint_key = fair_launch_data.token_mint;
+        let authority_seeds = &[
+            FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(),
+            token_mint_key.as_ref(),
+            &[fair_launch_data.bump],
+        ];
+        let signer_seeds = &[&authority_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.launchpad_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: pool_token_account.to_account_info(),
+                    authority: fair_launch_data.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            liquidity_tokens,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.contribution_vault.to_account_info(),
+                    to: pool_sol_vault.to_account_info(),
+                },
+                vault_signer,
+            ),
+            liquidity_sol,
+        )?;
+
+        bonding_curve_account.reserve_token = bonding_curve_account.reserve_token
+            .checked_add(liquidity_tokens)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        bonding_curve_account.reserve_balance = bonding_curve_account.reserve_balance
+            .checked_add(liquidity_sol)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+        fair_launch_data.tokens_deposited = fair_launch_data.tokens_deposited
+            .checked_sub(liquidity_tokens)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+        // Close the curve to buys at the seeded reserve so only the migration can move it to the DEX
+        if fair_launch_data.migrate_to.is_some() {
+            bonding_curve_configuration.set_target_liquidity(bonding_curve_account.reserve_balance)?;
+        }
+
+        msg!("Liquidity seeded: {} lamports and {} tokens", liquidity_sol, liquidity_tokens);
+        if let Some(dex) = fair_launch_data.migrate_to {
+            msg!("Bonding curve ready to migrate to {:?}", dex);
+        }
+    }
+
+    if platform_fee > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.contribution_vault.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+                vault_signer,
+            ),
+            platform_fee,
+        )?;
+    }
+
+    if proceeds > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.contribution_vault.to_account_info(),
+                    to: ctx.accounts.authority.to_account_info(),
+                },
+                vault_signer,
+            ),
+            proceeds,
+        )?;
+    }
+
+    msg!("Fair launch finalized!");
+    msg!("Token pool: {}", fair_launch_data.token_pool);
+    msg!("Total raised: {}", fair_launch_data.final_raised);
+    msg!("Accepted: {}", fair_launch_data.accepted_raised);
+    msg!("Platform fee: {}", platform_fee);
+    msg!("Proceeds: {}", proceeds);
+
+    Ok(())
+}
@@ -0,0 +1,331 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, FAIR_LAUNCH_DATA_SEED_PREFIX, DUTCH_AUCTION_DATA_SEED_PREFIX, BATCH_AUCTION_DATA_SEED_PREFIX},
+    state::{WhitelistLaunchData, FairLaunchData, DutchAuctionData, BatchAuctionData},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct FundWhitelistLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == whitelist_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = whitelist_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn fund_whitelist_launch(ctx: Context<FundWhitelistLaunch>, amount: u64) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if amount == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Check if launch is cancelled
+    if whitelist_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if sale has ended
+    if current_time > whitelist_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleEnded.into());
+    }
+
+    // Transfer tokens from authority to launchpad vault
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.launchpad_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    whitelist_data.tokens_deposited = whitelist_data.tokens_deposited.checked_add(amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Whitelist launch funded!");
+    msg!("Amount deposited: {}", amount);
+    msg!("Tokens deposited: {}", whitelist_data.tokens_deposited);
+    msg!("Total supply: {}", whitelist_data.total_supply);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundFairLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(), fair_launch_data.token_mint.key().as_ref()],
+        bump = fair_launch_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub fair_launch_data: Box<Account<'info, FairLaunchData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == fair_launch_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = fair_launch_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn fund_fair_launch(ctx: Context<FundFairLaunch>, amount: u64) -> Result<()> {
+    let fair_launch_data = &mut ctx.accounts.fair_launch_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if amount == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Check if launch is cancelled
+    if fair_launch_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if sale has ended
+    if current_time > fair_launch_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleEnded.into());
+    }
+
+    // Transfer tokens from authority to launchpad vault
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.launchpad_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    fair_launch_data.tokens_deposited = fair_launch_data.tokens_deposited.checked_add(amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Fair launch funded!");
+    msg!("Amount deposited: {}", amount);
+    msg!("Tokens deposited: {}", fair_launch_data.tokens_deposited);
+    msg!("Total tokens: {}", fair_launch_data.total_tokens);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundDutchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn fund_dutch_auction(ctx: Context<FundDutchAuction>, amount: u64) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if amount == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Check if auction is cancelled
+    if auction_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if sale has ended
+    if current_time > auction_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleEnded.into());
+    }
+
+    // Transfer tokens from authority to launchpad vault
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.launchpad_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    auction_data.tokens_deposited = auction_data.tokens_deposited.checked_add(amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Dutch auction funded!");
+    msg!("Amount deposited: {}", amount);
+    msg!("Tokens deposited: {}", auction_data.tokens_deposited);
+    msg!("Total tokens: {}", auction_data.total_tokens);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundBatchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub auction_data: Box<Account<'info, BatchAuctionData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn fund_batch_auction(ctx: Context<FundBatchAuction>, amount: u64) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if amount == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Check if auction is cancelled
+    if auction_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if sale has ended
+    if current_time > auction_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleEnded.into());
+    }
+
+    // Transfer tokens from authority to launchpad vault
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.launchpad_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    auction_data.tokens_deposited = auction_data.tokens_deposited.checked_add(amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Batch auction funded!");
+    msg!("Amount deposited: {}", amount);
+    msg!("Tokens deposited: {}", auction_data.tokens_deposited);
+    msg!("Total tokens: {}", auction_data.total_tokens);
+
+    Ok(())
+}
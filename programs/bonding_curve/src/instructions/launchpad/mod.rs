@@ -7,12 +7,33 @@ pub use create_fair_launch::*;
 pub mod contribute_fair_launch;
 pub use contribute_fair_launch::*;
 
+pub mod set_fair_launch_liquidity;
+pub use set_fair_launch_liquidity::*;
+
+pub mod set_fair_launch_oversubscription;
+pub use set_fair_launch_oversubscription::*;
+
+pub mod set_launch_vesting;
+pub use set_launch_vesting::*;
+
+pub mod finalize_fair_launch;
+pub use finalize_fair_launch::*;
+
 pub mod distribute_tokens;
 pub use distribute_tokens::*;
 
 pub mod refund_contribution;
 pub use refund_contribution::*;
 
+pub mod cancel_launch;
+pub use cancel_launch::*;
+
+pub mod refund_whitelist_purchase;
+pub use refund_whitelist_purchase::*;
+
+pub mod refund_dutch_auction_bid;
+pub use refund_dutch_auction_bid::*;
+
 pub mod pause_launchpad;
 pub use pause_launchpad::*;
 
@@ -24,3 +45,63 @@ pub use add_whitelist::*;
 
 pub mod remove_whitelist;
 pub use remove_whitelist::*;
+
+pub mod set_whitelist_manager;
+pub use set_whitelist_manager::*;
+
+pub mod purchase_whitelist;
+pub use purchase_whitelist::*;
+
+pub mod claim_whitelist_tokens;
+pub use claim_whitelist_tokens::*;
+
+pub mod withdraw_whitelist_proceeds;
+pub use withdraw_whitelist_proceeds::*;
+
+pub mod set_whitelist_merkle_root;
+pub use set_whitelist_merkle_root::*;
+
+pub mod set_sale_rounds;
+pub use set_sale_rounds::*;
+
+pub mod set_buyer_tier;
+pub use set_buyer_tier::*;
+
+pub mod fund_launchpad;
+pub use fund_launchpad::*;
+
+pub mod withdraw_unsold_tokens;
+pub use withdraw_unsold_tokens::*;
+
+pub mod create_dutch_auction;
+pub use create_dutch_auction::*;
+
+pub mod bid_dutch_auction;
+pub use bid_dutch_auction::*;
+
+pub mod settle_dutch_auction;
+pub use settle_dutch_auction::*;
+
+pub mod claim_dutch_auction;
+pub use claim_dutch_auction::*;
+
+pub mod withdraw_dutch_auction_proceeds;
+pub use withdraw_dutch_auction_proceeds::*;
+
+pub mod create_batch_auction;
+pub use create_batch_auction::*;
+
+pub mod bid_batch_auction;
+pub use bid_batch_auction::*;
+
+pub mod crank_batch_auction;
+pub use crank_batch_auction::*;
+
+pub mod refund_batch_auction_bid;
+pub use refund_batch_auction_bid::*;
+
+pub mod claim_batch_auction;
+pub use claim_batch_auction::*;
+
+pub mod withdraw_batch_auction_proceeds;
+pub use withdraw_batch_auction_proceeds::*;
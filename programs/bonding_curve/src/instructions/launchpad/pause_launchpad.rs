@@ -1,17 +1,23 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::{WhitelistLaunchData, FairLaunchData}, 
+    state::{WhitelistLaunchData, FairLaunchData, DutchAuctionData, BatchAuctionData}, 
     consts::LAUNCHPAD_SEED_PREFIX,
-    errors::LaunchPadCustomErrror
+    errors::{CommonCustomError, LaunchPadCustomErrror}
 };
 
 #[derive(Accounts)]
 pub struct PauseLaunchPad<'info> {    
-    #[account(mut)]
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
     pub whitelist_data: Option<Account<'info, WhitelistLaunchData>>,
     
-    #[account(mut)]
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
     pub fair_launch_data: Option<Account<'info, FairLaunchData>>,
+
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
+    pub auction_data: Option<Account<'info, DutchAuctionData>>,
+
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
+    pub batch_auction_data: Option<Account<'info, BatchAuctionData>>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -35,6 +41,20 @@ pub fn pause_launchpad(ctx: Context<PauseLaunchPad>) -> Result<()> {
         msg!("Fair launch paused");
     }
 
+    // Pause Dutch auction if it exists
+    if let Some(auction_data) = &mut ctx.accounts.auction_data {
+        auction_data.paused = true;
+        paused_count += 1;
+        msg!("Dutch auction paused");
+    }
+
+    // Pause batch auction if it exists
+    if let Some(batch_auction_data) = &mut ctx.accounts.batch_auction_data {
+        batch_auction_data.paused = true;
+        paused_count += 1;
+        msg!("Batch auction paused");
+    }
+
     // Ensure at least one launch type was paused
     if paused_count == 0 {
         return Err(LaunchPadCustomErrror::InvalidLaunchType.into());
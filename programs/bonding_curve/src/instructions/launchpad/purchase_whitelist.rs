@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::Mint;
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, BUYER_SEED_PREFIX, WHITELIST_VAULT_SEED_PREFIX},
+    state::{WhitelistLaunchData, BuyerAccount, WhitelistProof},
+    errors::{LaunchPadCustomErrror, CommonCustomError}
+};
+
+#[derive(Accounts)]
+pub struct PurchaseWhitelist<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    // created on first purchase in merkle-root mode, added by `add_whitelist` otherwise
+    #[account(
+        init_if_needed,
+        seeds = [BUYER_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        payer = buyer,
+        space = 8 + BuyerAccount::ACCOUNT_SIZE,
+    )]
+    pub buyer_account: Box<Account<'info, BuyerAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == whitelist_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: This is the vault that receives the SOL paid for whitelist purchases
+    #[account(
+        mut,
+        seeds = [WHITELIST_VAULT_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn purchase_whitelist(
+    ctx: Context<PurchaseWhitelist>,
+    token_amount: u64,
+    proof: Option<WhitelistProof>,
+) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+    let buyer_account = &mut ctx.accounts.buyer_account;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if whitelist launch is cancelled
+    if whitelist_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if whitelist launch is paused
+    if whitelist_data.paused {
+        return Err(LaunchPadCustomErrror::LaunchpadPaused.into());
+    }
+
+    // Check if the vault holds the tokens for sale
+    if !whitelist_data.is_funded() {
+        return Err(LaunchPadCustomErrror::LaunchpadNotFunded.into());
+    }
+
+    // Check the sale window and eligibility, and resolve the active round's price and limit
+    let terms = whitelist_data.purchase_terms(
+        &ctx.accounts.buyer.key(),
+        buyer_account,
+        proof.as_ref(),
+        current_time,
+    )?;
+
+    if token_amount == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Check purchase limit per wallet
+    let purchased = terms.purchased.checked_add(token_amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    if purchased > terms.limit {
+        return Err(LaunchPadCustomErrror::PurchaseLimitExceeded.into());
+    }
+    let total_purchased = buyer_account.amount.checked_add(token_amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    // Check tokens left for sale
+    let sold_tokens = whitelist_data.sold_tokens.checked_add(token_amount)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    if sold_tokens > whitelist_data.total_supply {
+        return Err(LaunchPadCustomErrror::InsufficientTokensForSale.into());
+    }
+
+    let cost = WhitelistLaunchData::purchase_cost(
+        terms.token_price,
+        token_amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+    if cost == 0 {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Transfer SOL from buyer to whitelist vault
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.whitelist_vault.to_account_info(),
+            },
+        ),
+        cost,
+    )?;
+
+    // Update buyer account
+    if buyer_account.buyer == Pubkey::default() {
+        buyer_account.buyer = ctx.accounts.buyer.key();
+        buyer_account.launchpad = whitelist_data.key();
+        buyer_account.bump = ctx.bumps.buyer_account;
+    }
+    buyer_account.amount = total_purchased;
+    buyer_account.committed = buyer_account.committed.checked_add(cost)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    if let Some(round) = terms.round {
+        buyer_account.round_purchased[round] = purchased;
+        whitelist_data.rounds[round].sold_tokens = whitelist_data.rounds[round].sold_tokens
+            .checked_add(token_amount)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+    }
+
+    // Update whitelist data, each purchase is recorded on the buyer account only
+    // so the sale account doesn't grow with the number of buyers
+    whitelist_data.sold_tokens = sold_tokens;
+
+    msg!("Purchase successful!");
+    msg!("Buyer: {}", ctx.accounts.buyer.key());
+    msg!("Tokens purchased: {}", token_amount);
+    msg!("SOL paid: {}", cost);
+    if let Some(round) = terms.round {
+        msg!("Sale round: {}", round);
+    }
+    msg!("Total purchased by user: {}", buyer_account.amount);
+    msg!("Sold tokens: {}", whitelist_data.sold_tokens);
+
+    Ok(())
+}
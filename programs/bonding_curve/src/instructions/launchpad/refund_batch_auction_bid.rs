@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    consts::{BATCH_AUCTION_DATA_SEED_PREFIX, BATCH_BID_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX, BATCH_AUCTION_FINALIZE_WINDOW},
+    state::{BatchAuctionData, BatchBid},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct RefundBatchAuctionBid<'info> {
+    #[account(
+        mut,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, BatchAuctionData>>,
+
+    #[account(
+        mut,
+        seeds = [BATCH_BID_SEED_PREFIX.as_bytes(), auction_data.key().as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        constraint = bid.bidder == bidder.key() @ CommonCustomError::InvalidAuthority,
+    )]
+    pub bid: Box<Account<'info, BatchBid>>,
+
+    /// CHECK: This is the vault that escrows the SOL of the bids
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Return the whole escrow of a bid when the auction was cancelled, or when nobody finalized it
+/// within `BATCH_AUCTION_FINALIZE_WINDOW` after its end. The first late refund expires the auction for good
+pub fn refund_batch_auction_bid(ctx: Context<RefundBatchAuctionBid>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let bid = &mut ctx.accounts.bid;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Finalized auctions pay out through claims
+    if auction_data.finalized {
+        return Err(LaunchPadCustomErrror::AlreadyFinalized.into());
+    }
+
+    // A cancelled auction refunds right away
+    if !auction_data.cancelled
+        && current_time <= auction_data.end_time.saturating_add(BATCH_AUCTION_FINALIZE_WINDOW)
+    {
+        return Err(LaunchPadCustomErrror::FinalizationWindowOpen.into());
+    }
+
+    // Check if already refunded
+    if bid.claimed {
+        return Err(LaunchPadCustomErrror::AlreadyClaimed.into());
+    }
+
+    let refund_amount = bid.amount;
+    let auction_key = auction_data.key();
+
+    // Transfer SOL from contribution vault back to bidder
+    let vault_seeds = &[
+        CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(),
+        auction_key.as_ref(),
+        &[ctx.bumps.contribution_vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.contribution_vault.to_account_info(),
+                to: ctx.accounts.bidder.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        refund_amount,
+    )?;
+
+    bid.claimed = true;
+    if !auction_data.cancelled {
+        auction_data.expired = true;
+    }
+
+    msg!("Refund successful!");
+    msg!("Bidder: {}", ctx.accounts.bidder.key());
+    msg!("Refund amount: {}", refund_amount);
+
+    Ok(())
+}
@@ -43,14 +43,17 @@ pub fn refund_contribution(ctx: Context<RefundContribution>) -> Result<()> {
     let buyer_account = &mut ctx.accounts.buyer_account;
     let current_time = Clock::get()?.unix_timestamp;
 
-    // Check if sale has ended
-    if current_time <= fair_launch_data.end_time {
-        return Err(LaunchPadCustomErrror::SaleNotStarted.into()); // Reusing error, could create SaleNotEnded
-    }
+    // A cancelled launch refunds right away
+    if !fair_launch_data.cancelled {
+        // Check if sale has ended
+        if current_time <= fair_launch_data.end_time {
+            return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+        }
 
-    // Check if soft cap was NOT reached (this is when refunds are allowed)
-    if fair_launch_data.total_raised >= fair_launch_data.soft_cap {
-        return Err(CommonCustomError::InvalidAmount.into()); // Soft cap was reached, no refunds
+        // Check if soft cap was NOT reached (this is when refunds are allowed)
+        if fair_launch_data.total_raised >= fair_launch_data.soft_cap {
+            return Err(LaunchPadCustomErrror::SoftCapReached.into());
+        }
     }
 
     // Check if contributor has any amount to refund
@@ -85,7 +88,7 @@ pub fn refund_contribution(ctx: Context<RefundContribution>) -> Result<()> {
     // Update fair launch data
     fair_launch_data.total_raised = fair_launch_data.total_raised
         .checked_sub(refund_amount)
-        .unwrap();
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
 
     // Mark buyer as refunded by setting amount to 0
     buyer_account.amount = 0;
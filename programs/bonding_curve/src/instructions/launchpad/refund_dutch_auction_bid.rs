@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    consts::{DUTCH_AUCTION_DATA_SEED_PREFIX, BUYER_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX},
+    state::{DutchAuctionData, BuyerAccount},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct RefundDutchAuctionBid<'info> {
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    #[account(
+        mut,
+        seeds = [BUYER_SEED_PREFIX.as_bytes(), auction_data.key().as_ref(), bidder.key().as_ref()],
+        bump = buyer_account.bump,
+        constraint = buyer_account.buyer == bidder.key() @ CommonCustomError::InvalidAuthority,
+    )]
+    pub buyer_account: Box<Account<'info, BuyerAccount>>,
+
+    /// CHECK: This is the vault that locks the SOL of the bids
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Return all the SOL a bidder locked in a cancelled auction
+pub fn refund_dutch_auction_bid(ctx: Context<RefundDutchAuctionBid>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let buyer_account = &mut ctx.accounts.buyer_account;
+
+    // Refunds are only open on a cancelled auction
+    if !auction_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchNotCancelled.into());
+    }
+
+    // Check if bidder has anything to refund
+    if buyer_account.committed == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let refund_amount = buyer_account.committed;
+    let auction_key = auction_data.key();
+
+    // Transfer SOL from contribution vault back to bidder
+    let vault_seeds = &[
+        CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(),
+        auction_key.as_ref(),
+        &[ctx.bumps.contribution_vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.contribution_vault.to_account_info(),
+                to: ctx.accounts.bidder.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        refund_amount,
+    )?;
+
+    auction_data.tokens_bid = auction_data.tokens_bid.saturating_sub(buyer_account.amount);
+    auction_data.total_committed = auction_data.total_committed.saturating_sub(refund_amount);
+    buyer_account.amount = 0;
+    buyer_account.committed = 0;
+
+    msg!("Refund successful!");
+    msg!("Bidder: {}", ctx.accounts.bidder.key());
+    msg!("Refund amount: {}", refund_amount);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, BUYER_SEED_PREFIX, WHITELIST_VAULT_SEED_PREFIX},
+    state::{WhitelistLaunchData, BuyerAccount},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct RefundWhitelistPurchase<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    #[account(
+        mut,
+        seeds = [BUYER_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref(), buyer.key().as_ref()],
+        bump = buyer_account.bump,
+        constraint = buyer_account.buyer == buyer.key() @ CommonCustomError::InvalidAuthority,
+    )]
+    pub buyer_account: Box<Account<'info, BuyerAccount>>,
+
+    /// CHECK: This is the vault that holds the SOL paid for whitelist purchases
+    #[account(
+        mut,
+        seeds = [WHITELIST_VAULT_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn refund_whitelist_purchase(ctx: Context<RefundWhitelistPurchase>) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+    let buyer_account = &mut ctx.accounts.buyer_account;
+
+    // Refunds are only open on a cancelled launch
+    if !whitelist_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchNotCancelled.into());
+    }
+
+    // Check if buyer has anything to refund
+    if buyer_account.committed == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let refund_amount = buyer_account.committed;
+    let whitelist_key = whitelist_data.key();
+
+    // Transfer SOL from whitelist vault back to buyer
+    let vault_seeds = &[
+        WHITELIST_VAULT_SEED_PREFIX.as_bytes(),
+        whitelist_key.as_ref(),
+        &[ctx.bumps.whitelist_vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.whitelist_vault.to_account_info(),
+                to: ctx.accounts.buyer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        refund_amount,
+    )?;
+
+    whitelist_data.sold_tokens = whitelist_data.sold_tokens.saturating_sub(buyer_account.amount);
+    buyer_account.amount = 0;
+    buyer_account.committed = 0;
+
+    msg!("Refund successful!");
+    msg!("Buyer: {}", ctx.accounts.buyer.key());
+    msg!("Refund amount: {}", refund_amount);
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::{
-    errors::LaunchPadCustomErrror, 
+    errors::{CommonCustomError, LaunchPadCustomErrror}, 
     state::{BuyerAccount, WhitelistLaunchData}, 
     consts::{LAUNCHPAD_SEED_PREFIX, WHITELIST_DATA_SEED_PREFIX, BUYER_SEED_PREFIX}
 };
@@ -12,6 +12,7 @@ pub struct RemoveWhitelist<'info> {
         mut,
         seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
         bump = whitelist_data.bump,
+        constraint = whitelist_data.can_manage_whitelist(&authority.key()) @ CommonCustomError::InvalidAuthority,
     )]
     pub whitelist_data: Account<'info, WhitelistLaunchData>,
     
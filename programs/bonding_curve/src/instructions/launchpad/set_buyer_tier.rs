@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, BUYER_SEED_PREFIX},
+    state::{BuyerAccount, WhitelistLaunchData},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct SetBuyerTier<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        constraint = whitelist_data.can_manage_whitelist(&authority.key()) @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [BUYER_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref(), user.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + BuyerAccount::ACCOUNT_SIZE,
+    )]
+    pub buyer_account: Box<Account<'info, BuyerAccount>>,
+
+    /// CHECK: User whose tier is set
+    pub user: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_buyer_tier(ctx: Context<SetBuyerTier>, tier: u8) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+    let buyer_account = &mut ctx.accounts.buyer_account;
+    let user = ctx.accounts.user.key();
+
+    // Check if launchpad is not paused
+    if whitelist_data.paused {
+        return Err(LaunchPadCustomErrror::LaunchpadPaused.into());
+    }
+
+    // Initialize buyer account
+    if buyer_account.buyer == Pubkey::default() {
+        buyer_account.buyer = user;
+        buyer_account.launchpad = whitelist_data.key();
+        buyer_account.bump = ctx.bumps.buyer_account;
+    }
+    buyer_account.whitelisted = true;
+    buyer_account.tier = tier;
+
+    if !whitelist_data.whitelisted_users.contains(&user) {
+        whitelist_data.whitelisted_users.push(user);
+    }
+
+    msg!("User {} whitelisted in tier {}", user, tier);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::{FAIR_LAUNCH_DATA_SEED_PREFIX, CURVE_CONFIGURATION_SEED},
+    state::{FairLaunchData, Dex, CurveConfiguration},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct SetFairLaunchLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(), fair_launch_data.token_mint.key().as_ref()],
+        bump = fair_launch_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub fair_launch_data: Box<Account<'info, FairLaunchData>>,
+
+    // Bonding curve the liquidity is seeded into, required when seeding liquidity
+    #[account(
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), fair_launch_data.token_mint.as_ref()],
+        bump,
+        constraint = bonding_curve_configuration.global_admin == authority.key() @ CommonCustomError::InvalidAuthority,
+    )]
+    pub bonding_curve_configuration: Option<Box<Account<'info, CurveConfiguration>>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_fair_launch_liquidity(
+    ctx: Context<SetFairLaunchLiquidity>,
+    liquidity_bps: u16,
+    liquidity_tokens: u64,
+    migrate_to: Option<Dex>,
+) -> Result<()> {
+    let fair_launch_data = &mut ctx.accounts.fair_launch_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // The liquidity plan can't change once the sale has started
+    if current_time >= fair_launch_data.start_time {
+        return Err(LaunchPadCustomErrror::SaleAlreadyStarted.into());
+    }
+
+    // The liquidity and the launch fee are both taken from the raised SOL
+    if (liquidity_bps as u32) + (fair_launch_data.launch_fee_bps as u32) > 10000 {
+        return Err(CommonCustomError::InvalidFee.into());
+    }
+
+    // SOL and tokens are seeded together
+    if (liquidity_bps == 0) != (liquidity_tokens == 0) {
+        return Err(CommonCustomError::InvalidAmount.into());
+    }
+
+    // Finalization seeds an existing curve, so it has to be there before the sale
+    if liquidity_bps > 0 && ctx.accounts.bonding_curve_configuration.is_none() {
+        return Err(LaunchPadCustomErrror::MissingLiquidityAccounts.into());
+    }
+
+    fair_launch_data.liquidity_bps = liquidity_bps;
+    fair_launch_data.liquidity_tokens = liquidity_tokens;
+    fair_launch_data.migrate_to = migrate_to;
+
+    msg!("Fair launch liquidity set!");
+    msg!("Liquidity share: {} bps", liquidity_bps);
+    msg!("Liquidity tokens: {}", liquidity_tokens);
+    msg!("Migrate to: {:?}", migrate_to);
+
+    Ok(())
+}
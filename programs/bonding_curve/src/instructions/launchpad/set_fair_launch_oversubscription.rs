@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::FAIR_LAUNCH_DATA_SEED_PREFIX,
+    state::FairLaunchData,
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct SetFairLaunchOversubscription<'info> {
+    #[account(
+        mut,
+        seeds = [FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(), fair_launch_data.token_mint.key().as_ref()],
+        bump = fair_launch_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub fair_launch_data: Box<Account<'info, FairLaunchData>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_fair_launch_oversubscription(
+    ctx: Context<SetFairLaunchOversubscription>,
+    enabled: bool,
+) -> Result<()> {
+    let fair_launch_data = &mut ctx.accounts.fair_launch_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // The sale mode can't change once the sale has started
+    if current_time >= fair_launch_data.start_time {
+        return Err(LaunchPadCustomErrror::SaleAlreadyStarted.into());
+    }
+
+    fair_launch_data.oversubscription = enabled;
+
+    msg!("Fair launch oversubscription: {}", enabled);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, FAIR_LAUNCH_DATA_SEED_PREFIX},
+    state::{WhitelistLaunchData, FairLaunchData, Vesting},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct SetWhitelistVesting<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_whitelist_vesting(ctx: Context<SetWhitelistVesting>, vesting: Option<Vesting>) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+
+    validate_vesting(vesting.as_ref(), whitelist_data.start_time, whitelist_data.end_time)?;
+
+    whitelist_data.vesting = vesting.clone();
+
+    msg!("Whitelist buyer vesting set: {:?}", vesting);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFairLaunchVesting<'info> {
+    #[account(
+        mut,
+        seeds = [FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(), fair_launch_data.token_mint.key().as_ref()],
+        bump = fair_launch_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub fair_launch_data: Box<Account<'info, FairLaunchData>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_fair_launch_vesting(ctx: Context<SetFairLaunchVesting>, vesting: Option<Vesting>) -> Result<()> {
+    let fair_launch_data = &mut ctx.accounts.fair_launch_data;
+
+    validate_vesting(vesting.as_ref(), fair_launch_data.start_time, fair_launch_data.end_time)?;
+
+    fair_launch_data.vesting = vesting.clone();
+
+    msg!("Fair launch contributor vesting set: {:?}", vesting);
+
+    Ok(())
+}
+
+fn validate_vesting(vesting: Option<&Vesting>, sale_start: i64, sale_end: i64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Buyers must know the schedule before they commit
+    if current_time >= sale_start {
+        return Err(LaunchPadCustomErrror::SaleAlreadyStarted.into());
+    }
+
+    if let Some(vesting) = vesting {
+        if !vesting.is_valid() {
+            return Err(LaunchPadCustomErrror::InvalidVestingSchedule.into());
+        }
+
+        // Nothing unlocks before the sale ends
+        if vesting.start_time < sale_end {
+            return Err(LaunchPadCustomErrror::InvalidVestingSchedule.into());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::WHITELIST_DATA_SEED_PREFIX,
+    state::{WhitelistLaunchData, SaleRound},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct SetSaleRounds<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_sale_rounds(ctx: Context<SetSaleRounds>, rounds: Vec<SaleRound>) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Rounds can't change once the sale has started
+    if current_time >= whitelist_data.start_time {
+        return Err(LaunchPadCustomErrror::SaleAlreadyStarted.into());
+    }
+
+    WhitelistLaunchData::validate_rounds(&rounds)?;
+
+    let first_round_start = rounds[0].start_time;
+    if first_round_start <= current_time {
+        return Err(LaunchPadCustomErrror::InvalidTimeRange.into());
+    }
+
+    // The sale window spans all the rounds
+    whitelist_data.start_time = first_round_start;
+    whitelist_data.end_time = rounds[rounds.len() - 1].end_time;
+    whitelist_data.rounds = rounds
+        .into_iter()
+        .map(|round| SaleRound { sold_tokens: 0, ..round })
+        .collect();
+
+    msg!("Sale rounds set: {}", whitelist_data.rounds.len());
+    msg!("Start time: {}", whitelist_data.start_time);
+    msg!("End time: {}", whitelist_data.end_time);
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::WHITELIST_DATA_SEED_PREFIX,
+    state::WhitelistLaunchData,
+    errors::CommonCustomError
+};
+
+#[derive(Accounts)]
+pub struct SetWhitelistManager<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_whitelist_manager(ctx: Context<SetWhitelistManager>, new_manager: Pubkey) -> Result<()> {
+    ctx.accounts.whitelist_data.whitelist_manager = new_manager;
+
+    msg!("Whitelist manager set to: {}", new_manager);
+    Ok(())
+}
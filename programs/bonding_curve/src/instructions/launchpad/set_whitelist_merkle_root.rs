@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::WHITELIST_DATA_SEED_PREFIX,
+    state::WhitelistLaunchData,
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct SetWhitelistMerkleRoot<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_whitelist_merkle_root(
+    ctx: Context<SetWhitelistMerkleRoot>,
+    merkle_root: Option<[u8; 32]>,
+) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // The whitelist can't change once the sale has started
+    if current_time >= whitelist_data.start_time {
+        return Err(LaunchPadCustomErrror::WhitelistDurationOver.into());
+    }
+
+    whitelist_data.merkle_root = merkle_root;
+
+    match merkle_root {
+        Some(root) => msg!("Whitelist merkle root set: {:?}", root),
+        None => msg!("Whitelist merkle root cleared, using the on-account whitelist"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{
+    consts::DUTCH_AUCTION_DATA_SEED_PREFIX,
+    state::DutchAuctionData,
+    errors::LaunchPadCustomErrror
+};
+
+#[derive(Accounts)]
+pub struct SettleDutchAuction<'info> {
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    // anyone can settle once the auction is over
+    pub payer: Signer<'info>,
+}
+
+/// Settle an auction that didn't sell out at the price reached at its end time
+pub fn settle_dutch_auction(ctx: Context<SettleDutchAuction>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if auction is cancelled
+    if auction_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if auction has ended
+    if current_time <= auction_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+    }
+
+    // Check if already settled by a sell-out
+    if auction_data.settled {
+        return Err(LaunchPadCustomErrror::AuctionSettled.into());
+    }
+
+    auction_data.clearing_price = auction_data.price_at(auction_data.end_time);
+    auction_data.settled = true;
+
+    msg!("Dutch auction settled!");
+    msg!("Clearing price: {}", auction_data.clearing_price);
+    msg!("Tokens sold: {}", auction_data.tokens_bid);
+
+    Ok(())
+}
@@ -1,18 +1,24 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::{WhitelistLaunchData, FairLaunchData}, 
+    state::{WhitelistLaunchData, FairLaunchData, DutchAuctionData, BatchAuctionData}, 
     consts::LAUNCHPAD_SEED_PREFIX,
-    errors::LaunchPadCustomErrror
+    errors::{CommonCustomError, LaunchPadCustomErrror}
 };
 
 #[derive(Accounts)]
 pub struct UnpauseLaunchPad<'info> {
 
-    #[account(mut)]
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
     pub whitelist_data: Option<Account<'info, WhitelistLaunchData>>,
     
-    #[account(mut)]
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
     pub fair_launch_data: Option<Account<'info, FairLaunchData>>,
+
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
+    pub auction_data: Option<Account<'info, DutchAuctionData>>,
+
+    #[account(mut, has_one = authority @ CommonCustomError::InvalidAuthority)]
+    pub batch_auction_data: Option<Account<'info, BatchAuctionData>>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -37,6 +43,20 @@ pub fn unpause_launchpad(ctx: Context<UnpauseLaunchPad>) -> Result<()> {
         msg!("Fair launch unpaused");
     }
 
+    // Unpause Dutch auction if it exists
+    if let Some(auction_data) = &mut ctx.accounts.auction_data {
+        auction_data.paused = false;
+        unpaused_count += 1;
+        msg!("Dutch auction unpaused");
+    }
+
+    // Unpause batch auction if it exists
+    if let Some(batch_auction_data) = &mut ctx.accounts.batch_auction_data {
+        batch_auction_data.paused = false;
+        unpaused_count += 1;
+        msg!("Batch auction unpaused");
+    }
+
     // Ensure at least one launch type was unpaused
     if unpaused_count == 0 {
         return Err(LaunchPadCustomErrror::InvalidLaunchType.into());
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    consts::{DUTCH_AUCTION_DATA_SEED_PREFIX, CONTRIBUTION_VAULT_SEED_PREFIX},
+    state::DutchAuctionData,
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct WithdrawDutchAuctionProceeds<'info> {
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    /// CHECK: This is the vault that locks the SOL of the bids
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(), auction_data.key().as_ref()],
+        bump,
+    )]
+    pub contribution_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw the SOL paid by the bids claimed so far, the rest of the vault is owed to unclaimed bidders
+pub fn withdraw_dutch_auction_proceeds(ctx: Context<WithdrawDutchAuctionProceeds>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+
+    // Check if the clearing price is known
+    if !auction_data.settled {
+        return Err(LaunchPadCustomErrror::AuctionNotSettled.into());
+    }
+
+    // Each claim accrues the cost of its tokens at the clearing price, so the proceeds
+    // and the refunds add up to exactly the SOL locked by the bids
+    let proceeds = auction_data.proceeds_accrued - auction_data.proceeds_withdrawn;
+    if proceeds == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let auction_key = auction_data.key();
+    let vault_seeds = &[
+        CONTRIBUTION_VAULT_SEED_PREFIX.as_bytes(),
+        auction_key.as_ref(),
+        &[ctx.bumps.contribution_vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.contribution_vault.to_account_info(),
+                to: ctx.accounts.authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        proceeds,
+    )?;
+
+    auction_data.proceeds_withdrawn = auction_data.proceeds_accrued;
+
+    msg!("Proceeds withdrawn successfully!");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Amount withdrawn: {}", proceeds);
+
+    Ok(())
+}
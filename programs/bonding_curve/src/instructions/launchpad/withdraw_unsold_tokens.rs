@@ -0,0 +1,354 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, FAIR_LAUNCH_DATA_SEED_PREFIX, DUTCH_AUCTION_DATA_SEED_PREFIX, BATCH_AUCTION_DATA_SEED_PREFIX},
+    state::{WhitelistLaunchData, FairLaunchData, DutchAuctionData, BatchAuctionData},
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct WithdrawUnsoldWhitelistTokens<'info> {
+    #[account(
+        mut,
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == whitelist_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = whitelist_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_unsold_whitelist_tokens(ctx: Context<WithdrawUnsoldWhitelistTokens>) -> Result<()> {
+    let whitelist_data = &mut ctx.accounts.whitelist_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if sale has ended
+    if current_time <= whitelist_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+    }
+
+    // Sold tokens stay in the vault for buyers to claim
+    let unsold_tokens = whitelist_data.unsold_tokens();
+    if unsold_tokens == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let token_mint_key = whitelist_data.token_mint;
+    let authority_seeds = &[
+        WHITELIST_DATA_SEED_PREFIX.as_bytes(),
+        token_mint_key.as_ref(),
+        &[whitelist_data.bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.launchpad_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: whitelist_data.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        unsold_tokens,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    whitelist_data.tokens_deposited = whitelist_data.sold_tokens;
+
+    msg!("Unsold tokens withdrawn!");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Amount withdrawn: {}", unsold_tokens);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnsoldFairLaunchTokens<'info> {
+    #[account(
+        mut,
+        seeds = [FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(), fair_launch_data.token_mint.key().as_ref()],
+        bump = fair_launch_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub fair_launch_data: Box<Account<'info, FairLaunchData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == fair_launch_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = fair_launch_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_unsold_fair_launch_tokens(ctx: Context<WithdrawUnsoldFairLaunchTokens>) -> Result<()> {
+    let fair_launch_data = &mut ctx.accounts.fair_launch_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if sale has ended
+    if current_time <= fair_launch_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+    }
+
+    // A failed sale returns every token, otherwise only what was deposited above the tokens for sale
+    // and, until finalization seeds it, the liquidity
+    let tokens_owed = if fair_launch_data.total_raised < fair_launch_data.soft_cap {
+        0
+    } else if fair_launch_data.finalized {
+        fair_launch_data.total_tokens
+    } else {
+        fair_launch_data.required_tokens()
+    };
+    let unsold_tokens = fair_launch_data.tokens_deposited.saturating_sub(tokens_owed);
+    if unsold_tokens == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let token_mint_key = fair_launch_data.token_mint;
+    let authority_seeds = &[
+        FAIR_LAUNCH_DATA_SEED_PREFIX.as_bytes(),
+        token_mint_key.as_ref(),
+        &[fair_launch_data.bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.launchpad_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: fair_launch_data.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        unsold_tokens,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    fair_launch_data.tokens_deposited = tokens_owed;
+
+    msg!("Unsold tokens withdrawn!");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Amount withdrawn: {}", unsold_tokens);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnsoldDutchAuctionTokens<'info> {
+    #[account(
+        mut,
+        seeds = [DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub auction_data: Box<Account<'info, DutchAuctionData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_unsold_dutch_auction_tokens(ctx: Context<WithdrawUnsoldDutchAuctionTokens>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+
+    // Check if the auction sold out or ended
+    if !auction_data.settled {
+        return Err(LaunchPadCustomErrror::AuctionNotSettled.into());
+    }
+
+    // Tokens bid for stay in the vault for bidders to claim
+    let unsold_tokens = auction_data.unsold_tokens();
+    if unsold_tokens == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let token_mint_key = auction_data.token_mint;
+    let authority_seeds = &[
+        DUTCH_AUCTION_DATA_SEED_PREFIX.as_bytes(),
+        token_mint_key.as_ref(),
+        &[auction_data.bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.launchpad_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: auction_data.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        unsold_tokens,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    auction_data.tokens_deposited = auction_data.tokens_bid;
+
+    msg!("Unsold tokens withdrawn!");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Amount withdrawn: {}", unsold_tokens);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnsoldBatchAuctionTokens<'info> {
+    #[account(
+        mut,
+        seeds = [BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(), auction_data.token_mint.key().as_ref()],
+        bump = auction_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub auction_data: Box<Account<'info, BatchAuctionData>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.key() == auction_data.token_mint @ CommonCustomError::BondingCurveTokenMismatch,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = token_mint,
+        associated_token::authority = auction_data,
+    )]
+    pub launchpad_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = token_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_unsold_batch_auction_tokens(ctx: Context<WithdrawUnsoldBatchAuctionTokens>) -> Result<()> {
+    let auction_data = &mut ctx.accounts.auction_data;
+
+    // An expired auction sold nothing, bidders take their escrow back and every token returns.
+    // Otherwise the clearing price must be known and the tokens owed to the winning bids stay
+    // in the vault for bidders to claim
+    let unsold_tokens = if auction_data.expired {
+        auction_data.tokens_deposited
+    } else {
+        if !auction_data.finalized {
+            return Err(LaunchPadCustomErrror::AuctionNotFinalized.into());
+        }
+        auction_data.unsold_tokens()
+    };
+    if unsold_tokens == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let token_mint_key = auction_data.token_mint;
+    let authority_seeds = &[
+        BATCH_AUCTION_DATA_SEED_PREFIX.as_bytes(),
+        token_mint_key.as_ref(),
+        &[auction_data.bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.launchpad_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: auction_data.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        unsold_tokens,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    auction_data.tokens_deposited = auction_data.tokens_deposited.checked_sub(unsold_tokens)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    msg!("Unsold tokens withdrawn!");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Amount withdrawn: {}", unsold_tokens);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::{
+    consts::{WHITELIST_DATA_SEED_PREFIX, WHITELIST_VAULT_SEED_PREFIX},
+    state::WhitelistLaunchData,
+    errors::{CommonCustomError, LaunchPadCustomErrror}
+};
+
+#[derive(Accounts)]
+pub struct WithdrawWhitelistProceeds<'info> {
+    #[account(
+        seeds = [WHITELIST_DATA_SEED_PREFIX.as_bytes(), whitelist_data.token_mint.key().as_ref()],
+        bump = whitelist_data.bump,
+        has_one = authority @ CommonCustomError::InvalidAuthority,
+    )]
+    pub whitelist_data: Box<Account<'info, WhitelistLaunchData>>,
+
+    /// CHECK: This is the vault that holds the SOL paid for whitelist purchases
+    #[account(
+        mut,
+        seeds = [WHITELIST_VAULT_SEED_PREFIX.as_bytes(), whitelist_data.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_whitelist_proceeds(ctx: Context<WithdrawWhitelistProceeds>) -> Result<()> {
+    let whitelist_data = &ctx.accounts.whitelist_data;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Check if whitelist launch is cancelled, the vault is refunded to buyers
+    if whitelist_data.cancelled {
+        return Err(LaunchPadCustomErrror::LaunchCancelled.into());
+    }
+
+    // Check if sale has ended
+    if current_time <= whitelist_data.end_time {
+        return Err(LaunchPadCustomErrror::SaleNotEnded.into());
+    }
+
+    let proceeds = ctx.accounts.whitelist_vault.lamports();
+    if proceeds == 0 {
+        return Err(LaunchPadCustomErrror::NothingToClaim.into());
+    }
+
+    let whitelist_key = whitelist_data.key();
+
+    // Transfer SOL from whitelist vault to authority
+    let vault_seeds = &[
+        WHITELIST_VAULT_SEED_PREFIX.as_bytes(),
+        whitelist_key.as_ref(),
+        &[ctx.bumps.whitelist_vault],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.whitelist_vault.to_account_info(),
+                to: ctx.accounts.authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        proceeds,
+    )?;
+
+    msg!("Proceeds withdrawn successfully!");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Amount withdrawn: {}", proceeds);
+
+    Ok(())
+}
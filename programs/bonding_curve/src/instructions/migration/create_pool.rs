@@ -1,10 +1,10 @@
 use crate::consts::{
-    CURVE_CONFIGURATION_SEED, METEORA_PROGRAM_KEY, POOL_SEED_PREFIX, PUMP_SWAP_PROGRAM_KEY,
-    QUOTE_TOKEN_MINT, SOL_VAULT_PREFIX,
+    CURVE_CONFIGURATION_SEED, METEORA_PROGRAM_KEY, PLATFORM_CONFIG_SEED, POOL_SEED_PREFIX,
+    PUMP_SWAP_PROGRAM_KEY, QUOTE_TOKEN_MINT, SOL_VAULT_PREFIX,
 };
 use crate::errors::CommonCustomError;
 use crate::state::{get_meteora_pool_create_ix_data, get_pump_pool_create_ix_data};
-use crate::state::{BondingCurve, CurveConfiguration};
+use crate::state::{BondingCurve, CurveConfiguration, Dex, MigratedPool, PlatformConfig};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
 use anchor_lang::system_program;
@@ -46,6 +46,12 @@ pub struct InitializeMeteoraPool<'info> {
     )]
     pub pool_sol_vault: AccountInfo<'info>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     /// END OF FAIRLAUNCH'S ACCOUNT
 
     /// BEGINNING OF METERORA'S ACCOUNT
@@ -154,6 +160,10 @@ pub fn initialize_pool_meteora_with_config(ctx: Context<InitializeMeteoraPool>)
     // 2. update the bonding curve state to indicate that the pool has been created
     // 2.1
 
+    ctx.accounts
+        .dex_configuration_account
+        .require_not_paused(&ctx.accounts.platform_config)?;
+
     let quote_mint: Pubkey = Pubkey::from_str(QUOTE_TOKEN_MINT).unwrap();
 
     require!(
@@ -312,6 +322,11 @@ pub fn initialize_pool_meteora_with_config(ctx: Context<InitializeMeteoraPool>)
     ctx.accounts.bonding_curve_account.reserve_token = 0;
     // reset reserve balance
     ctx.accounts.bonding_curve_account.reserve_balance = 0;
+    // remember where the liquidity went, buybacks route there afterwards
+    ctx.accounts.bonding_curve_account.migration = Some(MigratedPool {
+        dex: Dex::Meteora,
+        pool: ctx.accounts.pool.key(),
+    });
 
     Ok(())
 }
@@ -348,6 +363,12 @@ pub struct InitializePumpswapPool<'info> {
     )]
     pub pool_sol_vault: AccountInfo<'info>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     /// END OF FAIRLAUNCH'S ACCOUNT
 
     /// BEGINNING OF PUMP SWAP'S ACCOUNT
@@ -413,6 +434,10 @@ pub struct InitializePumpswapPool<'info> {
 }
 
 pub fn initialize_pool_pumpswap(ctx: Context<InitializePumpswapPool>, index: u16) -> Result<()> {
+    ctx.accounts
+        .dex_configuration_account
+        .require_not_paused(&ctx.accounts.platform_config)?;
+
     let quote_mint: Pubkey = Pubkey::from_str(QUOTE_TOKEN_MINT).unwrap();
 
     require!(
@@ -561,5 +586,10 @@ pub fn initialize_pool_pumpswap(ctx: Context<InitializePumpswapPool>, index: u16
     ctx.accounts.bonding_curve_account.reserve_token = 0;
     // reset reserve balance
     ctx.accounts.bonding_curve_account.reserve_balance = 0;
+    // remember where the liquidity went, buybacks route there afterwards
+    ctx.accounts.bonding_curve_account.migration = Some(MigratedPool {
+        dex: Dex::PumpSwap,
+        pool: ctx.accounts.pool.key(),
+    });
     Ok(())
 }
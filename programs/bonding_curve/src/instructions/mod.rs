@@ -1,13 +1,17 @@
 pub mod add_liquidity;
 pub mod buy;
 pub mod create_pool;
+pub mod launch_token;
 pub mod remove_liquidity;
+pub mod quote;
 pub mod sell;
 
 pub use add_liquidity::*;
 pub use buy::*;
 pub use create_pool::*;
+pub use launch_token::*;
 pub use remove_liquidity::*;
+pub use quote::*;
 pub use sell::*;
 
 pub mod migration;
@@ -23,4 +27,6 @@ pub mod allocation;
 pub use allocation::*;
 
 pub mod delete;
-pub use delete::*;
\ No newline at end of file
+pub use delete::*;
+pub mod fees;
+pub use fees::*;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::consts::*;
+use crate::state::{BondingCurve, BondingCurveAccount, CurveConfiguration, PlatformConfig, Quote};
+
+pub fn quote(ctx: Context<GetQuote>, amount: u64, is_buy: bool, with_referrer: bool) -> Result<Quote> {
+    let bonding_curve = &mut ctx.accounts.bonding_curve_account;
+    let bonding_curve_configuration = &ctx.accounts.bonding_curve_configuration;
+
+    let bonding_curve_type: u8 = bonding_curve_configuration.bonding_curve_type.into();
+    let fee_rates = bonding_curve.apply_launch_fee(
+        bonding_curve_configuration,
+        bonding_curve_configuration.fee_rates(&ctx.accounts.platform_config, with_referrer),
+    )?;
+
+    let (amount_out, fees) = if is_buy {
+        let amount_out = bonding_curve.calculate_buy_cost(
+            amount,
+            bonding_curve_type,
+            bonding_curve_configuration.reserve_ratio,
+        )?;
+        (amount_out, fee_rates.compute(amount)?)
+    } else {
+        let amount_out = bonding_curve.calculate_sell_cost(
+            amount,
+            bonding_curve_type,
+            bonding_curve_configuration.reserve_ratio,
+        )?;
+        (amount_out, fee_rates.compute(amount_out)?)
+    };
+
+    Ok(Quote {
+        amount_in: amount,
+        amount_out,
+        effective_fee_bps: fee_rates.pool_fee_bps,
+        total_fee_bps: fee_rates.total_bps()?,
+        fees,
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetQuote<'info> {
+    #[account(
+        seeds = [CURVE_CONFIGURATION_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve_configuration: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = bonding_curve_account.bump,
+    )]
+    pub bonding_curve_account: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+}
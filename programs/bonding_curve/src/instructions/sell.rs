@@ -4,7 +4,9 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::state::{BondingCurve, BondingCurveAccount, CurveConfiguration};
+use crate::state::{
+    BondingCurve, BondingCurveAccount, CurveConfiguration, PlatformConfig, ReferralAccount,
+};
 use crate::{consts::*, errors::CommonCustomError};
 use anchor_lang::system_program;
 
@@ -21,8 +23,25 @@ pub fn sell<'info>(
     let token_program = &ctx.accounts.token_program;
     let pool_sol_vault = &mut ctx.accounts.pool_sol_vault;
 
+    bonding_curve_configuration.require_not_paused(&ctx.accounts.platform_config)?;
+
     let bonding_curve_type: u8 = bonding_curve_configuration.bonding_curve_type.into();
-    let fee_percentage: u16 = bonding_curve_configuration.fee_percentage;
+    let fee_rates = bonding_curve_configuration.fee_rates(
+        &ctx.accounts.platform_config,
+        ctx.accounts.referral_account.is_some(),
+    );
+
+    // a trader can't refer themselves
+    if let Some(referral_account) = &ctx.accounts.referral_account {
+        if referral_account.referrer == user.key() {
+            return Err(CommonCustomError::InvalidReferrer.into());
+        }
+    }
+    let mut referral_vault = ctx
+        .accounts
+        .referral_account
+        .as_ref()
+        .map(|referral_account| referral_account.to_account_info());
 
     let token_one_accounts = (
         &mut *ctx.accounts.token_mint,
@@ -30,12 +49,13 @@ pub fn sell<'info>(
         &mut *ctx.accounts.user_token_account,
     );
 
-    bonding_curve.sell(
+    let fees = bonding_curve.sell(
         bonding_curve_configuration,
         token_one_accounts,
         pool_sol_vault,
+        (&mut ctx.accounts.fee_vault, referral_vault.as_mut()),
         amount,
-        fee_percentage,
+        fee_rates,
         bump,
         user,
         bonding_curve_type,
@@ -44,6 +64,10 @@ pub fn sell<'info>(
         system_program,
     )?;
 
+    if let Some(referral_account) = &mut ctx.accounts.referral_account {
+        referral_account.record_trade(fees.trade_volume, fees.referrer_fee)?;
+    }
+
     // transfer fees to recipients
     // for recipient in ctx.remaining_accounts {
     //     // check if recipient is a valid address in the fee recipients
@@ -113,6 +137,27 @@ pub struct Sell<'info> {
     )]
     pub pool_sol_vault: AccountInfo<'info>,
 
+    /// CHECK: Vault holding the accrued pool, creator and protocol fees
+    #[account(
+        mut,
+        seeds = [FEE_POOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED.as_bytes()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED_PREFIX.as_bytes(), referral_account.referrer.as_ref()],
+        bump = referral_account.bump,
+    )]
+    pub referral_account: Option<Box<Account<'info, ReferralAccount>>>,
+
     #[account(mut,
         associated_token::mint = token_mint,
         associated_token::authority = user,
@@ -10,8 +10,8 @@ use crate::instructions::allocation::{
     claim_tokens, create_allocation, ClaimTokens, CreateAllocation,
 };
 use crate::instructions::*;
-use crate::state::allocation::Vesting;
-use crate::state::Recipient;
+use crate::state::allocation::{AllocationCategory, AllocationEntry, CategoryShare, Milestone, Vesting};
+use crate::state::{DecayCurve, Dex, LaunchFeeSchedule, Quote, Recipient, RecipientShare, SaleRound, TokenMetadataArgs, WhitelistProof};
 declare_id!("EPA9LC7sm4SmiZXU9yv8h8VfzAUNd3SF7CxyPSiDfNQK");
 
 #[program]
@@ -40,6 +40,7 @@ pub mod bonding_curve {
         initial_supply: u64,
         recipients: Vec<Recipient>,
         reserve_ratio: u16,
+        launch_fee_schedule: Option<LaunchFeeSchedule>,
     ) -> Result<()> {
         instructions::create_pool(
             ctx,
@@ -57,6 +58,49 @@ pub mod bonding_curve {
             initial_supply,
             recipients,
             reserve_ratio,
+            launch_fee_schedule,
+        )
+    }
+
+    pub fn launch_token(
+        ctx: Context<LaunchToken>,
+        decimals: u8,
+        metadata: TokenMetadataArgs,
+        admin: Pubkey,
+        fee_percentage: u16,
+        initial_quorum: u64,
+        target_liquidity: u64,
+        governance: Pubkey,
+        dao_quorum: u16,
+        bonding_curve_type: u8,
+        max_token_supply: u64,
+        liquidity_lock_period: i64,
+        liquidity_pool_percentage: u16,
+        initial_reserve: u64,
+        initial_supply: u64,
+        recipients: Vec<Recipient>,
+        reserve_ratio: u16,
+        launch_fee_schedule: Option<LaunchFeeSchedule>,
+    ) -> Result<()> {
+        instructions::launch_token(
+            ctx,
+            decimals,
+            metadata,
+            admin,
+            fee_percentage,
+            initial_quorum,
+            target_liquidity,
+            governance,
+            dao_quorum,
+            bonding_curve_type,
+            max_token_supply,
+            liquidity_lock_period,
+            liquidity_pool_percentage,
+            initial_reserve,
+            initial_supply,
+            recipients,
+            reserve_ratio,
+            launch_fee_schedule,
         )
     }
 
@@ -72,6 +116,10 @@ pub mod bonding_curve {
         instructions::sell(ctx, amount, bump)
     }
 
+    pub fn quote(ctx: Context<GetQuote>, amount: u64, is_buy: bool, with_referrer: bool) -> Result<Quote> {
+        instructions::quote(ctx, amount, is_buy, with_referrer)
+    }
+
     pub fn add_liquidity(
         ctx: Context<AddLiquidity>,
         sol_amount: u64,
@@ -93,6 +141,17 @@ pub mod bonding_curve {
     ) -> Result<()> {
         instructions::add_fee_recipients(ctx, recipients)
     }
+
+    pub fn remove_fee_recipient(ctx: Context<RemoveFeeRecipient>, recipient: Pubkey) -> Result<()> {
+        instructions::remove_fee_recipient(ctx, recipient)
+    }
+
+    pub fn update_recipient_share(
+        ctx: Context<UpdateRecipientShare>,
+        shares: Vec<RecipientShare>,
+    ) -> Result<()> {
+        instructions::update_recipient_share(ctx, shares)
+    }
     pub fn change_fee_admin(ctx: Context<ChangeFeeAdmin>, new_fee_admin: Pubkey) -> Result<()> {
         instructions::change_fee_admin(ctx, new_fee_admin)
     }
@@ -101,6 +160,84 @@ pub mod bonding_curve {
         instructions::set_target_liquidity(ctx, new_target_liquidity)
     }
 
+    pub fn set_creator_fee(ctx: Context<SetCreatorFee>, creator_fee_bps: u16) -> Result<()> {
+        instructions::set_creator_fee(ctx, creator_fee_bps)
+    }
+
+    // ============================================================================
+    // Admin Functions : Platform
+    // ============================================================================
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+        treasury: Pubkey,
+        protocol_fee_bps: u16,
+        referrer_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_platform_config(ctx, treasury, protocol_fee_bps, referrer_fee_bps)
+    }
+
+    pub fn update_platform_fees(
+        ctx: Context<UpdatePlatformFees>,
+        treasury: Pubkey,
+        protocol_fee_bps: u16,
+        referrer_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::update_platform_fees(ctx, treasury, protocol_fee_bps, referrer_fee_bps)
+    }
+
+    pub fn set_launch_fee(ctx: Context<SetLaunchFee>, launch_fee_bps: u16) -> Result<()> {
+        instructions::set_launch_fee(ctx, launch_fee_bps)
+    }
+
+    pub fn set_pauser(ctx: Context<SetPauser>, new_pauser: Pubkey) -> Result<()> {
+        instructions::set_pauser(ctx, new_pauser)
+    }
+
+    // ============================================================================
+    // Admin Pause & Unpause Functions : Bonding Curve
+    // ============================================================================
+    pub fn pause_pool(ctx: Context<PausePool>) -> Result<()> {
+        instructions::pause_pool(ctx)
+    }
+
+    pub fn unpause_pool(ctx: Context<UnpausePool>) -> Result<()> {
+        instructions::unpause_pool(ctx)
+    }
+
+    pub fn set_kill_switch(ctx: Context<SetKillSwitch>, halted: bool) -> Result<()> {
+        instructions::set_kill_switch(ctx, halted)
+    }
+
+    // ============================================================================
+    // Fee Claims & Referrals
+    // ============================================================================
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer(ctx)
+    }
+
+    pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
+        instructions::claim_fees(ctx)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees(ctx)
+    }
+
+    pub fn claim_protocol_fees(ctx: Context<ClaimProtocolFees>) -> Result<()> {
+        instructions::claim_protocol_fees(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees(ctx)
+    }
+
+    pub fn execute_buyback<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteBuyback<'info>>,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::execute_buyback(ctx, min_tokens_out)
+    }
+
     // ============================================================================
     // Migrate Liquidity Pool Bonding Curve to DEX
     // ============================================================================
@@ -144,6 +281,58 @@ pub mod bonding_curve {
         instructions::remove_whitelist(ctx, user)
     }
 
+    pub fn set_whitelist_manager(ctx: Context<SetWhitelistManager>, new_manager: Pubkey) -> Result<()> {
+        instructions::set_whitelist_manager(ctx, new_manager)
+    }
+
+    pub fn set_whitelist_merkle_root(
+        ctx: Context<SetWhitelistMerkleRoot>,
+        merkle_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::set_whitelist_merkle_root(ctx, merkle_root)
+    }
+
+    pub fn set_sale_rounds(ctx: Context<SetSaleRounds>, rounds: Vec<SaleRound>) -> Result<()> {
+        instructions::set_sale_rounds(ctx, rounds)
+    }
+
+    pub fn set_buyer_tier(ctx: Context<SetBuyerTier>, tier: u8) -> Result<()> {
+        instructions::set_buyer_tier(ctx, tier)
+    }
+
+    pub fn set_whitelist_vesting(
+        ctx: Context<SetWhitelistVesting>,
+        vesting: Option<Vesting>,
+    ) -> Result<()> {
+        instructions::set_whitelist_vesting(ctx, vesting)
+    }
+
+    pub fn purchase_whitelist(
+        ctx: Context<PurchaseWhitelist>,
+        token_amount: u64,
+        proof: Option<WhitelistProof>,
+    ) -> Result<()> {
+        instructions::purchase_whitelist(ctx, token_amount, proof)
+    }
+
+    pub fn claim_whitelist_tokens(ctx: Context<ClaimWhitelistTokens>) -> Result<()> {
+        instructions::claim_whitelist_tokens(ctx)
+    }
+
+    pub fn withdraw_whitelist_proceeds(ctx: Context<WithdrawWhitelistProceeds>) -> Result<()> {
+        instructions::withdraw_whitelist_proceeds(ctx)
+    }
+
+    pub fn fund_whitelist_launch(ctx: Context<FundWhitelistLaunch>, amount: u64) -> Result<()> {
+        instructions::fund_whitelist_launch(ctx, amount)
+    }
+
+    pub fn withdraw_unsold_whitelist_tokens(
+        ctx: Context<WithdrawUnsoldWhitelistTokens>,
+    ) -> Result<()> {
+        instructions::withdraw_unsold_whitelist_tokens(ctx)
+    }
+
     // ============================================================================
     // Fair Launch Functions
     // ============================================================================
@@ -158,6 +347,7 @@ pub mod bonding_curve {
         max_contribution: u64,
         max_tokens_per_wallet: u64,
         distribution_delay: i64,
+        total_tokens: u64,
     ) -> Result<()> {
         instructions::create_fair_launch(
             ctx,
@@ -169,6 +359,7 @@ pub mod bonding_curve {
             max_contribution,
             max_tokens_per_wallet,
             distribution_delay,
+            total_tokens,
         )
     }
 
@@ -176,6 +367,33 @@ pub mod bonding_curve {
         instructions::contribute_fair_launch(ctx, amount)
     }
 
+    pub fn set_fair_launch_liquidity(
+        ctx: Context<SetFairLaunchLiquidity>,
+        liquidity_bps: u16,
+        liquidity_tokens: u64,
+        migrate_to: Option<Dex>,
+    ) -> Result<()> {
+        instructions::set_fair_launch_liquidity(ctx, liquidity_bps, liquidity_tokens, migrate_to)
+    }
+
+    pub fn set_fair_launch_oversubscription(
+        ctx: Context<SetFairLaunchOversubscription>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_fair_launch_oversubscription(ctx, enabled)
+    }
+
+    pub fn set_fair_launch_vesting(
+        ctx: Context<SetFairLaunchVesting>,
+        vesting: Option<Vesting>,
+    ) -> Result<()> {
+        instructions::set_fair_launch_vesting(ctx, vesting)
+    }
+
+    pub fn finalize_fair_launch(ctx: Context<FinalizeFairLaunch>) -> Result<()> {
+        instructions::finalize_fair_launch(ctx)
+    }
+
     pub fn distribute_tokens(ctx: Context<DistributeTokens>) -> Result<()> {
         instructions::distribute_tokens(ctx)
     }
@@ -184,6 +402,118 @@ pub mod bonding_curve {
         instructions::refund_contribution(ctx)
     }
 
+    pub fn fund_fair_launch(ctx: Context<FundFairLaunch>, amount: u64) -> Result<()> {
+        instructions::fund_fair_launch(ctx, amount)
+    }
+
+    pub fn withdraw_unsold_fair_launch_tokens(
+        ctx: Context<WithdrawUnsoldFairLaunchTokens>,
+    ) -> Result<()> {
+        instructions::withdraw_unsold_fair_launch_tokens(ctx)
+    }
+
+    // ============================================================================
+    // Dutch Auction Launchpad Functions
+    // ============================================================================
+    pub fn create_dutch_auction(
+        ctx: Context<CreateDutchAuction>,
+        start_time: i64,
+        end_time: i64,
+        start_price: u64,
+        floor_price: u64,
+        decay: DecayCurve,
+        total_tokens: u64,
+    ) -> Result<()> {
+        instructions::create_dutch_auction(
+            ctx,
+            start_time,
+            end_time,
+            start_price,
+            floor_price,
+            decay,
+            total_tokens,
+        )
+    }
+
+    pub fn fund_dutch_auction(ctx: Context<FundDutchAuction>, amount: u64) -> Result<()> {
+        instructions::fund_dutch_auction(ctx, amount)
+    }
+
+    pub fn bid_dutch_auction(
+        ctx: Context<BidDutchAuction>,
+        token_amount: u64,
+        max_price: u64,
+    ) -> Result<()> {
+        instructions::bid_dutch_auction(ctx, token_amount, max_price)
+    }
+
+    pub fn settle_dutch_auction(ctx: Context<SettleDutchAuction>) -> Result<()> {
+        instructions::settle_dutch_auction(ctx)
+    }
+
+    pub fn claim_dutch_auction(ctx: Context<ClaimDutchAuction>) -> Result<()> {
+        instructions::claim_dutch_auction(ctx)
+    }
+
+    pub fn withdraw_dutch_auction_proceeds(
+        ctx: Context<WithdrawDutchAuctionProceeds>,
+    ) -> Result<()> {
+        instructions::withdraw_dutch_auction_proceeds(ctx)
+    }
+
+    pub fn withdraw_unsold_dutch_auction_tokens(
+        ctx: Context<WithdrawUnsoldDutchAuctionTokens>,
+    ) -> Result<()> {
+        instructions::withdraw_unsold_dutch_auction_tokens(ctx)
+    }
+
+    // ============================================================================
+    // Batch Auction Launchpad Functions
+    // ============================================================================
+    pub fn create_batch_auction(
+        ctx: Context<CreateBatchAuction>,
+        start_time: i64,
+        end_time: i64,
+        min_price: u64,
+        tick_size: u64,
+        min_bid: u64,
+        total_tokens: u64,
+    ) -> Result<()> {
+        instructions::create_batch_auction(ctx, start_time, end_time, min_price, tick_size, min_bid, total_tokens)
+    }
+
+    pub fn fund_batch_auction(ctx: Context<FundBatchAuction>, amount: u64) -> Result<()> {
+        instructions::fund_batch_auction(ctx, amount)
+    }
+
+    pub fn bid_batch_auction(ctx: Context<BidBatchAuction>, max_price: u64, amount: u64) -> Result<()> {
+        instructions::bid_batch_auction(ctx, max_price, amount)
+    }
+
+    pub fn crank_batch_auction(ctx: Context<CrankBatchAuction>) -> Result<()> {
+        instructions::crank_batch_auction(ctx)
+    }
+
+    pub fn refund_batch_auction_bid(ctx: Context<RefundBatchAuctionBid>) -> Result<()> {
+        instructions::refund_batch_auction_bid(ctx)
+    }
+
+    pub fn claim_batch_auction(ctx: Context<ClaimBatchAuction>) -> Result<()> {
+        instructions::claim_batch_auction(ctx)
+    }
+
+    pub fn withdraw_batch_auction_proceeds(
+        ctx: Context<WithdrawBatchAuctionProceeds>,
+    ) -> Result<()> {
+        instructions::withdraw_batch_auction_proceeds(ctx)
+    }
+
+    pub fn withdraw_unsold_batch_auction_tokens(
+        ctx: Context<WithdrawUnsoldBatchAuctionTokens>,
+    ) -> Result<()> {
+        instructions::withdraw_unsold_batch_auction_tokens(ctx)
+    }
+
     // ============================================================================
     // Admin Pause & Unpause Functions : Launchpad
     // ============================================================================
@@ -196,20 +526,75 @@ pub mod bonding_curve {
         instructions::unpause_launchpad(ctx)
     }
 
+    pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+        instructions::cancel_launch(ctx)
+    }
+
+    pub fn refund_whitelist_purchase(ctx: Context<RefundWhitelistPurchase>) -> Result<()> {
+        instructions::refund_whitelist_purchase(ctx)
+    }
+
+    pub fn refund_dutch_auction_bid(ctx: Context<RefundDutchAuctionBid>) -> Result<()> {
+        instructions::refund_dutch_auction_bid(ctx)
+    }
+
     // ============================================================================
     // Token Distribution & Vesting
     // ============================================================================
+    pub fn create_tokenomics_plan(
+        ctx: Context<CreateTokenomicsPlan>,
+        total_supply: u64,
+        categories: Vec<CategoryShare>,
+    ) -> Result<()> {
+        instructions::create_tokenomics_plan(ctx, total_supply, categories)
+    }
+
     pub fn create_allocation(
         ctx: Context<CreateAllocation>,
-        percentage: u8,
+        category: AllocationCategory,
         total_tokens: u64,
         vesting: Option<Vesting>,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::create_allocation(ctx, category, total_tokens, vesting, revocable)
+    }
+
+    pub fn create_allocations_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateAllocationsBatch<'info>>,
+        category: AllocationCategory,
+        entries: Vec<AllocationEntry>,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::create_allocations_batch(ctx, category, entries, revocable)
+    }
+
+    pub fn fund_allocation(ctx: Context<FundAllocation>, amount: u64) -> Result<()> {
+        instructions::fund_allocation(ctx, amount)
+    }
+
+    pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<u64> {
+        instructions::claim_tokens(ctx)
+    }
+
+    pub fn revoke_allocation(ctx: Context<RevokeAllocation>) -> Result<()> {
+        instructions::revoke_allocation(ctx)
+    }
+
+    pub fn update_allocation(
+        ctx: Context<UpdateAllocation>,
+        milestones: Option<Vec<Milestone>>,
+        milestone_oracle: Option<Pubkey>,
+        transferable: Option<bool>,
     ) -> Result<()> {
-        instructions::create_allocation(ctx, percentage, total_tokens, vesting)
+        instructions::update_allocation(ctx, milestones, milestone_oracle, transferable)
+    }
+
+    pub fn transfer_allocation(ctx: Context<TransferAllocation>) -> Result<()> {
+        instructions::transfer_allocation(ctx)
     }
 
-    pub fn claim_tokens(ctx: Context<ClaimTokens>, now: i64) -> Result<u64> {
-        instructions::claim_tokens(ctx, now)
+    pub fn achieve_milestone(ctx: Context<AchieveMilestone>, index: u8) -> Result<()> {
+        instructions::achieve_milestone(ctx, index)
     }
 
     // ==========================================================================
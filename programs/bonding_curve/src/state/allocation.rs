@@ -1,3 +1,5 @@
+use crate::consts::MAX_MILESTONES;
+use crate::errors::{CommonCustomError, LaunchPadCustomErrror};
 use anchor_lang::prelude::*;
 
 
@@ -8,7 +10,67 @@ pub struct Vesting {
     pub start_time: i64,           // unix timestamp when vesting starts
     pub duration: i64,             // total vesting duration in seconds
     pub interval: i64,             // interval between releases (seconds)
-    pub released: u64,             // tokens already released
+    pub tge_bps: u16,              // share unlocked at start_time in basis points
+}
+
+impl Vesting {
+    /// Tokens of `total` vested at `now`: the TGE share at `start_time`,
+    /// then the rest linearly over `duration` once the cliff has passed,
+    /// unlocking in `interval` steps when set. Never more than `total`
+    pub fn vested_amount(&self, total: u64, now: i64) -> Result<u64> {
+        if now < self.start_time {
+            return Ok(0);
+        }
+
+        let tge_amount = (total as u128)
+            .checked_mul(self.tge_bps as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(10000)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)? as u64;
+
+        let vesting_start = self.start_time.saturating_add(self.cliff_period);
+        if now < vesting_start {
+            return Ok(tge_amount);
+        }
+
+        let mut elapsed = now - vesting_start;
+        if elapsed >= self.duration {
+            return Ok(total);
+        }
+
+        // Only whole intervals count, e.g. monthly unlocks
+        if self.interval > 0 {
+            elapsed -= elapsed % self.interval;
+        }
+
+        let linear_amount = ((total - tge_amount) as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(self.duration as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)? as u64;
+
+        Ok(tge_amount.saturating_add(linear_amount).min(total))
+    }
+
+    /// Before the cliff only the TGE share is unlocked
+    pub fn cliff_reached(&self, now: i64) -> bool {
+        now >= self.start_time.saturating_add(self.cliff_period)
+    }
+
+    /// Periods can't be negative and the TGE share can't exceed 100%
+    pub fn is_valid(&self) -> bool {
+        self.cliff_period >= 0
+            && self.duration >= 0
+            && self.interval >= 0
+            && self.tge_bps <= 10000
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct Milestone {
+    pub description_hash: [u8; 32], // hash of the off-chain milestone description
+    pub unlock_bps: u16,             // share of total_tokens unlocked when achieved, in basis points
+    pub achieved: bool,              // set by the milestone oracle or the DAO governance
 }
 
 #[account]
@@ -20,4 +82,194 @@ pub struct Allocation {
     pub claimed_tokens: u64,       // Amount already claimed
     pub vesting: Option<Vesting>,  // Optional vesting schedule
     pub bump: u8,                  // PDA bump
-}
\ No newline at end of file
+    pub authority: Pubkey,         // Creator, allowed to revoke
+    pub revocable: bool,           // Unvested tokens can be clawed back by the authority
+    pub revoked_at: Option<i64>,   // Vesting is frozen at this timestamp once revoked
+    pub milestone_oracle: Pubkey,  // Wallet allowed to mark milestones achieved besides the DAO governance
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>, // Milestone-gated share of total_tokens, the rest follows vesting
+    pub category: AllocationCategory, // Tokenomics category the allocation is budgeted from
+    pub shared_vault: bool,        // Tokens sit in the tokenomics plan vault instead of the allocation ATA
+    pub funded_tokens: u64,        // Tokens deposited for this allocation
+    pub transferable: bool,        // The recipient can move the position to another wallet, set by the authority
+}
+
+impl Allocation {
+    /// Tokens vested at `now`, frozen at the revocation time once revoked.
+    /// The milestone share unlocks with achieved milestones, the rest follows the vesting schedule
+    pub fn vested(&self, now: i64) -> Result<u64> {
+        let now = match self.revoked_at {
+            Some(revoked_at) => now.min(revoked_at),
+            None => now,
+        };
+        let time_based_tokens = self.total_tokens.saturating_sub(self.milestone_tokens(false)?);
+        let time_vested = match &self.vesting {
+            Some(vesting) => vesting.vested_amount(time_based_tokens, now)?,
+            None => time_based_tokens,
+        };
+        Ok(time_vested.saturating_add(self.milestone_tokens(true)?).min(self.total_tokens))
+    }
+
+    /// Tokens gated by milestones, only the achieved ones with `achieved_only`
+    pub fn milestone_tokens(&self, achieved_only: bool) -> Result<u64> {
+        let unlock_bps: u64 = self
+            .milestones
+            .iter()
+            .filter(|milestone| milestone.achieved || !achieved_only)
+            .map(|milestone| milestone.unlock_bps as u64)
+            .sum();
+        let tokens = (self.total_tokens as u128)
+            .checked_mul(unlock_bps as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(10000)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        u64::try_from(tokens).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+
+    /// Tokens vested at `now` that haven't been claimed yet, `claimed_tokens` is the only claim counter.
+    /// A shared vault also holds other allocations, so only the tokens funded for this one can be claimed
+    pub fn claimable(&self, now: i64) -> Result<u64> {
+        let vested = self.vested(now)?;
+        let vested = if self.shared_vault { vested.min(self.funded_tokens) } else { vested };
+        Ok(vested.saturating_sub(self.claimed_tokens))
+    }
+
+    /// Record `amount` tokens deposited for this allocation, never more than `total_tokens`
+    pub fn fund(&mut self, amount: u64) -> Result<()> {
+        let funded = self
+            .funded_tokens
+            .checked_add(amount)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        if funded > self.total_tokens {
+            return Err(CommonCustomError::InvalidAmount.into());
+        }
+        self.funded_tokens = funded;
+        Ok(())
+    }
+
+    /// Owner of the vault holding the allocation tokens
+    pub fn vault_owner(&self, allocation: Pubkey, tokenomics_plan: Pubkey) -> Pubkey {
+        if self.shared_vault {
+            tokenomics_plan
+        } else {
+            allocation
+        }
+    }
+
+    /// Tokens of the vault still held for this allocation
+    pub fn held_tokens(&self, vault_amount: u64) -> u64 {
+        if self.shared_vault {
+            self.funded_tokens.saturating_sub(self.claimed_tokens)
+        } else {
+            vault_amount
+        }
+    }
+}
+
+/// Allocation requested by `create_allocations_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllocationEntry {
+    pub wallet: Pubkey,            // Recipient wallet
+    pub total_tokens: u64,
+    pub vesting: Option<Vesting>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AllocationCategory {
+    Team,
+    Advisors,
+    Treasury,
+    Liquidity,
+    Sale,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct CategoryBudget {
+    pub category: AllocationCategory,
+    pub percentage: u8,            // % of the plan total supply
+    pub allocated: u64,            // Tokens promised to allocations in this category
+    pub funded: u64,               // Tokens deposited into this category's allocation vaults
+}
+
+/// Category share requested when creating a tokenomics plan
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CategoryShare {
+    pub category: AllocationCategory,
+    pub percentage: u8, // % of the plan total supply
+}
+
+/// Per-mint tokenomics, every allocation must fit within its category budget
+#[account]
+#[derive(InitSpace)]
+pub struct TokenomicsPlan {
+    pub authority: Pubkey,         // Mint creator, the only one allowed to create allocations
+    pub token_mint: Pubkey,
+    pub total_supply: u64,         // Supply the category percentages apply to
+    #[max_len(5)]
+    pub categories: Vec<CategoryBudget>,
+    pub bump: u8,
+}
+
+impl TokenomicsPlan {
+    /// Tokens budgeted for `category`
+    pub fn budget(&self, category: AllocationCategory) -> Result<u64> {
+        let entry = self.category(category)?;
+        let budget = (self.total_supply as u128)
+            .checked_mul(entry.percentage as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(100)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        u64::try_from(budget).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+
+    pub fn category(&self, category: AllocationCategory) -> Result<&CategoryBudget> {
+        self.categories
+            .iter()
+            .find(|entry| entry.category == category)
+            .ok_or(LaunchPadCustomErrror::CategoryNotInPlan.into())
+    }
+
+    pub fn category_mut(&mut self, category: AllocationCategory) -> Result<&mut CategoryBudget> {
+        self.categories
+            .iter_mut()
+            .find(|entry| entry.category == category)
+            .ok_or(LaunchPadCustomErrror::CategoryNotInPlan.into())
+    }
+
+    /// Reserve `amount` tokens of the `category` budget for a new allocation
+    pub fn allocate(&mut self, category: AllocationCategory, amount: u64) -> Result<()> {
+        let budget = self.budget(category)?;
+        let entry = self.category_mut(category)?;
+        let allocated = entry
+            .allocated
+            .checked_add(amount)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        if allocated > budget {
+            return Err(LaunchPadCustomErrror::CategoryBudgetExceeded.into());
+        }
+        entry.allocated = allocated;
+        Ok(())
+    }
+
+    /// Record `amount` tokens deposited for `category`, never more than it allocated
+    pub fn fund(&mut self, category: AllocationCategory, amount: u64) -> Result<()> {
+        let entry = self.category_mut(category)?;
+        let funded = entry
+            .funded
+            .checked_add(amount)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        if funded > entry.allocated {
+            return Err(LaunchPadCustomErrror::CategoryBudgetExceeded.into());
+        }
+        entry.funded = funded;
+        Ok(())
+    }
+
+    /// Give `unvested` tokens clawed back from a revoked allocation back to the `category` budget
+    pub fn release(&mut self, category: AllocationCategory, unvested: u64, clawback: u64) -> Result<()> {
+        let entry = self.category_mut(category)?;
+        entry.allocated = entry.allocated.saturating_sub(unvested);
+        entry.funded = entry.funded.saturating_sub(clawback);
+        Ok(())
+    }
+}
@@ -3,11 +3,25 @@ use crate::errors::CommonCustomError;
 use crate::state::curve_configuration::{
     BondingCurveType, CurveConfiguration, CurveConfigurationAccount,
 };
+use crate::state::fees::{FeeBreakdown, FeeRates};
 use crate::utils::calc::*;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Dex {
+    Meteora,
+    PumpSwap,
+}
+
+/// DEX pool the bonding curve liquidity was migrated to
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MigratedPool {
+    pub dex: Dex,
+    pub pool: Pubkey,
+}
+
 /// BONDING CURVE ACCOUNT
 #[account]
 pub struct BondingCurve {
@@ -17,11 +31,15 @@ pub struct BondingCurve {
     pub reserve_token: u64,   // Tracks the token reserve balance
     pub token: Pubkey,        // Public key of the token in the liquidity pool
     pub bump: u8,             // Bump seed for PDA
+    pub total_burned: u64,       // Tokens bought back and burned
+    pub buyback_sol_spent: u64,  // SOL spent on buybacks
+    pub migration: Option<MigratedPool>, // Set once the liquidity is migrated to a DEX
 }
 
 impl BondingCurve {
     // Discriminator (8) + Pubkey(32) + u64(8) + u64(8) + u64(8) + Pubkey(32) + u16(2) + u8(1)
-    pub const ACCOUNT_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 32 + 2 + 1;
+    // + u64(8) + u64(8) + Option<MigratedPool>(1 + 1 + 32)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 32 + 2 + 1 + 8 + 8 + 1 + 1 + 32;
     pub fn new(creator: Pubkey, token: Pubkey, bump: u8) -> Self {
         Self {
             creator,
@@ -30,6 +48,9 @@ impl BondingCurve {
             reserve_token: 0,
             token,
             bump,
+            total_burned: 0,
+            buyback_sol_spent: 0,
+            migration: None,
         }
     }
 
@@ -106,15 +127,21 @@ pub trait BondingCurveAccount<'info> {
             &mut InterfaceAccount<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
+        fee_vaults: (
+            // pool fee vault
+            &mut AccountInfo<'info>,
+            // referral account, if a referrer was passed
+            Option<&mut AccountInfo<'info>>,
+        ),
         sol_amount: u64,
-        fee_percentage: u16,
+        fee_rates: FeeRates,
         authority: &Signer<'info>,
         bonding_curve_type: u8,
         // target liquidity for migration
         target_liquidity: u64,
         token_program: &Interface<'info, TokenInterface>,
         system_program: &Program<'info, System>,
-    ) -> Result<()>;
+    ) -> Result<FeeBreakdown>;
 
     fn sell(
         &mut self,
@@ -125,8 +152,14 @@ pub trait BondingCurveAccount<'info> {
             &mut InterfaceAccount<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
+        fee_vaults: (
+            // pool fee vault
+            &mut AccountInfo<'info>,
+            // referral account, if a referrer was passed
+            Option<&mut AccountInfo<'info>>,
+        ),
         amount: u64,
-        fee_percentage: u16,
+        fee_rates: FeeRates,
         bump: u8,
         authority: &Signer<'info>,
         bonding_curve_type: u8,
@@ -134,6 +167,54 @@ pub trait BondingCurveAccount<'info> {
         target_liquidity: u64,
         token_program: &Interface<'info, TokenInterface>,
         system_program: &Program<'info, System>,
+    ) -> Result<FeeBreakdown>;
+
+    // Replaces the pool fee with the launch fee in effect at the current time
+    fn apply_launch_fee(
+        &self,
+        bonding_configuration_account: &CurveConfiguration,
+        fee_rates: FeeRates,
+    ) -> Result<FeeRates>;
+
+    // Moves the trade fees from the trader into the fee vault and referral account
+    fn collect_fees(
+        &self,
+        bonding_configuration_account: &mut Account<'info, CurveConfiguration>,
+        fees: &FeeBreakdown,
+        fee_vaults: (&mut AccountInfo<'info>, Option<&mut AccountInfo<'info>>),
+        authority: &Signer<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
+
+    // Buys tokens from the curve with the fee vault SOL and burns them
+    fn buyback_and_burn(
+        &mut self,
+        bonding_configuration_account: &CurveConfiguration,
+        token_accounts: (
+            // token mint
+            &mut InterfaceAccount<'info, Mint>,
+            // pool token account
+            &mut InterfaceAccount<'info, TokenAccount>,
+        ),
+        sol_vaults: (
+            // pool sol vault
+            &mut AccountInfo<'info>,
+            // fee vault and its bump
+            &mut AccountInfo<'info>,
+            u8,
+        ),
+        sol_amount: u64,
+        min_tokens_out: u64,
+        token_program: &Interface<'info, TokenInterface>,
+        system_program: &Program<'info, System>,
+    ) -> Result<u64>;
+
+    fn burn_from_pool(
+        &mut self,
+        pool_token_account: &InterfaceAccount<'info, TokenAccount>,
+        token_mint: &InterfaceAccount<'info, Mint>,
+        amount: u64,
+        token_program: &Interface<'info, TokenInterface>,
     ) -> Result<()>;
 
     fn transfer_sol_to_pool(
@@ -147,7 +228,7 @@ pub trait BondingCurveAccount<'info> {
     fn transfer_sol_from_pool(
         &self,
         from: &mut AccountInfo<'info>,
-        to: &Signer<'info>,
+        to: &AccountInfo<'info>,
         amount: u64,
         bump: u8,
         system_program: &Program<'info, System>,
@@ -315,20 +396,23 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             &mut InterfaceAccount<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
+        fee_vaults: (&mut AccountInfo<'info>, Option<&mut AccountInfo<'info>>),
         sol_amount: u64,
-        fee_percentage: u16,
+        fee_rates: FeeRates,
         authority: &Signer<'info>,
         bonding_curve_type: u8,
         // target liquidity for migration
         target_liquidity: u64,
         token_program: &Interface<'info, TokenInterface>,
         system_program: &Program<'info, System>,
-    ) -> Result<()> {
+    ) -> Result<FeeBreakdown> {
         let amount_out = self.calculate_buy_cost(sol_amount, bonding_curve_type, bonding_configuration_account.reserve_ratio)?;
 
         msg!("amount out in token {:?}", amount_out);
-        let fee_in_sol = amount_out * (fee_percentage as u64) / 10000;
-        msg!("fee in sol {:?}", fee_in_sol);
+        // fees are charged in SOL on top of the amount paid into the reserve
+        let fee_rates = self.apply_launch_fee(bonding_configuration_account, fee_rates)?;
+        let fees = fee_rates.compute(sol_amount)?;
+        msg!("fees in sol {:?}", fees);
 
         // make sure the bonding curve SOL liquility is not hit target liquidity
         if self.reserve_balance + sol_amount > target_liquidity {
@@ -347,9 +431,15 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             token_program,
         )?;
         // Collect fees
-        bonding_configuration_account.calculate_fee(fee_in_sol)?;
+        self.collect_fees(
+            bonding_configuration_account,
+            &fees,
+            fee_vaults,
+            authority,
+            system_program,
+        )?;
 
-        Ok(())
+        Ok(fees)
     }
 
     fn sell(
@@ -364,8 +454,9 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             &mut InterfaceAccount<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
+        fee_vaults: (&mut AccountInfo<'info>, Option<&mut AccountInfo<'info>>),
         token_amount: u64,
-        fee_percentage: u16,
+        fee_rates: FeeRates,
         bump: u8,
         authority: &Signer<'info>,
         bonding_curve_type: u8,
@@ -373,14 +464,17 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         target_liquidity: u64,
         token_program: &Interface<'info, TokenInterface>,
         system_program: &Program<'info, System>,
-    ) -> Result<()> {
+    ) -> Result<FeeBreakdown> {
         let amount_out = self.calculate_sell_cost(
             token_amount,
             bonding_curve_type,
             bonding_configuration_account.reserve_ratio,
         )?;
         msg!("amount out in SOL {:?}", amount_out);
-        let fee = amount_out * (fee_percentage as u64) / 10000;
+        // fees are deducted from the SOL paid out to the seller
+        let fee_rates = self.apply_launch_fee(bonding_configuration_account, fee_rates)?;
+        let fees = fee_rates.compute(amount_out)?;
+        msg!("fees in sol {:?}", fees);
 
         // make sure the bonding curve SOL liquility is not hit target liquidity
         if self.reserve_balance + amount_out > target_liquidity {
@@ -412,8 +506,144 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             SOL_VAULT_PREFIX.as_bytes(),
         )?;
 
-        bonding_configuration_account.calculate_fee(fee)?;
+        self.collect_fees(
+            bonding_configuration_account,
+            &fees,
+            fee_vaults,
+            authority,
+            system_program,
+        )?;
+
+        Ok(fees)
+    }
+
+    fn apply_launch_fee(
+        &self,
+        bonding_configuration_account: &CurveConfiguration,
+        fee_rates: FeeRates,
+    ) -> Result<FeeRates> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let pool_fee_bps = bonding_configuration_account.effective_fee_percentage(current_time)?;
+        // platform fees can be raised after the pool was created, the launch fee gives way so
+        // the layers never add up to more than the trade
+        let other_fees_bps = fee_rates
+            .protocol_fee_bps
+            .saturating_add(fee_rates.creator_fee_bps)
+            .saturating_add(fee_rates.referrer_fee_bps);
+        let pool_fee_bps = pool_fee_bps.min(10000u16.saturating_sub(other_fees_bps));
+        msg!("effective pool fee {:?} bps", pool_fee_bps);
+
+        Ok(FeeRates {
+            pool_fee_bps,
+            ..fee_rates
+        })
+    }
+
+    fn collect_fees(
+        &self,
+        bonding_configuration_account: &mut Account<'info, CurveConfiguration>,
+        fees: &FeeBreakdown,
+        fee_vaults: (&mut AccountInfo<'info>, Option<&mut AccountInfo<'info>>),
+        authority: &Signer<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let (fee_vault, referral_vault) = fee_vaults;
+
+        let vault_fees = fees.vault_total()?;
+        if vault_fees > 0 {
+            self.transfer_sol_to_pool(authority, fee_vault, vault_fees, system_program)?;
+        }
+
+        if fees.referrer_fee > 0 {
+            let referral_vault = referral_vault.ok_or(CommonCustomError::InvalidReferrer)?;
+            self.transfer_sol_to_pool(authority, referral_vault, fees.referrer_fee, system_program)?;
+        }
+
+        bonding_configuration_account.accrue_fees(fees)
+    }
+
+    fn buyback_and_burn(
+        &mut self,
+        bonding_configuration_account: &CurveConfiguration,
+        token_accounts: (
+            &mut InterfaceAccount<'info, Mint>,
+            &mut InterfaceAccount<'info, TokenAccount>,
+        ),
+        sol_vaults: (&mut AccountInfo<'info>, &mut AccountInfo<'info>, u8),
+        sol_amount: u64,
+        min_tokens_out: u64,
+        token_program: &Interface<'info, TokenInterface>,
+        system_program: &Program<'info, System>,
+    ) -> Result<u64> {
+        let (pool_sol_vault, fee_vault, fee_vault_bump) = sol_vaults;
+        let bonding_curve_type: u8 = bonding_configuration_account.bonding_curve_type.into();
+
+        let amount_out = self.calculate_buy_cost(
+            sol_amount,
+            bonding_curve_type,
+            bonding_configuration_account.reserve_ratio,
+        )?;
+        msg!("buyback amount out in token {:?}", amount_out);
+
+        if amount_out < min_tokens_out {
+            return err!(CommonCustomError::SlippageExceeded);
+        }
+        if amount_out > self.reserve_token {
+            return err!(CommonCustomError::InsufficientBalance);
+        }
+
+        self.total_supply += amount_out;
+        self.reserve_balance += sol_amount;
+        self.reserve_token -= amount_out;
+
+        // the fee vault pays the curve the same way a buyer would, without trade fees
+        self.transfer_sol_from_pool(
+            fee_vault,
+            pool_sol_vault,
+            sol_amount,
+            fee_vault_bump,
+            system_program,
+            FEE_POOL_VAULT_PREFIX.as_bytes(),
+        )?;
+
+        self.burn_from_pool(token_accounts.1, token_accounts.0, amount_out, token_program)?;
+
+        self.buyback_sol_spent = self
+            .buyback_sol_spent
+            .checked_add(sol_amount)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+        Ok(amount_out)
+    }
+
+    fn burn_from_pool(
+        &mut self,
+        pool_token_account: &InterfaceAccount<'info, TokenAccount>,
+        token_mint: &InterfaceAccount<'info, Mint>,
+        amount: u64,
+        token_program: &Interface<'info, TokenInterface>,
+    ) -> Result<()> {
+        anchor_spl::token_interface::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                anchor_spl::token_interface::Burn {
+                    mint: token_mint.to_account_info(),
+                    from: pool_token_account.to_account_info(),
+                    authority: self.to_account_info(),
+                },
+                &[&[
+                    POOL_SEED_PREFIX.as_bytes(),
+                    self.token.key().as_ref(),
+                    &[self.bump],
+                ]],
+            ),
+            amount,
+        )?;
 
+        self.total_burned = self
+            .total_burned
+            .checked_add(amount)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
         Ok(())
     }
 
@@ -441,7 +671,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
     fn transfer_sol_from_pool(
         &self,
         from: &mut AccountInfo<'info>,
-        to: &Signer<'info>,
+        to: &AccountInfo<'info>,
         amount: u64,
         bump: u8,
         system_program: &Program<'info, System>,
@@ -1,4 +1,7 @@
+use crate::consts::MAX_FEE_RECIPIENTS;
 use crate::errors::CommonCustomError;
+use crate::state::fees::{FeeBreakdown, FeeRates, LaunchFeeSchedule};
+use crate::state::platform_config::PlatformConfig;
 use anchor_lang::prelude::*;
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -29,12 +32,51 @@ impl From<BondingCurveType> for u8 {
     }
 }
 
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RecipientKind {
+    Wallet,      // Fees are claimed by the recipient address
+    BuybackBurn, // Fees are spent by `execute_buyback` to buy and burn the token
+}
+
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct Recipient {
     pub address: Pubkey,
     pub share: u16, // Share in basis points (e.g., 5000 = 50%)
     pub amount: u64,
     pub locking_period: i64,
+    pub kind: RecipientKind,
+}
+
+impl Recipient {
+    // Pubkey(32) + u16(2) + u64(8) + i64(8) + RecipientKind(1)
+    pub const SIZE: usize = 32 + 2 + 8 + 8 + 1;
+}
+
+/// New share for an existing fee recipient
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RecipientShare {
+    pub address: Pubkey,
+    pub share: u16, // Share in basis points (e.g., 5000 = 50%)
+}
+
+/// Check the recipients shares add up to 100% and there is at most one buyback recipient
+pub fn validate_fee_recipients(recipients: &[Recipient]) -> Result<()> {
+    if recipients.len() > MAX_FEE_RECIPIENTS {
+        return Err(CommonCustomError::TooManyFeeRecipients.into());
+    }
+    let total_share: u32 = recipients.iter().map(|r| r.share as u32).sum();
+    if total_share != 10000 {
+        return Err(CommonCustomError::InvalidSharePercentage.into());
+    }
+
+    let buyback_recipients = recipients
+        .iter()
+        .filter(|r| r.kind == RecipientKind::BuybackBurn)
+        .count();
+    if buyback_recipients > 1 {
+        return Err(CommonCustomError::MultipleBuybackRecipients.into());
+    }
+    Ok(())
 }
 
 /// CURVE CONFIGURATION ACCOUNT
@@ -59,13 +101,70 @@ pub struct CurveConfiguration {
     pub fee_recipients: Vec<Recipient>,
     pub total_fees_collected: u64,
     pub reserve_ratio: u16,   // Reserve ratio in basis points (default: 50%)
+    pub creator_fee_bps: u16,       // Creator fee in basis points, paid to BondingCurve.creator
+    pub creator_fees_accrued: u64,  // Unclaimed creator fees held in the fee vault
+    pub protocol_fees_accrued: u64, // Unclaimed protocol fees held in the fee vault
+    pub created_at: i64,            // Pool creation timestamp
+    pub launch_fee_schedule: Option<LaunchFeeSchedule>, // Optional anti-sniping fee schedule
+    pub removed_fee_recipients: Vec<Recipient>, // Removed recipients with unclaimed fees
+    pub paused: bool,                           // Emergency pause, blocks trading on this pool
 }
 
 impl CurveConfiguration {
-    // Discriminator (8) + Pubkey(32) + Pubkey(32) + u64(8) + bool(1) + u16(2) + Pubkey(32) + u16(2) + bool(1) + u64(8) + u16(2) + bool(1) + u8(1) + u64(8) + i64(8) + u16(2) + u64(8) + u64(8)
-    // todo : limit number of fee recipients for init account
-    pub const ACCOUNT_SIZE: usize =
-        8 + 32 + 8 + 1 + 2 + 32 + 2 + 1 + 8 + 2 + 1 + 1 + 8 + 8 + 2 + 8 + 8 + 500;
+    // Discriminator (8) + Pubkey(32) + Pubkey(32) + u64(8) + bool(1) + Pubkey(32) + u16(2) + bool(1) + u64(8) + u16(2) + bool(1) + u8(1) + u64(8) + i64(8) + u16(2) + u64(8) + u64(8)
+    // + u64(8) + u16(2) for total_fees_collected & reserve_ratio
+    // + u16(2) + u64(8) + u64(8) for the creator & protocol fee layers
+    // + i64(8) + Option<LaunchFeeSchedule>(1 + 13) for the launch fee schedule
+    // + Vec<Recipient>(4) + Vec<Recipient>(4) for the active & removed fee recipients
+    // + bool(1) for the pause flag
+    pub const BASE_SIZE: usize =
+        8 + 32 + 32 + 8 + 1 + 32 + 2 + 1 + 8 + 2 + 1 + 1 + 8 + 8 + 2 + 8 + 8
+            + 8 + 2
+            + 2 + 8 + 8
+            + 8 + 1 + LaunchFeeSchedule::SIZE
+            + 4 + 4
+            + 1;
+
+    // Initial allocation, room for 5 recipients. The account is reallocated when the lists grow
+    pub const ACCOUNT_SIZE: usize = Self::BASE_SIZE + 5 * Recipient::SIZE;
+
+    /// Account size needed to hold the current recipient lists
+    pub fn required_space(&self) -> usize {
+        let recipients = self.fee_recipients.len() + self.removed_fee_recipients.len();
+        (Self::BASE_SIZE + recipients * Recipient::SIZE).max(Self::ACCOUNT_SIZE)
+    }
+
+    /// Keep the unclaimed balance of a recipient that is no longer active
+    fn retire_recipient(&mut self, mut recipient: Recipient) -> Result<()> {
+        if recipient.amount == 0 {
+            return Ok(());
+        }
+        recipient.share = 0;
+        match self
+            .removed_fee_recipients
+            .iter_mut()
+            .find(|r| r.address == recipient.address && r.kind == recipient.kind)
+        {
+            Some(removed) => {
+                removed.amount = removed
+                    .amount
+                    .checked_add(recipient.amount)
+                    .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+            }
+            None => {
+                if self.removed_fee_recipients.len() >= MAX_FEE_RECIPIENTS {
+                    return Err(CommonCustomError::TooManyFeeRecipients.into());
+                }
+                self.removed_fee_recipients.push(recipient);
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop removed recipients once their balance has been paid out
+    pub fn prune_removed_fee_recipients(&mut self) {
+        self.removed_fee_recipients.retain(|r| r.amount > 0);
+    }
 
     pub fn new(
         admin: Pubkey,
@@ -82,14 +181,12 @@ impl CurveConfiguration {
         initial_supply: u64,
         fee_recipients: Vec<Recipient>,
         reserve_ratio: u16,
+        launch_fee_schedule: Option<LaunchFeeSchedule>,
     ) -> Result<Self> {
         let bonding_curve_type =
             BondingCurveType::try_from(bonding_curve_type).unwrap_or(BondingCurveType::Linear);
 
-        let total_share: u16 = fee_recipients.iter().map(|r| r.share).sum();
-        if total_share != 10000 {
-            return Err(CommonCustomError::InvalidSharePercentage.into());
-        }
+        validate_fee_recipients(&fee_recipients)?;
         let current_time = Clock::get()?.unix_timestamp;
 
         // make sure amount is 0 in all recipients in initial state
@@ -100,6 +197,7 @@ impl CurveConfiguration {
                 share: r.share,
                 amount: 0,
                 locking_period: current_time + r.locking_period,
+                kind: r.kind,
             })
             .collect();
 
@@ -123,15 +221,94 @@ impl CurveConfiguration {
             fee_recipients: recipients,
             total_fees_collected: 0,
             reserve_ratio,
+            creator_fee_bps: 0,
+            creator_fees_accrued: 0,
+            protocol_fees_accrued: 0,
+            created_at: current_time,
+            launch_fee_schedule,
+            removed_fee_recipients: vec![],
+            paused: false,
         })
     }
+
+    /// Trading, liquidity and migration are blocked while the pool is paused or the platform is halted
+    pub fn require_not_paused(&self, platform_config: &PlatformConfig) -> Result<()> {
+        platform_config.require_trading_enabled()?;
+        require!(!self.paused, CommonCustomError::PoolPaused);
+        Ok(())
+    }
+
+    /// Pool fee in basis points at `now`, following the launch fee schedule if one is set
+    pub fn effective_fee_percentage(&self, now: i64) -> Result<u16> {
+        if !self.fees_enabled {
+            return Ok(0);
+        }
+        match &self.launch_fee_schedule {
+            Some(schedule) => {
+                schedule.fee_bps_at(self.fee_percentage, now.saturating_sub(self.created_at))
+            }
+            None => Ok(self.fee_percentage),
+        }
+    }
+
+    /// Check the highest fee a trade can pay on this pool, the launch fee at pool creation plus
+    /// the protocol, creator and referrer layers, stays within 100%
+    pub fn validate_fee_layers(&self, platform_config: &PlatformConfig) -> Result<()> {
+        let fee_rates = self.fee_rates(platform_config, true);
+        match &self.launch_fee_schedule {
+            Some(schedule) => {
+                let other_fees_bps = fee_rates
+                    .protocol_fee_bps
+                    .checked_add(fee_rates.creator_fee_bps)
+                    .and_then(|bps| bps.checked_add(fee_rates.referrer_fee_bps))
+                    .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+                schedule.validate(self.fee_percentage, other_fees_bps)
+            }
+            None => fee_rates.total_bps().map(|_| ()),
+        }
+    }
+
+    /// Base fee rates for a trade on this pool, combining the pool, platform and referrer layers.
+    /// The pool fee is replaced by the launch fee in effect when the trade executes.
+    pub fn fee_rates(&self, platform_config: &PlatformConfig, has_referrer: bool) -> FeeRates {
+        let (pool_fee_bps, creator_fee_bps) = if self.fees_enabled {
+            (self.fee_percentage, self.creator_fee_bps)
+        } else {
+            (0, 0)
+        };
+
+        FeeRates {
+            pool_fee_bps,
+            protocol_fee_bps: platform_config.protocol_fee_bps,
+            creator_fee_bps,
+            referrer_fee_bps: if has_referrer {
+                platform_config.referrer_fee_bps
+            } else {
+                0
+            },
+        }
+    }
 }
 
 pub trait CurveConfigurationAccount<'info> {
     fn toggle_dao(&mut self) -> Result<()>;
+    fn set_paused(&mut self, paused: bool) -> Result<()>;
     fn update_fee_percentage(&mut self, new_fee_percentage: u16) -> Result<()>;
     fn calculate_fee(&mut self, amount: u64) -> Result<()>;
+    fn accrue_fees(&mut self, fees: &FeeBreakdown) -> Result<()>;
+    fn set_creator_fee(&mut self, creator_fee_bps: u16) -> Result<()>;
+    fn take_recipient_fees(&mut self, recipient: Pubkey, now: i64) -> Result<u64>;
+    fn take_creator_fees(&mut self) -> Result<u64>;
+    fn buyback_recipient_mut(&mut self) -> Result<&mut Recipient>;
+    fn take_protocol_fees(&mut self) -> Result<u64>;
     fn add_fee_recipients(&mut self, new_recipients: Vec<Recipient>) -> Result<()>;
+    fn remove_fee_recipient(&mut self, address: Pubkey) -> Result<()>;
+    fn update_recipient_shares(&mut self, shares: Vec<RecipientShare>) -> Result<()>;
+    fn realloc_for_recipients(
+        &self,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
     fn change_fee_admin(&mut self, new_fee_admin: Pubkey) -> Result<()>;
     fn set_target_liquidity(&mut self, new_target_liquidity: u64) -> Result<()>;
 }
@@ -145,6 +322,16 @@ impl<'info> CurveConfigurationAccount<'info> for Account<'info, CurveConfigurati
         Ok(())
     }
 
+    fn set_paused(&mut self, paused: bool) -> Result<()> {
+        if paused {
+            require!(!self.paused, CommonCustomError::PoolPaused);
+        } else {
+            require!(self.paused, CommonCustomError::PoolNotPaused);
+        }
+        self.paused = paused;
+        Ok(())
+    }
+
     fn update_fee_percentage(&mut self, new_fee_percentage: u16) -> Result<()> {
         // Maximum fee is 10%
         if new_fee_percentage <= 1000_u16 {
@@ -161,31 +348,131 @@ impl<'info> CurveConfigurationAccount<'info> for Account<'info, CurveConfigurati
             .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
 
         for recipient in self.fee_recipients.iter_mut() {
-            recipient.amount = amount * (recipient.share as u64) / 10000;
+            let share = amount
+                .checked_mul(recipient.share as u64)
+                .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+                / 10000;
+            recipient.amount = recipient
+                .amount
+                .checked_add(share)
+                .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
         }
         Ok(())
     }
 
+    fn accrue_fees(&mut self, fees: &FeeBreakdown) -> Result<()> {
+        // Pool fee is split among the fee recipients
+        self.calculate_fee(fees.pool_fee)?;
+
+        self.creator_fees_accrued = self
+            .creator_fees_accrued
+            .checked_add(fees.creator_fee)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        self.protocol_fees_accrued = self
+            .protocol_fees_accrued
+            .checked_add(fees.protocol_fee)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        Ok(())
+    }
+
+    fn set_creator_fee(&mut self, creator_fee_bps: u16) -> Result<()> {
+        require!(creator_fee_bps <= 10000, CommonCustomError::InvalidFee);
+        self.creator_fee_bps = creator_fee_bps;
+        Ok(())
+    }
+
+    fn take_recipient_fees(&mut self, recipient: Pubkey, now: i64) -> Result<u64> {
+        let mut amount = 0;
+        let configuration: &mut CurveConfiguration = self;
+        // active and removed entries of the same wallet are claimed together
+        for entry in configuration
+            .fee_recipients
+            .iter_mut()
+            .chain(configuration.removed_fee_recipients.iter_mut())
+            .filter(|r| r.address == recipient && r.kind == RecipientKind::Wallet)
+        {
+            if entry.locking_period > now {
+                return Err(CommonCustomError::FeesLocked.into());
+            }
+            amount = entry
+                .amount
+                .checked_add(amount)
+                .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+            entry.amount = 0;
+        }
+
+        if amount == 0 {
+            let is_recipient = self
+                .fee_recipients
+                .iter()
+                .chain(self.removed_fee_recipients.iter())
+                .find(|r| r.address == recipient)
+                .ok_or(CommonCustomError::FeeRecipientNotFound)?;
+            if is_recipient.kind == RecipientKind::BuybackBurn {
+                return Err(CommonCustomError::BuybackNotClaimable.into());
+            }
+            return Err(CommonCustomError::NoFeesToClaim.into());
+        }
+
+        self.prune_removed_fee_recipients();
+        Ok(amount)
+    }
+
+    fn take_creator_fees(&mut self) -> Result<u64> {
+        require!(self.creator_fees_accrued > 0, CommonCustomError::NoFeesToClaim);
+        let amount = self.creator_fees_accrued;
+        self.creator_fees_accrued = 0;
+        Ok(amount)
+    }
+
+    fn buyback_recipient_mut(&mut self) -> Result<&mut Recipient> {
+        // a removed buyback recipient is drained before the active one
+        if let Some(index) = self
+            .removed_fee_recipients
+            .iter()
+            .position(|r| r.kind == RecipientKind::BuybackBurn)
+        {
+            return Ok(&mut self.removed_fee_recipients[index]);
+        }
+        self.fee_recipients
+            .iter_mut()
+            .find(|r| r.kind == RecipientKind::BuybackBurn)
+            .ok_or(CommonCustomError::NoBuybackRecipient.into())
+    }
+
+    fn take_protocol_fees(&mut self) -> Result<u64> {
+        require!(self.protocol_fees_accrued > 0, CommonCustomError::NoFeesToClaim);
+        let amount = self.protocol_fees_accrued;
+        self.protocol_fees_accrued = 0;
+        Ok(amount)
+    }
+
     fn add_fee_recipients(&mut self, new_recipients: Vec<Recipient>) -> Result<()> {
         let old_recipients = self.fee_recipients.clone();
 
         let updated_recipients: Vec<Recipient> = new_recipients
             .into_iter()
             .map(|mut new_recipient| {
-                if let Some(old_recipient) = old_recipients
+                new_recipient.amount = old_recipients
                     .iter()
-                    .find(|r| r.address == new_recipient.address)
-                {
-                    new_recipient.amount = old_recipient.amount;
-                }
+                    .find(|r| r.address == new_recipient.address && r.kind == new_recipient.kind)
+                    .map_or(0, |r| r.amount);
                 new_recipient
             })
             .collect();
 
-        let total_share: u16 = updated_recipients.iter().map(|r| r.share).sum();
-        if total_share != 10000 {
-            return Err(CommonCustomError::InvalidSharePercentage.into());
+        validate_fee_recipients(&updated_recipients)?;
+
+        // recipients dropped from the list keep their unclaimed fees
+        for old_recipient in old_recipients {
+            if !updated_recipients
+                .iter()
+                .any(|r| r.address == old_recipient.address && r.kind == old_recipient.kind)
+            {
+                self.retire_recipient(old_recipient)?;
+            }
         }
+
         msg!("updated recipients {:?}", updated_recipients);
         // Update recipients list
         self.fee_recipients = updated_recipients;
@@ -193,6 +480,76 @@ impl<'info> CurveConfigurationAccount<'info> for Account<'info, CurveConfigurati
         Ok(())
     }
 
+    fn remove_fee_recipient(&mut self, address: Pubkey) -> Result<()> {
+        let index = self
+            .fee_recipients
+            .iter()
+            .position(|r| r.address == address)
+            .ok_or(CommonCustomError::FeeRecipientNotFound)?;
+        if self.fee_recipients.len() == 1 {
+            return Err(CommonCustomError::LastFeeRecipient.into());
+        }
+
+        let removed = self.fee_recipients.remove(index);
+
+        // hand the removed share to the remaining recipients pro rata
+        let remaining_share = 10000 - removed.share;
+        if remaining_share == 0 {
+            return Err(CommonCustomError::InvalidSharePercentage.into());
+        }
+        for recipient in self.fee_recipients.iter_mut() {
+            recipient.share =
+                (recipient.share as u32 * 10000 / remaining_share as u32) as u16;
+        }
+        let total_share: u16 = self.fee_recipients.iter().map(|r| r.share).sum();
+        self.fee_recipients[0].share += 10000 - total_share;
+
+        self.retire_recipient(removed)?;
+        validate_fee_recipients(&self.fee_recipients)
+    }
+
+    fn update_recipient_shares(&mut self, shares: Vec<RecipientShare>) -> Result<()> {
+        for update in shares {
+            let recipient = self
+                .fee_recipients
+                .iter_mut()
+                .find(|r| r.address == update.address)
+                .ok_or(CommonCustomError::FeeRecipientNotFound)?;
+            recipient.share = update.share;
+        }
+        validate_fee_recipients(&self.fee_recipients)
+    }
+
+    fn realloc_for_recipients(
+        &self,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let account_info = self.to_account_info();
+        let new_size = self.required_space();
+        if new_size <= account_info.data_len() {
+            return Ok(());
+        }
+
+        let rent_due = Rent::get()?
+            .minimum_balance(new_size)
+            .saturating_sub(account_info.lamports());
+        if rent_due > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        account_info.realloc(new_size, false)?;
+        Ok(())
+    }
+
     fn change_fee_admin(&mut self, new_fee_admin: Pubkey) -> Result<()> {
         self.fee_admin = new_fee_admin;
         Ok(())
@@ -0,0 +1,131 @@
+use crate::errors::CommonCustomError;
+use crate::utils::calc::{bps_of, decayed_fee_bps};
+use anchor_lang::prelude::*;
+
+/// Fee rates (in basis points) applied to a single trade, one per fee layer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeRates {
+    pub pool_fee_bps: u16,     // Split among the pool's fee_recipients
+    pub protocol_fee_bps: u16, // Set in the platform config
+    pub creator_fee_bps: u16,  // Paid to BondingCurve.creator
+    pub referrer_fee_bps: u16, // Paid to the referrer, 0 when no referrer is passed
+}
+
+impl FeeRates {
+    pub fn total_bps(&self) -> Result<u16> {
+        let total = [
+            self.pool_fee_bps,
+            self.protocol_fee_bps,
+            self.creator_fee_bps,
+            self.referrer_fee_bps,
+        ]
+        .iter()
+        .try_fold(0u16, |acc, bps| acc.checked_add(*bps))
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+        require!(total <= 10000, CommonCustomError::InvalidFee);
+        Ok(total)
+    }
+
+    /// Split the fee charged on `amount` lamports into its layers
+    pub fn compute(&self, amount: u64) -> Result<FeeBreakdown> {
+        self.total_bps()?;
+
+        Ok(FeeBreakdown {
+            trade_volume: amount,
+            pool_fee: bps_of(amount, self.pool_fee_bps)?,
+            protocol_fee: bps_of(amount, self.protocol_fee_bps)?,
+            creator_fee: bps_of(amount, self.creator_fee_bps)?,
+            referrer_fee: bps_of(amount, self.referrer_fee_bps)?,
+        })
+    }
+}
+
+/// Fee amounts (in lamports) charged on a single trade
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeBreakdown {
+    pub trade_volume: u64, // SOL amount the fees were charged on
+    pub pool_fee: u64,
+    pub protocol_fee: u64,
+    pub creator_fee: u64,
+    pub referrer_fee: u64,
+}
+
+impl FeeBreakdown {
+    /// Fees that are held in the pool fee vault (everything except the referrer share)
+    pub fn vault_total(&self) -> Result<u64> {
+        self.pool_fee
+            .checked_add(self.protocol_fee)
+            .and_then(|v| v.checked_add(self.creator_fee))
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+
+    pub fn total(&self) -> Result<u64> {
+        self.vault_total()?
+            .checked_add(self.referrer_fee)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+}
+
+/// How a launch fee decays towards the base `fee_percentage`
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FeeDecay {
+    Linear,
+    Stepped { steps: u16 }, // Fee drops in `steps` equal steps over the decay window
+}
+
+/// Anti-sniping fee schedule, starting high at pool creation and decaying to the base fee
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct LaunchFeeSchedule {
+    pub initial_fee_bps: u16, // Fee at pool creation in basis points (e.g., 5000 = 50%)
+    pub decay_window: i64,    // Seconds until the fee reaches the base fee_percentage
+    pub decay: FeeDecay,
+}
+
+impl LaunchFeeSchedule {
+    // initial_fee_bps(2) + decay_window(8) + decay(1 + u16(2))
+    pub const SIZE: usize = 2 + 8 + 1 + 2;
+
+    /// `other_fees_bps` is the sum of the protocol, creator and referrer layers charged on top
+    /// of the pool fee, the launch fee must leave room for them
+    pub fn validate(&self, base_fee_bps: u16, other_fees_bps: u16) -> Result<()> {
+        let max_total_bps = self
+            .initial_fee_bps
+            .checked_add(other_fees_bps)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        require!(
+            max_total_bps <= 10000 && self.initial_fee_bps >= base_fee_bps,
+            CommonCustomError::InvalidFee
+        );
+        require!(self.decay_window > 0, CommonCustomError::InvalidAmount);
+        if let FeeDecay::Stepped { steps } = self.decay {
+            require!(steps > 0, CommonCustomError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    /// Fee in basis points `elapsed` seconds after pool creation
+    pub fn fee_bps_at(&self, base_fee_bps: u16, elapsed: i64) -> Result<u16> {
+        let steps = match self.decay {
+            FeeDecay::Linear => None,
+            FeeDecay::Stepped { steps } => Some(steps),
+        };
+        decayed_fee_bps(
+            self.initial_fee_bps,
+            base_fee_bps,
+            elapsed,
+            self.decay_window,
+            steps,
+        )
+    }
+}
+
+/// Result of the `quote` view
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct Quote {
+    pub amount_in: u64,
+    pub amount_out: u64,         // Tokens out for a buy, SOL out (before fees) for a sell
+    pub effective_fee_bps: u16,  // Pool fee in effect now, including any launch fee
+    pub total_fee_bps: u16,      // Sum of every fee layer
+    pub fees: FeeBreakdown,
+}
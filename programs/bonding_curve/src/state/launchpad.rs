@@ -1,3 +1,7 @@
+use crate::consts::{MAX_SALE_ROUNDS, BATCH_AUCTION_PRICE_TICKS};
+use crate::state::{Dex, Vesting};
+use crate::errors::{CommonCustomError, LaunchPadCustomErrror};
+use crate::utils::{verify_merkle_proof, whitelist_leaf};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -11,10 +15,49 @@ pub struct WhitelistLaunchData {
     pub total_supply: u64,              // Total tokens available for sale
     pub sold_tokens: u64,               // Tokens sold so far
     pub whitelisted_users: Vec<Pubkey>, // List of whitelisted addresses
-    pub buyers: Vec<Pubkey>,            // List of purchasers
+    pub buyers: Vec<Pubkey>,            // Unused, purchasers are tracked by their BuyerAccount
     pub paused: bool,                   // Emergency pause state
     pub whitelist_duration: i64,        // Duration of whitelist period
     pub bump: u8,                       // PDA bump seed
+    pub merkle_root: Option<[u8; 32]>,  // When set, buyers prove inclusion instead of being added one by one
+    pub rounds: Vec<SaleRound>,         // Ordered sale rounds, replaces the single price & limit when set
+    pub tokens_deposited: u64,          // Sale tokens deposited in the launchpad vault
+    pub cancelled: bool,                // Cancelled by the authority, buyers are refunded
+    pub whitelist_manager: Pubkey,      // Wallet allowed to manage the whitelist besides the authority
+    pub vesting: Option<Vesting>,       // Buyer vesting schedule, None unlocks everything at claim
+}
+
+/// Who can buy in a sale round
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RoundEligibility {
+    Tier { tier: u8 },               // Whitelisted buyers with this tier id on their BuyerAccount
+    MerkleRoot { root: [u8; 32] },   // Buyers proving inclusion, capped at their leaf allocation
+    Public,                          // Everyone
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SaleRound {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub token_price: u64,               // Price per token in lamports
+    pub purchase_limit_per_wallet: u64, // Maximum tokens per wallet in this round
+    pub eligibility: RoundEligibility,
+    pub sold_tokens: u64,               // Tokens sold in this round
+}
+
+/// Price and limit applied to a purchase
+pub struct PurchaseTerms {
+    pub round: Option<usize>, // Index of the active round, None without rounds
+    pub token_price: u64,
+    pub limit: u64,           // Maximum tokens the buyer can hold from this round (or the sale)
+    pub purchased: u64,       // Tokens the buyer already bought in this round (or the sale)
+}
+
+/// Inclusion proof for a merkle-root whitelist, the leaf commits to the wallet and its allocation
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WhitelistProof {
+    pub allocation: u64,       // Maximum tokens the wallet can buy
+    pub proof: Vec<[u8; 32]>,
 }
 
 #[account]
@@ -33,6 +76,19 @@ pub struct FairLaunchData {
     pub total_raised: u64,          // Total amount raised so far
     pub paused: bool,               // Emergency pause state
     pub bump: u8,                   // PDA bump seed
+    pub total_tokens: u64,          // Tokens distributed to contributors
+    pub tokens_deposited: u64,      // Sale tokens deposited in the launchpad vault
+    pub finalized: bool,            // Set once the sale result is snapshotted
+    pub token_pool: u64,            // Snapshot of the tokens shared between contributors
+    pub final_raised: u64,          // Snapshot of the total raised when finalized
+    pub liquidity_bps: u16,         // Share of the raised SOL seeded as liquidity in basis points
+    pub liquidity_tokens: u64,      // Tokens seeded as liquidity alongside the SOL
+    pub migrate_to: Option<Dex>,    // DEX the seeded bonding curve migrates to, None keeps it trading on the curve
+    pub oversubscription: bool,     // Accept contributions above the hard cap and refund the excess pro-rata
+    pub accepted_raised: u64,       // Snapshot of the raised SOL kept by the sale, capped at the hard cap when oversubscribed
+    pub cancelled: bool,            // Cancelled by the authority, contributors are refunded
+    pub vesting: Option<Vesting>,   // Contributor vesting schedule, None unlocks everything at distribution
+    pub launch_fee_bps: u16,        // Platform launch fee snapshotted at creation, a later fee change can't block finalization
 }
 
 impl WhitelistLaunchData {
@@ -63,13 +119,152 @@ impl WhitelistLaunchData {
             paused: false,
             whitelist_duration,
             bump,
+            merkle_root: None,
+            rounds: vec![],
+            tokens_deposited: 0,
+            cancelled: false,
+            whitelist_manager: authority,
+            vesting: None,
+        }
+    }
+
+    /// Purchases are blocked until the vault holds the full sale supply
+    pub fn is_funded(&self) -> bool {
+        self.tokens_deposited >= self.total_supply
+    }
+
+    /// The authority and its delegated whitelist manager can add, remove and tier buyers
+    pub fn can_manage_whitelist(&self, signer: &Pubkey) -> bool {
+        *signer == self.authority || *signer == self.whitelist_manager
+    }
+
+    /// Deposited tokens that were not sold and are not owed to buyers
+    pub fn unsold_tokens(&self) -> u64 {
+        self.tokens_deposited.saturating_sub(self.sold_tokens)
+    }
+}
+
+impl WhitelistLaunchData {
+    /// SOL cost in lamports of `token_amount` base units at `token_price` lamports per whole token
+    pub fn purchase_cost(token_price: u64, token_amount: u64, decimals: u8) -> Result<u64> {
+        let cost = (token_amount as u128)
+            .checked_mul(token_price as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(10u128.pow(decimals as u32))
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        u64::try_from(cost).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+
+    /// Maximum tokens `buyer` can purchase, from the merkle proof in root mode or the on-account list otherwise
+    pub fn purchase_limit(
+        &self,
+        buyer: &Pubkey,
+        whitelisted: bool,
+        proof: Option<&WhitelistProof>,
+    ) -> Result<u64> {
+        match self.merkle_root {
+            Some(root) => verified_allocation(buyer, root, proof),
+            None => {
+                if !whitelisted {
+                    return Err(LaunchPadCustomErrror::NotWhitelisted.into());
+                }
+                Ok(self.purchase_limit_per_wallet)
+            }
+        }
+    }
+
+    /// Resolve the price and limit for a purchase at `now`, routing to the active round when rounds are set
+    pub fn purchase_terms(
+        &self,
+        buyer: &Pubkey,
+        buyer_account: &BuyerAccount,
+        proof: Option<&WhitelistProof>,
+        now: i64,
+    ) -> Result<PurchaseTerms> {
+        // Check if sale has started
+        if now < self.start_time {
+            return Err(LaunchPadCustomErrror::SaleNotStarted.into());
+        }
+
+        // Check if sale has ended
+        if now > self.end_time {
+            return Err(LaunchPadCustomErrror::SaleEnded.into());
+        }
+
+        if self.rounds.is_empty() {
+            return Ok(PurchaseTerms {
+                round: None,
+                token_price: self.token_price,
+                limit: self.purchase_limit(buyer, buyer_account.whitelisted, proof)?,
+                purchased: buyer_account.amount,
+            });
+        }
+
+        let index = self
+            .rounds
+            .iter()
+            .position(|r| now >= r.start_time && now <= r.end_time)
+            .ok_or(LaunchPadCustomErrror::NoActiveRound)?;
+        let round = &self.rounds[index];
+
+        let limit = match round.eligibility {
+            RoundEligibility::Tier { tier } => {
+                if !buyer_account.whitelisted || buyer_account.tier != tier {
+                    return Err(LaunchPadCustomErrror::NotWhitelisted.into());
+                }
+                round.purchase_limit_per_wallet
+            }
+            RoundEligibility::MerkleRoot { root } => round
+                .purchase_limit_per_wallet
+                .min(verified_allocation(buyer, root, proof)?),
+            RoundEligibility::Public => round.purchase_limit_per_wallet,
+        };
+
+        Ok(PurchaseTerms {
+            round: Some(index),
+            token_price: round.token_price,
+            limit,
+            purchased: buyer_account.round_purchased[index],
+        })
+    }
+
+    /// Check rounds are ordered, don't overlap and have a price and limit
+    pub fn validate_rounds(rounds: &[SaleRound]) -> Result<()> {
+        if rounds.is_empty() || rounds.len() > MAX_SALE_ROUNDS {
+            return Err(LaunchPadCustomErrror::InvalidSaleRounds.into());
+        }
+        for (i, round) in rounds.iter().enumerate() {
+            if round.end_time <= round.start_time {
+                return Err(LaunchPadCustomErrror::InvalidTimeRange.into());
+            }
+            if round.token_price == 0 || round.purchase_limit_per_wallet == 0 {
+                return Err(CommonCustomError::InvalidAmount.into());
+            }
+            if i > 0 && round.start_time <= rounds[i - 1].end_time {
+                return Err(LaunchPadCustomErrror::InvalidSaleRounds.into());
+            }
         }
+        Ok(())
+    }
+}
+
+/// Allocation committed in the merkle leaf of `buyer`
+fn verified_allocation(
+    buyer: &Pubkey,
+    root: [u8; 32],
+    proof: Option<&WhitelistProof>,
+) -> Result<u64> {
+    let proof = proof.ok_or(LaunchPadCustomErrror::InvalidMerkleProof)?;
+    let leaf = whitelist_leaf(buyer, proof.allocation);
+    if !verify_merkle_proof(&proof.proof, root, leaf) {
+        return Err(LaunchPadCustomErrror::InvalidMerkleProof.into());
     }
+    Ok(proof.allocation)
 }
 
 impl FairLaunchData {
     // Fixed size account
-    pub const ACCOUNT_SIZE: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1000; // ~131 bytes
+    pub const ACCOUNT_SIZE: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 8 + 1 + 8 + 8 + 2 + 8 + 2 + 1 + 8 + 1 + 1 + Vesting::INIT_SPACE + 2 + 1000; // ~238 bytes
 
     pub fn new(
         authority: Pubkey,
@@ -83,6 +278,8 @@ impl FairLaunchData {
         max_contribution: u64,
         max_tokens_per_wallet: u64,
         distribution_delay: i64,
+        total_tokens: u64,
+        launch_fee_bps: u16,
         bump: u8,
     ) -> Self {
         Self {
@@ -100,10 +297,369 @@ impl FairLaunchData {
             total_raised: 0,
             paused: false,
             bump,
+            total_tokens,
+            tokens_deposited: 0,
+            finalized: false,
+            token_pool: 0,
+            final_raised: 0,
+            liquidity_bps: 0,
+            liquidity_tokens: 0,
+            migrate_to: None,
+            oversubscription: false,
+            accepted_raised: 0,
+            cancelled: false,
+            vesting: None,
+            launch_fee_bps,
+        }
+    }
+
+    /// Contributions are blocked until the vault holds the tokens for sale
+    pub fn is_funded(&self) -> bool {
+        self.tokens_deposited >= self.required_tokens()
+    }
+
+    /// Tokens for contributors plus the tokens seeded as liquidity
+    pub fn required_tokens(&self) -> u64 {
+        self.total_tokens.saturating_add(self.liquidity_tokens)
+    }
+
+    /// Tokens owed for `contribution` lamports, against the finalization snapshot
+    pub fn token_share(&self, contribution: u64) -> Result<u64> {
+        let share = (contribution as u128)
+            .checked_mul(self.token_pool as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(self.final_raised as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        u64::try_from(share).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+
+    /// Most tokens `contribution` lamports can receive while `total_raised` has been raised.
+    /// A sale is only distributed at or above the soft cap and the raise never shrinks, so the final share is at most this
+    pub fn max_token_share(&self, contribution: u64, total_raised: u64) -> Result<u64> {
+        let share = (contribution as u128)
+            .checked_mul(self.total_tokens as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(total_raised.max(self.soft_cap) as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        u64::try_from(share).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+
+    /// SOL refunded on `contribution` lamports when the sale was oversubscribed.
+    /// Rounds down so the refunds never exceed the excess held in the vault, the dust stays on top of the vault rent
+    pub fn excess_refund(&self, contribution: u64) -> Result<u64> {
+        let excess = self.final_raised.saturating_sub(self.accepted_raised);
+        if excess == 0 {
+            return Ok(0);
         }
+        let refund = (contribution as u128)
+            .checked_mul(excess as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+            .checked_div(self.final_raised as u128)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        u64::try_from(refund).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
     }
 }
 
+/// How the Dutch auction price falls from the start price to the floor
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DecayCurve {
+    Linear,                         // Reaches the floor price at the end time
+    Exponential { half_life: i64 }, // Distance to the floor halves every `half_life` seconds
+}
+
+#[account]
+pub struct DutchAuctionData {
+    pub authority: Pubkey,
+    pub token_mint: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub vault: Pubkey,              // SOL vault holding the bids
+    pub start_price: u64,           // Price per token in lamports when the auction opens
+    pub floor_price: u64,           // Lowest price per token in lamports
+    pub decay: DecayCurve,
+    pub total_tokens: u64,          // Tokens for sale
+    pub tokens_deposited: u64,      // Sale tokens deposited in the launchpad vault
+    pub tokens_bid: u64,            // Tokens bid for so far
+    pub total_committed: u64,       // SOL locked by the bids
+    pub clearing_price: u64,        // Uniform price every bidder pays, set on settlement
+    pub settled: bool,              // Set when the supply sells out or the auction ends
+    pub proceeds_accrued: u64,      // SOL paid by the claimed bids
+    pub proceeds_withdrawn: u64,    // SOL withdrawn by the authority
+    pub paused: bool,               // Emergency pause state
+    pub cancelled: bool,            // Set when the authority cancels the auction, bidders get their SOL back
+    pub bump: u8,                   // PDA bump seed
+}
+
+impl DutchAuctionData {
+    // 32 + 32 + 8 + 8 + 32 + 8 + 8 + DecayCurve(1 + 8) + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 1 + 1 + 1
+    pub const ACCOUNT_SIZE: usize = 32 + 32 + 8 + 8 + 32 + 8 + 8 + 9 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 1 + 1 + 1; // ~196 bytes
+
+    pub fn new(
+        authority: Pubkey,
+        token_mint: Pubkey,
+        start_time: i64,
+        end_time: i64,
+        vault: Pubkey,
+        start_price: u64,
+        floor_price: u64,
+        decay: DecayCurve,
+        total_tokens: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            authority,
+            token_mint,
+            start_time,
+            end_time,
+            vault,
+            start_price,
+            floor_price,
+            decay,
+            total_tokens,
+            tokens_deposited: 0,
+            tokens_bid: 0,
+            total_committed: 0,
+            clearing_price: 0,
+            settled: false,
+            proceeds_accrued: 0,
+            proceeds_withdrawn: 0,
+            paused: false,
+            cancelled: false,
+            bump,
+        }
+    }
+
+    /// Bids are blocked until the vault holds the tokens for sale
+    pub fn is_funded(&self) -> bool {
+        self.tokens_deposited >= self.total_tokens
+    }
+
+    /// Price per token in lamports at `now`
+    pub fn price_at(&self, now: i64) -> u64 {
+        if now <= self.start_time {
+            return self.start_price;
+        }
+        let elapsed = (now.min(self.end_time) - self.start_time) as u128;
+        let span = (self.start_price - self.floor_price) as u128;
+
+        let remaining = match self.decay {
+            DecayCurve::Linear => {
+                let duration = (self.end_time - self.start_time) as u128;
+                span - span * elapsed / duration
+            }
+            DecayCurve::Exponential { half_life } => {
+                let half_life = half_life as u128;
+                let halvings = elapsed / half_life;
+                if halvings >= 64 {
+                    0
+                } else {
+                    // halve once per half-life and move linearly towards the next halving in between
+                    let current = span >> halvings;
+                    current - (current / 2) * (elapsed % half_life) / half_life
+                }
+            }
+        };
+
+        self.floor_price + remaining as u64
+    }
+
+    /// SOL a bidder owes for `token_amount` at the clearing price out of the `committed` SOL they locked.
+    /// Rounds down like the bid cost and never exceeds what was locked, so the cost and the refund add up to `committed`
+    pub fn settlement_cost(&self, token_amount: u64, committed: u64, decimals: u8) -> Result<u64> {
+        let cost = WhitelistLaunchData::purchase_cost(self.clearing_price, token_amount, decimals)?;
+        Ok(cost.min(committed))
+    }
+
+    /// Deposited tokens that were not bid for and are not owed to bidders
+    pub fn unsold_tokens(&self) -> u64 {
+        self.tokens_deposited.saturating_sub(self.tokens_bid)
+    }
+}
+
+#[account]
+pub struct BatchAuctionData {
+    pub authority: Pubkey,
+    pub token_mint: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub vault: Pubkey,              // SOL vault escrowing the bids
+    pub min_price: u64,             // Reserve price per token in lamports, the lowest price tick
+    pub tick_size: u64,             // Step between the price ticks bids can be placed at
+    pub min_bid: u64,               // Minimum SOL per bid
+    pub total_tokens: u64,          // Tokens for sale
+    pub tokens_deposited: u64,      // Sale tokens deposited in the launchpad vault
+    pub bid_count: u64,             // Bids submitted, also the id of the next bid
+    pub total_escrowed: u64,        // SOL escrowed by the bids
+    pub tick_demand: [u64; BATCH_AUCTION_PRICE_TICKS], // SOL bid at each price tick, counted when the bid is placed
+    pub clearing_price: u64,        // Uniform price per token paid by the winning bids, 0 until found
+    pub cutoff_price: u64,          // Price tick only partially filled, 0 when every bid wins
+    pub marginal_tokens: u64,       // Tokens shared pro rata by the bids at the cutoff price
+    pub tokens_sold: u64,           // Tokens owed to the winning bids
+    pub proceeds_accrued: u64,      // SOL paid by the claimed bids
+    pub proceeds_withdrawn: u64,    // SOL withdrawn by the authority
+    pub finalized: bool,            // Set once the clearing price is found
+    pub expired: bool,              // Set by the first refund of an auction not finalized in time
+    pub paused: bool,               // Emergency pause state
+    pub cancelled: bool,            // Set when the authority cancels the auction, bidders get their SOL back
+    pub bump: u8,                   // PDA bump seed
+}
+
+#[account]
+pub struct BatchBid {
+    pub bidder: Pubkey,
+    pub auction: Pubkey,
+    pub bid_id: u64,                // Submission order
+    pub max_price: u64,             // Highest price per token in lamports the bidder pays, on a price tick
+    pub amount: u64,                // SOL escrowed
+    pub claimed: bool,              // Tokens and refund claimed, or the escrow refunded
+    pub bump: u8,
+}
+
+impl BatchBid {
+    pub const ACCOUNT_SIZE: usize = 32 + 32 + 8 + 8 + 8 + 1 + 1; // ~90 bytes
+}
+
+impl BatchAuctionData {
+    // 32 + 32 + 8 + 8 + 32 + 8 * 13 + 8 * BATCH_AUCTION_PRICE_TICKS + 1 + 1 + 1 + 1 + 1
+    pub const ACCOUNT_SIZE: usize = 32 + 32 + 8 + 8 + 32 + 8 * 13 + 8 * BATCH_AUCTION_PRICE_TICKS + 1 + 1 + 1 + 1 + 1; // ~733 bytes
+
+    pub fn new(
+        authority: Pubkey,
+        token_mint: Pubkey,
+        start_time: i64,
+        end_time: i64,
+        vault: Pubkey,
+        min_price: u64,
+        tick_size: u64,
+        min_bid: u64,
+        total_tokens: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            authority,
+            token_mint,
+            start_time,
+            end_time,
+            vault,
+            min_price,
+            tick_size,
+            min_bid,
+            total_tokens,
+            tokens_deposited: 0,
+            bid_count: 0,
+            total_escrowed: 0,
+            tick_demand: [0; BATCH_AUCTION_PRICE_TICKS],
+            clearing_price: 0,
+            cutoff_price: 0,
+            marginal_tokens: 0,
+            tokens_sold: 0,
+            proceeds_accrued: 0,
+            proceeds_withdrawn: 0,
+            finalized: false,
+            expired: false,
+            paused: false,
+            cancelled: false,
+            bump,
+        }
+    }
+
+    /// Bids are blocked until the vault holds the tokens for sale
+    pub fn is_funded(&self) -> bool {
+        self.tokens_deposited >= self.total_tokens
+    }
+
+    /// Deposited tokens that are not owed to the winning bids
+    pub fn unsold_tokens(&self) -> u64 {
+        self.tokens_deposited.saturating_sub(self.tokens_sold)
+    }
+
+    /// Index of the price tick `max_price` sits on, bids are only placed on ticks
+    pub fn price_tick(&self, max_price: u64) -> Result<usize> {
+        if max_price < self.min_price {
+            return Err(LaunchPadCustomErrror::InvalidAuctionPrice.into());
+        }
+        let offset = max_price - self.min_price;
+        let tick = offset / self.tick_size;
+        if tick * self.tick_size != offset || tick >= BATCH_AUCTION_PRICE_TICKS as u64 {
+            return Err(LaunchPadCustomErrror::InvalidAuctionPrice.into());
+        }
+        Ok(tick as usize)
+    }
+
+    fn tick_price(&self, tick: usize) -> u128 {
+        self.min_price as u128 + self.tick_size as u128 * tick as u128
+    }
+
+    /// Find the clearing price from the demand counted at each price tick, walking down from the highest tick.
+    /// It doesn't depend on the bids themselves, so it runs once in any order. When the bids don't buy
+    /// the supply at the reserve price every bid wins at the reserve price
+    pub fn finalize(&mut self, decimals: u8) -> Result<()> {
+        let unit = 10u128.pow(decimals as u32);
+        let supply = self.total_tokens as u128;
+        // SOL of the ticks above the current one, in lamports times the token unit
+        let mut above: u128 = 0;
+
+        for tick in (0..BATCH_AUCTION_PRICE_TICKS).rev() {
+            let price = self.tick_price(tick);
+
+            if above >= supply * price {
+                // the ticks above already buy the supply at a price between theirs and this one
+                self.set_clearing(div_ceil(above, supply)?, price, 0)?;
+                return Ok(());
+            }
+
+            let with_tick = above + (self.tick_demand[tick] as u128) * unit;
+            if with_tick >= supply * price {
+                // this tick completes the supply at its own price, its bids share the rest pro rata
+                let filled_above = above / price;
+                self.set_clearing(price, price, supply - filled_above)?;
+                return Ok(());
+            }
+            above = with_tick;
+        }
+
+        self.clearing_price = self.min_price;
+        self.tokens_sold = ((above / self.min_price as u128) as u64).min(self.total_tokens);
+        self.finalized = true;
+        Ok(())
+    }
+
+    fn set_clearing(&mut self, clearing_price: u128, cutoff_price: u128, marginal_tokens: u128) -> Result<()> {
+        self.clearing_price = u64::try_from(clearing_price).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured)?;
+        self.cutoff_price = u64::try_from(cutoff_price).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured)?;
+        self.marginal_tokens = marginal_tokens as u64;
+        self.tokens_sold = self.total_tokens;
+        self.finalized = true;
+        Ok(())
+    }
+
+    /// Tokens filled and SOL paid by `bid` at the clearing price
+    pub fn bid_fill(&self, bid: &BatchBid, decimals: u8) -> Result<(u64, u64)> {
+        let unit = 10u128.pow(decimals as u32);
+        let clearing_price = self.clearing_price as u128;
+
+        let tokens = if self.cutoff_price == 0 || bid.max_price > self.cutoff_price {
+            (bid.amount as u128) * unit / clearing_price
+        } else if bid.max_price == self.cutoff_price {
+            let tick_demand = self.tick_demand[self.price_tick(bid.max_price)?] as u128;
+            (bid.amount as u128) * (self.marginal_tokens as u128) / tick_demand
+        } else {
+            0
+        };
+
+        // Rounded up so the refunds never exceed the escrow
+        let cost = div_ceil(tokens * clearing_price, unit)?.min(bid.amount as u128);
+        Ok((tokens as u64, cost as u64))
+    }
+}
+
+fn div_ceil(numerator: u128, denominator: u128) -> Result<u128> {
+    if denominator == 0 {
+        return Err(CommonCustomError::OverFlowUnderFlowOccured.into());
+    }
+    Ok(numerator.div_ceil(denominator))
+}
+
 #[account]
 pub struct BuyerAccount {
     pub buyer: Pubkey,
@@ -111,8 +667,23 @@ pub struct BuyerAccount {
     pub whitelisted: bool,
     pub launchpad: Pubkey,
     pub bump: u8,
+    pub tier: u8,                                  // Whitelist tier id, used by tiered sale rounds
+    pub round_purchased: [u64; MAX_SALE_ROUNDS],   // Tokens bought in each sale round
+    pub claimed: bool,                             // Tokens already claimed
+    pub committed: u64,                            // SOL paid for whitelist purchases or locked by Dutch auction bids
+    pub released: u64,                             // Tokens already released by vesting claims
+    pub refunded: bool,                            // Oversubscribed SOL excess already refunded
 }
 
 impl BuyerAccount {
-    pub const ACCOUNT_SIZE: usize = 32 + 8 + 1 + 32 + 1; // ~75 bytes
+    pub const ACCOUNT_SIZE: usize = 32 + 8 + 1 + 32 + 1 + 1 + 8 * MAX_SALE_ROUNDS + 1 + 8 + 8 + 1; // ~125 bytes
+
+    /// Tokens of `total` that can be released at `now`, everything at once without vesting
+    pub fn releasable(&self, total: u64, vesting: Option<&Vesting>, now: i64) -> Result<u64> {
+        let vested = match vesting {
+            Some(vesting) => vesting.vested_amount(total, now)?,
+            None => total,
+        };
+        Ok(vested.saturating_sub(self.released))
+    }
 }
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+// Metaplex field limits, also applied to Token-2022 metadata
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+pub const MAX_URI_LENGTH: usize = 200;
+
+// CreateMetadataAccountV3 index in the Metaplex token metadata instruction enum
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+/// Token metadata written by `launch_token`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TokenMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl TokenMetadataArgs {
+    pub fn is_valid(&self) -> bool {
+        !self.name.is_empty()
+            && !self.symbol.is_empty()
+            && self.name.len() <= MAX_NAME_LENGTH
+            && self.symbol.len() <= MAX_SYMBOL_LENGTH
+            && self.uri.len() <= MAX_URI_LENGTH
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CpiMetadataCreator {
+    address: Pubkey,
+    verified: bool,
+    share: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CpiMetadataCollection {
+    verified: bool,
+    key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CpiMetadataUses {
+    use_method: u8,
+    remaining: u64,
+    total: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+enum CpiCollectionDetails {
+    V1 { size: u64 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CpiMetadataDataV2 {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<Vec<CpiMetadataCreator>>,
+    collection: Option<CpiMetadataCollection>,
+    uses: Option<CpiMetadataUses>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CpiCreateMetadataAccountArgsV3 {
+    data: CpiMetadataDataV2,
+    is_mutable: bool,
+    collection_details: Option<CpiCollectionDetails>,
+}
+
+pub fn get_create_metadata_v3_ix_data(metadata: &TokenMetadataArgs, is_mutable: bool) -> Vec<u8> {
+    let mut buf: Vec<u8> = vec![CREATE_METADATA_ACCOUNT_V3];
+    let args = CpiCreateMetadataAccountArgsV3 {
+        data: CpiMetadataDataV2 {
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        is_mutable,
+        collection_details: None,
+    };
+
+    args.serialize(&mut buf).unwrap();
+    buf
+}
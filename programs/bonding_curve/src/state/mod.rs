@@ -2,13 +2,21 @@ pub mod bonding_curve;
 pub mod curve_configuration;
 pub mod meteora;
 pub mod pumpswap;
+pub mod metadata;
 pub mod launchpad;
 pub mod allocation;
+pub mod fees;
+pub mod platform_config;
+pub mod referral;
 
 
 pub use bonding_curve::*;
 pub use curve_configuration::*;
 pub use meteora::*;
 pub use pumpswap::*;
+pub use metadata::*;
 pub use launchpad::*;
 pub use allocation::*;
+pub use fees::*;
+pub use platform_config::*;
+pub use referral::*;
@@ -0,0 +1,54 @@
+use crate::errors::CommonCustomError;
+use anchor_lang::prelude::*;
+
+/// PLATFORM CONFIGURATION ACCOUNT (one per program)
+#[account]
+pub struct PlatformConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,      // Wallet allowed to claim protocol fees
+    pub protocol_fee_bps: u16, // Protocol fee charged on every trade in basis points
+    pub referrer_fee_bps: u16, // Referrer fee charged when a referrer is passed in basis points
+    pub bump: u8,
+    pub pauser: Pubkey,        // Wallet allowed to pause pools and flip the kill switch
+    pub trading_halted: bool,  // Platform-wide kill switch for trading, liquidity and migration
+    pub launch_fee_bps: u16,   // Fee charged on the SOL raised by a fair launch in basis points
+}
+
+impl PlatformConfig {
+    // Discriminator (8) + Pubkey(32) + Pubkey(32) + u16(2) + u16(2) + u8(1) + Pubkey(32) + bool(1) + u16(2)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + 2 + 2 + 1 + 32 + 1 + 2;
+
+    pub fn new(
+        admin: Pubkey,
+        treasury: Pubkey,
+        protocol_fee_bps: u16,
+        referrer_fee_bps: u16,
+        bump: u8,
+    ) -> Result<Self> {
+        Self::validate_fees(protocol_fee_bps, referrer_fee_bps)?;
+
+        Ok(Self {
+            admin,
+            treasury,
+            protocol_fee_bps,
+            referrer_fee_bps,
+            bump,
+            pauser: admin,
+            trading_halted: false,
+            launch_fee_bps: 0,
+        })
+    }
+
+    pub fn require_trading_enabled(&self) -> Result<()> {
+        require!(!self.trading_halted, CommonCustomError::TradingHalted);
+        Ok(())
+    }
+
+    pub fn validate_fees(protocol_fee_bps: u16, referrer_fee_bps: u16) -> Result<()> {
+        let total = protocol_fee_bps
+            .checked_add(referrer_fee_bps)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        require!(total <= 10000, CommonCustomError::InvalidFee);
+        Ok(())
+    }
+}
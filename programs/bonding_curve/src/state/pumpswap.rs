@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::CommonCustomError;
 use crate::utils::get_function_hash;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -29,3 +30,49 @@ pub fn get_pump_pool_create_ix_data(
     args.serialize(&mut buf).unwrap();
     buf
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CpiPumpBuyArgs {
+    base_amount_out: u64,
+    max_quote_amount_in: u64,
+}
+
+pub fn get_pump_pool_buy_ix_data(base_amount_out: u64, max_quote_amount_in: u64) -> Vec<u8> {
+    let hash = get_function_hash("global", "buy");
+    let mut buf: Vec<u8> = vec![];
+    buf.extend_from_slice(&hash);
+    let args = CpiPumpBuyArgs {
+        base_amount_out,
+        max_quote_amount_in,
+    };
+
+    args.serialize(&mut buf).unwrap();
+    buf
+}
+
+// PumpSwap GlobalConfig layout: discriminator(8) + admin(32) + lp_fee_basis_points(8)
+// + protocol_fee_basis_points(8) + disable_flags(1) + protocol_fee_recipients(8 * 32)
+// + coin_creator_fee_basis_points(8)
+const PUMP_LP_FEE_OFFSET: usize = 8 + 32;
+const PUMP_PROTOCOL_FEE_OFFSET: usize = PUMP_LP_FEE_OFFSET + 8;
+const PUMP_COIN_CREATOR_FEE_OFFSET: usize = PUMP_PROTOCOL_FEE_OFFSET + 8 + 1 + 8 * 32;
+
+/// Total fee in basis points PumpSwap adds on top of the quote amount of a buy
+pub fn get_pump_buy_fee_bps(global_config_data: &[u8]) -> Result<u64> {
+    let read_u64 = |offset: usize| -> Option<u64> {
+        global_config_data
+            .get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    };
+
+    let lp_fee_bps = read_u64(PUMP_LP_FEE_OFFSET).ok_or(CommonCustomError::InvalidMigrationPool)?;
+    let protocol_fee_bps =
+        read_u64(PUMP_PROTOCOL_FEE_OFFSET).ok_or(CommonCustomError::InvalidMigrationPool)?;
+    // older global configs have no coin creator fee
+    let coin_creator_fee_bps = read_u64(PUMP_COIN_CREATOR_FEE_OFFSET).unwrap_or(0);
+
+    lp_fee_bps
+        .checked_add(protocol_fee_bps)
+        .and_then(|bps| bps.checked_add(coin_creator_fee_bps))
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured.into())
+}
@@ -0,0 +1,53 @@
+use crate::errors::CommonCustomError;
+use anchor_lang::prelude::*;
+
+/// REFERRAL ACCOUNT (one per referrer)
+///
+/// Referrer fees are paid into this PDA as lamports and claimed by the referrer.
+#[account]
+pub struct ReferralAccount {
+    pub referrer: Pubkey,
+    pub total_volume: u64, // SOL volume of the trades referred
+    pub trade_count: u64,  // Number of trades referred
+    pub fees_earned: u64,  // Lifetime referrer fees in lamports
+    pub fees_claimed: u64, // Referrer fees already claimed in lamports
+    pub bump: u8,
+}
+
+impl ReferralAccount {
+    // Discriminator (8) + Pubkey(32) + u64(8) + u64(8) + u64(8) + u64(8) + u8(1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    pub fn new(referrer: Pubkey, bump: u8) -> Self {
+        Self {
+            referrer,
+            total_volume: 0,
+            trade_count: 0,
+            fees_earned: 0,
+            fees_claimed: 0,
+            bump,
+        }
+    }
+
+    pub fn record_trade(&mut self, volume: u64, fee: u64) -> Result<()> {
+        self.total_volume = self
+            .total_volume
+            .checked_add(volume)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        self.trade_count = self
+            .trade_count
+            .checked_add(1)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        self.fees_earned = self
+            .fees_earned
+            .checked_add(fee)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+        Ok(())
+    }
+
+    pub fn claimable(&self) -> Result<u64> {
+        self.fees_earned
+            .checked_sub(self.fees_claimed)
+            .ok_or(CommonCustomError::OverFlowUnderFlowOccured.into())
+    }
+}
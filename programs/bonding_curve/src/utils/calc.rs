@@ -49,6 +49,84 @@ pub fn calculate_initial_reserve_amount(
     Ok(initial_reserve as u64)
 }
 
+/// Calculate `bps` basis points of `amount`
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+        .checked_div(10000)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    u64::try_from(value).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
+}
+
+/// Base tokens a constant product pool pays out for `max_quote_in`, once the `fee_bps`
+/// charged on top of the swapped quote is set aside. One lamport per fee layer is kept
+/// back for the pool rounding its fees up
+pub fn constant_product_amount_out(
+    max_quote_in: u64,
+    fee_bps: u64,
+    base_reserve: u64,
+    quote_reserve: u64,
+) -> Result<u64> {
+    let quote_in = (max_quote_in.saturating_sub(3) as u128)
+        .checked_mul(10000)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+        .checked_div(10000 + fee_bps as u128)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    let amount_out = (base_reserve as u128)
+        .checked_mul(quote_in)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+        .checked_div(
+            (quote_reserve as u128)
+                .checked_add(quote_in)
+                .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?,
+        )
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?;
+
+    u64::try_from(amount_out).map_err(|_| CommonCustomError::OverFlowUnderFlowOccured.into())
+}
+
+/// Decay a fee from `initial_bps` to `base_bps` over `window` seconds
+///
+/// Linear decay lowers the fee every second, stepped decay lowers it
+/// `steps` times in equal steps over the window.
+pub fn decayed_fee_bps(
+    initial_bps: u16,
+    base_bps: u16,
+    elapsed: i64,
+    window: i64,
+    steps: Option<u16>,
+) -> Result<u16> {
+    if initial_bps <= base_bps || window <= 0 || elapsed >= window {
+        return Ok(base_bps);
+    }
+    let elapsed = elapsed.max(0) as u128;
+    let window = window as u128;
+    let spread = (initial_bps - base_bps) as u128;
+
+    // fraction of the window elapsed, as numerator / denominator
+    let (numerator, denominator) = match steps {
+        Some(steps) if steps > 0 => {
+            let steps = steps as u128;
+            let steps_elapsed = elapsed
+                .checked_mul(steps)
+                .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+                / window;
+            (steps_elapsed, steps)
+        }
+        _ => (elapsed, window),
+    };
+
+    let decayed = spread
+        .checked_mul(numerator)
+        .ok_or(CommonCustomError::OverFlowUnderFlowOccured)?
+        / denominator;
+
+    Ok(initial_bps - decayed as u16)
+}
+
 pub fn linear_buy_cost(amount: u64, reserve_ratio: u16, total_supply: u64) -> Result<u64> {
     let new_supply = total_supply
         .checked_add(amount)
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// Whitelist leaf: keccak256(wallet || allocation as little endian u64)
+pub fn whitelist_leaf(wallet: &Pubkey, allocation: u64) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), &allocation.to_le_bytes()]).to_bytes()
+}
+
+/// Verify a merkle proof where each pair of nodes is hashed in sorted order
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == root
+}
@@ -2,3 +2,5 @@ pub mod calc;
 pub use calc::*;
 pub mod helpers;
 pub use helpers::*;
+pub mod merkle;
+pub use merkle::*;
@@ -146,14 +146,16 @@ async function deleteAll(program, signer, mint, team, advisor) {
         console.log("Deleted PoolTokenAccount Vault");
     } catch (e) { console.error("Failed to delete PoolTokenAccount Vault:", e); }
 
-    // Example: allocationVaults (authority = allocation PDA, seeds = [ALLOCATION_SEED_PREFIX, wallet pubkey])
+    // Example: allocationVaults (authority = allocation PDA, seeds = [ALLOCATION_SEED_PREFIX, wallet pubkey, mint pubkey])
+    const allocationWallets = [team.publicKey, advisor.publicKey];
     for (let i = 0; i < allocations.length; i++) {
         try {
             const allocationVault = allocationTokenAccounts[i];
             const allocationPDA = allocations[i];
             const vaultSeeds = [
                 Buffer.from("allocation"), // Replace with your actual seed string for allocation vault PDA
-                team.publicKey.toBuffer(), // or advisor.publicKey for the second
+                allocationWallets[i].toBuffer(),
+                mint.publicKey.toBuffer(),
             ];
             await program.methods.deleteVault(vaultSeeds.map(b => Array.from(b))).accountsStrict({
                 vault: allocationVault,
@@ -67,7 +67,7 @@ async function getAllocationsAndVesting() {
   const wallets = [team.publicKey, advisor.publicKey]
 
   for (const wallet of wallets) {
-  const seeds = [Buffer.from(ALLOCATION_SEED_PREFIX), wallet.toBuffer()];
+  const seeds = [Buffer.from(ALLOCATION_SEED_PREFIX), wallet.toBuffer(), mintLatest.toBuffer()];
 
 
   const [allocation, bump] = PublicKey.findProgramAddressSync(seeds, programId);
@@ -30,7 +30,7 @@ import dotenv from "dotenv"
 import * as anchor from "@coral-xyz/anchor";
 import { BN, Program } from "@coral-xyz/anchor";
 import { BondingCurve } from "../target/types/bonding_curve";
-import { getAllocationPDAs, getFairLaunchPDAs, getPDAs } from "./utils";
+import { getAllocationPDAs, getFairLaunchPDAs, getPDAs, getTokenomicsPlanPDA } from "./utils";
 import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
 dotenv.config()
 
@@ -89,6 +89,9 @@ async function main() {
         const bondingCurveTransaction = await createBondingCurveTransaction(program, mint.publicKey, signer);
         transactionFirst.add(bondingCurveTransaction);
 
+        const tokenomicsPlanTransaction = await createTokenomicsPlanTransaction(program, mint.publicKey, signer);
+        transactionFirst.add(tokenomicsPlanTransaction);
+
         const allocationTransaction1 = await createAllocationTransactions(program, mint.publicKey, [team.publicKey, advisor.publicKey, user1.publicKey], signer);
         transactionFirst.add(...allocationTransaction1);
 
@@ -273,29 +276,31 @@ async function createAllocationTransactions(
     console.log("Allocation's accounts:", allocations);
     const transactions: Transaction[] = [];
 
-    let percentage = new BN(10)
-    let totalTokens = new BN(1000000000000)
+    const tokenomicsPlan = getTokenomicsPlanPDA(mint, program.programId);
+    let category = { advisors: {} }
+    let totalTokens = new BN(10000000000) // 1% of the planned supply
     let currentTime = Math.floor(Date.now() / 1000);
     let startTime = new BN(currentTime).add(new BN(1000));
     let cliffPeriod = new BN(1000);
     let duration = new BN(1000);
     let interval = new BN(1000);
-    let released = new BN(0);
+    let tgeBps = 0;
 
     let vesting = {
         cliffPeriod: cliffPeriod,
         startTime: startTime,
         duration: duration,
         interval: interval,
-        released: released,
+        tgeBps: tgeBps,
     }
 
 
     for (let i = 0; i < wallets.length; i++) {
         const createAllocationInstruction = await program.methods
-            .createAllocation(percentage.toNumber(), totalTokens, vesting)
+            .createAllocation(category, totalTokens, vesting, false)
             .accountsStrict({
                 allocation: allocations[i],
+                tokenomicsPlan: tokenomicsPlan,
                 wallet: wallets[i],
                 tokenMint: mint,
                 allocationVault: allocationTokenAccounts[i],
@@ -313,6 +318,35 @@ async function createAllocationTransactions(
     return transactions;
 }
 
+async function createTokenomicsPlanTransaction(
+    program: Program<BondingCurve>,
+    mint: PublicKey,
+    signer: Keypair
+): Promise<Transaction> {
+    // Whole minted supply, split between the allocation categories
+    const totalSupply = new BN(1_000_000).mul(new BN(10).pow(new BN(6)));
+    const categories = [
+        { category: { team: {} }, percentage: 20 },
+        { category: { advisors: {} }, percentage: 20 },
+        { category: { treasury: {} }, percentage: 10 },
+        { category: { liquidity: {} }, percentage: 20 },
+        { category: { sale: {} }, percentage: 30 },
+    ];
+
+    const createTokenomicsPlanInstruction = await program.methods
+        .createTokenomicsPlan(totalSupply, categories)
+        .accountsStrict({
+            tokenomicsPlan: getTokenomicsPlanPDA(mint, program.programId),
+            tokenMint: mint,
+            authority: signer.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction();
+
+    return new Transaction().add(createTokenomicsPlanInstruction);
+}
+
 async function createFairLaunchTransaction(
     program: Program<BondingCurve>,
     mint: PublicKey,
@@ -13,6 +13,7 @@ const FEE_POOL_VAULT_PREFIX = "fee_pool_vault"
 
 /// Allocation
 export const ALLOCATION_SEED_PREFIX = "allocation"
+export const TOKENOMICS_PLAN_SEED_PREFIX = "tokenomics_plan"
 
 
 /// Fair Launch
@@ -208,7 +209,7 @@ export const deserializeAllocationAndVesting = (data) => {
 
     // Expected minimum lengths
     const minLengthWithoutVesting = 8 + 32 + 1 + 8 + 8 + 1 + 1; // 51 bytes
-    const minLengthWithVesting = minLengthWithoutVesting + 40; // 91 bytes
+    const minLengthWithVesting = minLengthWithoutVesting + 34; // 85 bytes
 
     let offset = 8; // Skip the 8-byte discriminator
 
@@ -258,16 +259,16 @@ export const deserializeAllocationAndVesting = (data) => {
         const interval = data.readBigInt64LE(offset);
         offset += 8;
 
-        // released: u64 (8 bytes, little-endian, unsigned)
-        const released = data.readBigUInt64LE(offset);
-        offset += 8;
+        // tge_bps: u16 (2 bytes, little-endian, unsigned)
+        const tgeBps = data.readUInt16LE(offset);
+        offset += 2;
 
         vesting = {
             cliffPeriod: cliffPeriod.toString(),
             startTime: startTime.toString(),
             duration: duration.toString(),
             interval: interval.toString(),
-            released: released.toString(),
+            tgeBps,
             startTimeDate: new Date(Number(startTime) * 1000).toISOString(),
             cliffEndDate: new Date((Number(startTime) + Number(cliffPeriod)) * 1000).toISOString(),
             endDate: new Date((Number(startTime) + Number(duration)) * 1000).toISOString(),
@@ -326,6 +327,14 @@ export async function getPDAs(user: PublicKey, mint: PublicKey, programId: Publi
     };
 }
 
+export function getTokenomicsPlanPDA(mint: PublicKey, programId: PublicKey) {
+    const [tokenomicsPlan] = PublicKey.findProgramAddressSync(
+        [Buffer.from(TOKENOMICS_PLAN_SEED_PREFIX), mint.toBuffer()],
+        programId
+    );
+    return tokenomicsPlan;
+}
+
 export function getAllocationPDAs(mint: PublicKey, wallet: PublicKey[], programId: PublicKey) {
     let allocations = []
     let allocationTokenAccounts = []
@@ -0,0 +1,771 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, Transaction, LAMPORTS_PER_SOL } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { ASSOCIATED_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { TOKEN_2022_PROGRAM_ID, createAssociatedTokenAccountIdempotent, createMint, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { fundWallet, getAllocationPDAs, getPDAs, getPlatformConfigPDA, getTokenomicsPlanPDA, initializePlatformConfigIfNeeded, launchTestToken } from "./utils";
+
+describe("allocation claims", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const totalTokens = 1_000_000_000;
+  let mint: PublicKey;
+  let tokenomicsPlan: PublicKey;
+
+  // Validator time, the program vests against Clock and not the local machine time
+  async function chainNow() {
+    const slot = await connection.getSlot();
+    return await connection.getBlockTime(slot);
+  }
+
+  // Create an allocation for a fresh wallet and fund its vault with `totalTokens`
+  async function createFundedAllocation(vesting: object | null, revocable = false, category: object = { team: {} }) {
+    const wallet = Keypair.generate();
+    const tx = new Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: signer.publicKey,
+        toPubkey: wallet.publicKey,
+        lamports: 0.05 * LAMPORTS_PER_SOL,
+      })
+    );
+    await provider.sendAndConfirm(tx);
+
+    const { allocations, allocationTokenAccounts } = getAllocationPDAs(mint, [wallet.publicKey]);
+    await program.methods
+      .createAllocation(category, new BN(totalTokens), vesting, revocable)
+      .accountsStrict({
+        allocation: allocations[0],
+        tokenomicsPlan,
+        tokenMint: mint,
+        allocationVault: allocationTokenAccounts[0],
+        authority: signer.publicKey,
+        wallet: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    await program.methods
+      .fundAllocation(new BN(totalTokens))
+      .accountsStrict({
+        tokenomicsPlan,
+        allocation: allocations[0],
+        allocationVault: allocationTokenAccounts[0],
+        authorityTokenAccount: authorityTokenAccount,
+        tokenMint: mint,
+        authority: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    const userTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, wallet.publicKey);
+
+    return {
+      wallet,
+      allocation: allocations[0],
+      allocationVault: allocationTokenAccounts[0],
+      userTokenAccount: userTokenAccount.address,
+    };
+  }
+
+  function claimTokens(accounts: { wallet: Keypair, allocation: PublicKey, allocationVault: PublicKey, userTokenAccount: PublicKey }) {
+    return program.methods
+      .claimTokens()
+      .accountsStrict({
+        allocation: accounts.allocation,
+        tokenomicsPlan,
+        wallet: accounts.wallet.publicKey,
+        allocationVault: accounts.allocationVault,
+        userTokenAccount: accounts.userTokenAccount,
+        tokenMint: mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      })
+      .signers([accounts.wallet])
+      .rpc();
+  }
+
+  async function balanceOf(tokenAccount: PublicKey) {
+    return Number((await getAccount(connection, tokenAccount)).amount);
+  }
+
+  let authorityTokenAccount: PublicKey;
+
+  before(async () => {
+    mint = await createMint(connection, signer.payer, signer.publicKey, null, 6);
+    authorityTokenAccount = (await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, signer.publicKey)).address;
+    await mintTo(connection, signer.payer, mint, authorityTokenAccount, signer.payer, 100 * totalTokens);
+
+    // Room for 90 team allocations and a single advisor allocation
+    tokenomicsPlan = getTokenomicsPlanPDA(mint);
+    await program.methods
+      .createTokenomicsPlan(new BN(100 * totalTokens), [
+        { category: { team: {} }, percentage: 90 },
+        { category: { advisors: {} }, percentage: 1 },
+      ])
+      .accountsStrict({
+        tokenomicsPlan,
+        tokenMint: mint,
+        bondingCurveAccount: null,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it("lets the curve creator plan a launched token without a mint authority", async () => {
+    await initializePlatformConfigIfNeeded(signer.publicKey);
+    const launchedMint = Keypair.generate();
+    const { bondingCurve } = await launchTestToken(signer.publicKey, launchedMint);
+    const outsider = Keypair.generate();
+    await fundWallet(outsider.publicKey, 0.1 * LAMPORTS_PER_SOL);
+
+    const createPlan = (authority: Keypair) => program.methods
+      .createTokenomicsPlan(new BN(totalTokens), [{ category: { team: {} }, percentage: 20 }])
+      .accountsStrict({
+        tokenomicsPlan: getTokenomicsPlanPDA(launchedMint.publicKey),
+        tokenMint: launchedMint.publicKey,
+        bondingCurveAccount: bondingCurve,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+
+    try {
+      await createPlan(outsider);
+      expect.fail("planned by a wallet other than the creator");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidAuthority");
+    }
+
+    await createPlan(signer.payer);
+    const plan = await program.account.tokenomicsPlan.fetch(getTokenomicsPlanPDA(launchedMint.publicKey));
+    expect(plan.authority.toBase58()).to.equal(signer.publicKey.toBase58());
+  });
+
+  it("keeps the plan with a live mint authority when a holder creates the curve", async () => {
+    const initialSupply = 100_000_000_000;
+    const holder = Keypair.generate();
+    await fundWallet(holder.publicKey);
+    const heldMint = await createMint(connection, signer.payer, signer.publicKey, null, 6);
+    const holderTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, heldMint, holder.publicKey);
+    await mintTo(connection, signer.payer, heldMint, holderTokenAccount.address, signer.payer, initialSupply);
+
+    // any holder of the initial supply can create the curve of the mint
+    const pool = getPDAs(holder.publicKey, heldMint);
+    await program.methods
+      .createPool(
+        holder.publicKey,
+        100,                            // fee percentage
+        new BN(500),                    // initial quorum
+        new BN(1_000_000_000_000),      // target liquidity
+        holder.publicKey,               // governance
+        500,                            // dao quorum
+        0,                              // linear curve
+        new BN(10_000_000_000),         // max token supply
+        new BN(60),                     // liquidity lock period
+        50,                             // liquidity pool percentage
+        new BN(100),                    // initial price
+        new BN(initialSupply),
+        [{ address: holder.publicKey, share: 10000, amount: new BN(0), lockingPeriod: new BN(0), kind: { wallet: {} } }],
+        5000,                           // reserve ratio
+        null,
+      )
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: heldMint,
+        poolTokenAccount: pool.poolTokenAccount,
+        poolSolVault: pool.poolSolVault,
+        feeVault: pool.feeVault,
+        userTokenAccount: holderTokenAccount.address,
+        platformConfig: getPlatformConfigPDA(),
+        admin: holder.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      })
+      .signers([holder])
+      .rpc();
+
+    const createPlan = (authority: Keypair) => program.methods
+      .createTokenomicsPlan(new BN(totalTokens), [{ category: { team: {} }, percentage: 20 }])
+      .accountsStrict({
+        tokenomicsPlan: getTokenomicsPlanPDA(heldMint),
+        tokenMint: heldMint,
+        bondingCurveAccount: pool.bondingCurve,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+
+    try {
+      await createPlan(holder);
+      expect.fail("the curve creator took the plan of a mint with a live authority");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidAuthority");
+    }
+
+    await createPlan(signer.payer);
+    const plan = await program.account.tokenomicsPlan.fetch(getTokenomicsPlanPDA(heldMint));
+    expect(plan.authority.toBase58()).to.equal(signer.publicKey.toBase58());
+  });
+
+  it("claims the full allocation without vesting", async () => {
+    const accounts = await createFundedAllocation(null);
+
+    await claimTokens(accounts);
+
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens);
+    expect(await balanceOf(accounts.allocationVault)).to.equal(0);
+    const allocation = await program.account.allocation.fetch(accounts.allocation);
+    expect(allocation.claimedTokens.toNumber()).to.equal(totalTokens);
+  });
+
+  it("re-claims nothing once everything is claimed", async () => {
+    const accounts = await createFundedAllocation(null);
+
+    await claimTokens(accounts);
+    await claimTokens(accounts);
+
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens);
+    const allocation = await program.account.allocation.fetch(accounts.allocation);
+    expect(allocation.claimedTokens.toNumber()).to.equal(totalTokens);
+  });
+
+  it("releases only the vested part of a partial vest", async () => {
+    const now = await chainNow();
+    const tgeAmount = totalTokens * 0.2;
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(0),
+      startTime: new BN(now - 3000),
+      duration: new BN(1_000_000),
+      interval: new BN(0),
+      tgeBps: 2000,
+    });
+
+    await claimTokens(accounts);
+    const firstClaim = await balanceOf(accounts.userTokenAccount);
+    expect(firstClaim).to.be.greaterThan(tgeAmount);
+    expect(firstClaim).to.be.lessThan(totalTokens);
+
+    let allocation = await program.account.allocation.fetch(accounts.allocation);
+    expect(allocation.claimedTokens.toNumber()).to.equal(firstClaim);
+
+    // Re-claiming only adds what vested in between
+    await claimTokens(accounts);
+    const secondClaim = await balanceOf(accounts.userTokenAccount);
+    expect(secondClaim).to.be.at.least(firstClaim);
+    expect(secondClaim).to.be.lessThan(totalTokens);
+
+    allocation = await program.account.allocation.fetch(accounts.allocation);
+    expect(allocation.claimedTokens.toNumber()).to.equal(secondClaim);
+    expect(await balanceOf(accounts.allocationVault)).to.equal(totalTokens - secondClaim);
+  });
+
+  it("unlocks a partial vest in whole intervals", async () => {
+    const now = await chainNow();
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(0),
+      startTime: new BN(now - 3000),
+      duration: new BN(1_000_000),
+      interval: new BN(100_000),
+      tgeBps: 2000,
+    });
+
+    // Less than one interval elapsed, only the TGE share is unlocked
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens * 0.2);
+  });
+
+  it("counts only the elapsed whole intervals on top of the TGE share", async () => {
+    const now = await chainNow();
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(0),
+      startTime: new BN(now - 25),
+      duration: new BN(100),
+      interval: new BN(10),
+      tgeBps: 1000,
+    });
+
+    // 25s elapsed count as 2 intervals: 10% + 90% * 20 / 100
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens * 0.28);
+  });
+
+  it("unlocks the TGE share when the vesting starts on the validator clock", async () => {
+    const now = await chainNow();
+    const startTime = now + 4;
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(0),
+      startTime: new BN(startTime),
+      duration: new BN(1000),
+      interval: new BN(100),
+      tgeBps: 2000,
+    });
+
+    try {
+      await claimTokens(accounts);
+      expect.fail("claim before the vesting start succeeded");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("CliffNotReached");
+    }
+
+    while ((await chainNow()) <= startTime) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens * 0.2);
+  });
+
+  it("caps the vested amount at the allocation once the duration is over", async () => {
+    const now = await chainNow();
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(100),
+      startTime: new BN(now - 5000),
+      duration: new BN(1000),
+      interval: new BN(300),
+      tgeBps: 5000,
+    });
+
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens);
+    expect(await balanceOf(accounts.allocationVault)).to.equal(0);
+
+    await claimTokens(accounts);
+    const allocation = await program.account.allocation.fetch(accounts.allocation);
+    expect(allocation.claimedTokens.toNumber()).to.equal(totalTokens);
+  });
+
+  it("rejects a claim before the cliff when nothing is unlocked", async () => {
+    const now = await chainNow();
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(1000),
+      startTime: new BN(now + 1000),
+      duration: new BN(1000),
+      interval: new BN(0),
+      tgeBps: 0,
+    });
+
+    try {
+      await claimTokens(accounts);
+      expect.fail("claim before the cliff succeeded");
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal("CliffNotReached");
+    }
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(0);
+  });
+
+  function revokeAllocation(accounts: { allocation: PublicKey, allocationVault: PublicKey }, treasuryTokenAccount: PublicKey, authority: Keypair) {
+    return program.methods
+      .revokeAllocation()
+      .accountsStrict({
+        allocation: accounts.allocation,
+        tokenomicsPlan,
+        allocationVault: accounts.allocationVault,
+        treasuryTokenAccount,
+        tokenMint: mint,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  it("revokes a revocable allocation and claws back the unvested tokens", async () => {
+    const now = await chainNow();
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(0),
+      startTime: new BN(now - 3000),
+      duration: new BN(1_000_000),
+      interval: new BN(100_000),
+      tgeBps: 2000,
+    }, true);
+    const treasury = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, signer.publicKey);
+    const treasuryBefore = await balanceOf(treasury.address);
+
+    await revokeAllocation(accounts, treasury.address, signer.payer);
+
+    // Vesting is frozen at the TGE share, the rest goes to the treasury
+    const vested = totalTokens * 0.2;
+    expect(await balanceOf(treasury.address)).to.equal(treasuryBefore + totalTokens - vested);
+    expect(await balanceOf(accounts.allocationVault)).to.equal(vested);
+
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(vested);
+
+    // Nothing vests after the revocation
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(vested);
+  });
+
+  it("rejects revoking a non-revocable allocation", async () => {
+    const accounts = await createFundedAllocation(null);
+    const treasury = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, signer.publicKey);
+
+    try {
+      await revokeAllocation(accounts, treasury.address, signer.payer);
+      expect.fail("revoked a non-revocable allocation");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("AllocationNotRevocable");
+    }
+  });
+
+  it("rejects revoking by another wallet", async () => {
+    const accounts = await createFundedAllocation(null, true);
+    const treasury = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, signer.publicKey);
+
+    try {
+      await revokeAllocation(accounts, treasury.address, accounts.wallet);
+      expect.fail("revoked by another wallet");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidAuthority");
+    }
+  });
+
+  function achieveMilestone(accounts: { allocation: PublicKey }, index: number, approver: Keypair) {
+    return program.methods
+      .achieveMilestone(index)
+      .accountsStrict({
+        allocation: accounts.allocation,
+        tokenMint: mint,
+        curveConfiguration: null,
+        approver: approver.publicKey,
+      })
+      .signers([approver])
+      .rpc();
+  }
+
+  function setMilestones(allocation: PublicKey, milestones: object[], oracle: PublicKey) {
+    return program.methods
+      .updateAllocation(milestones, oracle, null)
+      .accountsStrict({ allocation, tokenMint: mint, authority: signer.publicKey })
+      .rpc();
+  }
+
+  it("rejects milestones on a non-revocable allocation", async () => {
+    const accounts = await createFundedAllocation(null);
+
+    try {
+      await setMilestones(accounts.allocation, [{ descriptionHash: Array(32).fill(1), unlockBps: 10000, achieved: false }], signer.publicKey);
+      expect.fail("milestones gated a non-revocable allocation");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("AllocationNotRevocable");
+    }
+  });
+
+  it("rejects milestones once part of the allocation vested", async () => {
+    // Without vesting the whole allocation is vested right away, even before any claim
+    const accounts = await createFundedAllocation(null, true);
+
+    try {
+      await setMilestones(accounts.allocation, [{ descriptionHash: Array(32).fill(1), unlockBps: 5000, achieved: false }], signer.publicKey);
+      expect.fail("milestones took back vested tokens");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidMilestones");
+    }
+  });
+
+  it("releases milestone shares once the oracle marks them achieved", async () => {
+    const oracle = Keypair.generate();
+    // Nothing vests before the start, then the non-milestone part unlocks at once
+    const startTime = (await chainNow()) + 4;
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(0),
+      startTime: new BN(startTime),
+      duration: new BN(1),
+      interval: new BN(0),
+      tgeBps: 0,
+    }, true);
+    await setMilestones(accounts.allocation, [
+      { descriptionHash: Array(32).fill(1), unlockBps: 3000, achieved: false },
+      { descriptionHash: Array(32).fill(2), unlockBps: 2000, achieved: false },
+    ], oracle.publicKey);
+
+    while ((await chainNow()) <= startTime + 1) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens * 0.5);
+
+    await achieveMilestone(accounts, 0, oracle);
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens * 0.8);
+
+    try {
+      await achieveMilestone(accounts, 1, accounts.wallet);
+      expect.fail("milestone achieved by the recipient");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidAuthority");
+    }
+
+    try {
+      await achieveMilestone(accounts, 0, oracle);
+      expect.fail("milestone achieved twice");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("MilestoneAlreadyAchieved");
+    }
+
+    await achieveMilestone(accounts, 1, oracle);
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens);
+  });
+
+  it("keeps allocations within their category budget", async () => {
+    await createFundedAllocation(null, false, { advisors: {} });
+
+    try {
+      await createFundedAllocation(null, false, { advisors: {} });
+      expect.fail("allocation above the category budget");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("CategoryBudgetExceeded");
+    }
+
+    try {
+      await createFundedAllocation(null, false, { sale: {} });
+      expect.fail("allocation in a category outside the plan");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("CategoryNotInPlan");
+    }
+  });
+
+  // Batch allocations for fresh wallets, all held in the tokenomics plan shared vault
+  // `prefund` lamports are sent to each allocation address beforehand
+  async function createAllocationsBatch(count: number, vesting: object | null, fund: boolean, revocable = false, prefund = 0) {
+    const wallets = Array.from({ length: count }, () => Keypair.generate());
+    const { allocations } = getAllocationPDAs(mint, wallets.map((wallet) => wallet.publicKey));
+    const tx = new Transaction();
+    for (const [i, wallet] of wallets.entries()) {
+      tx.add(
+        SystemProgram.transfer({
+          fromPubkey: signer.publicKey,
+          toPubkey: wallet.publicKey,
+          lamports: 0.05 * LAMPORTS_PER_SOL,
+        })
+      );
+      if (prefund > 0) {
+        tx.add(
+          SystemProgram.transfer({
+            fromPubkey: signer.publicKey,
+            toPubkey: allocations[i],
+            lamports: prefund,
+          })
+        );
+      }
+    }
+    await provider.sendAndConfirm(tx);
+
+    const sharedVault = getAssociatedTokenAddressSync(mint, tokenomicsPlan, true);
+    await program.methods
+      .createAllocationsBatch(
+        { team: {} },
+        wallets.map((wallet) => ({ wallet: wallet.publicKey, totalTokens: new BN(totalTokens), vesting })),
+        revocable,
+      )
+      .accountsStrict({
+        tokenomicsPlan,
+        tokenMint: mint,
+        sharedVault,
+        authorityTokenAccount: fund ? authorityTokenAccount : null,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      })
+      .remainingAccounts(allocations.map((allocation) => ({ pubkey: allocation, isWritable: true, isSigner: false })))
+      .rpc();
+
+    return Promise.all(wallets.map(async (wallet, i) => ({
+      wallet,
+      allocation: allocations[i],
+      allocationVault: sharedVault,
+      userTokenAccount: (await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, wallet.publicKey)).address,
+    })));
+  }
+
+  it("creates and funds a batch of allocations in the shared vault", async () => {
+    const sharedVault = getAssociatedTokenAddressSync(mint, tokenomicsPlan, true);
+    const planBefore = await program.account.tokenomicsPlan.fetch(tokenomicsPlan);
+    const batch = await createAllocationsBatch(3, null, true);
+    const vaultBefore = await balanceOf(sharedVault);
+
+    for (const accounts of batch) {
+      const allocation = await program.account.allocation.fetch(accounts.allocation);
+      expect(allocation.sharedVault).to.equal(true);
+      expect(allocation.fundedTokens.toNumber()).to.equal(totalTokens);
+    }
+
+    const plan = await program.account.tokenomicsPlan.fetch(tokenomicsPlan);
+    expect(plan.categories[0].allocated.toNumber()).to.equal(planBefore.categories[0].allocated.toNumber() + 3 * totalTokens);
+    expect(plan.categories[0].funded.toNumber()).to.equal(planBefore.categories[0].funded.toNumber() + 3 * totalTokens);
+
+    await claimTokens(batch[0]);
+    expect(await balanceOf(batch[0].userTokenAccount)).to.equal(totalTokens);
+    expect(await balanceOf(sharedVault)).to.equal(vaultBefore - totalTokens);
+
+    // The claimed allocation can't dip into the others' tokens
+    await claimTokens(batch[0]);
+    expect(await balanceOf(batch[0].userTokenAccount)).to.equal(totalTokens);
+  });
+
+  it("creates batch allocations at addresses that already hold lamports", async () => {
+    // one below and one above the rent-exempt minimum of an allocation
+    const below = await createAllocationsBatch(1, null, true, false, 1_000);
+    const above = await createAllocationsBatch(1, null, true, false, 0.05 * LAMPORTS_PER_SOL);
+
+    for (const [accounts] of [below, above]) {
+      const info = await connection.getAccountInfo(accounts.allocation);
+      expect(info.owner.toBase58()).to.equal(program.programId.toBase58());
+      expect(info.lamports).to.be.at.least(await connection.getMinimumBalanceForRentExemption(info.data.length));
+
+      await claimTokens(accounts);
+      expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens);
+    }
+  });
+
+  it("only releases the funded part of an unfunded batch allocation", async () => {
+    const [accounts] = await createAllocationsBatch(1, null, false);
+
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(0);
+
+    await program.methods
+      .fundAllocation(new BN(totalTokens / 2))
+      .accountsStrict({
+        tokenomicsPlan,
+        allocation: accounts.allocation,
+        allocationVault: accounts.allocationVault,
+        authorityTokenAccount: authorityTokenAccount,
+        tokenMint: mint,
+        authority: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(totalTokens / 2);
+  });
+
+  it("claws back only the revoked allocation's tokens from the shared vault", async () => {
+    const now = await chainNow();
+    const [accounts] = await createAllocationsBatch(1, {
+      cliffPeriod: new BN(0),
+      startTime: new BN(now - 3000),
+      duration: new BN(1_000_000),
+      interval: new BN(100_000),
+      tgeBps: 2000,
+    }, true, true);
+    const treasury = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, signer.publicKey);
+    const treasuryBefore = await balanceOf(treasury.address);
+    const vaultBefore = await balanceOf(accounts.allocationVault);
+
+    await revokeAllocation(accounts, treasury.address, signer.payer);
+
+    const vested = totalTokens * 0.2;
+    expect(await balanceOf(treasury.address)).to.equal(treasuryBefore + totalTokens - vested);
+    expect(await balanceOf(accounts.allocationVault)).to.equal(vaultBefore - (totalTokens - vested));
+
+    await claimTokens(accounts);
+    expect(await balanceOf(accounts.userTokenAccount)).to.equal(vested);
+  });
+
+  function transferAllocation(
+    accounts: { wallet: Keypair, allocation: PublicKey, allocationVault: PublicKey },
+    newWallet: PublicKey,
+    newAllocationVault: PublicKey | null,
+  ) {
+    const { allocations } = getAllocationPDAs(mint, [newWallet]);
+    return program.methods
+      .transferAllocation()
+      .accountsStrict({
+        allocation: accounts.allocation,
+        newAllocation: allocations[0],
+        tokenomicsPlan,
+        allocationVault: accounts.allocationVault,
+        newAllocationVault,
+        wallet: accounts.wallet.publicKey,
+        newWallet,
+        authority: signer.publicKey,
+        tokenMint: mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([accounts.wallet])
+      .rpc();
+  }
+
+  function setTransferable(allocation: PublicKey, transferable: boolean) {
+    return program.methods
+      .updateAllocation(null, null, transferable)
+      .accountsStrict({ allocation, tokenMint: mint, authority: signer.publicKey })
+      .rpc();
+  }
+
+  it("moves a transferable allocation to a new wallet", async () => {
+    const now = await chainNow();
+    const accounts = await createFundedAllocation({
+      cliffPeriod: new BN(0),
+      startTime: new BN(now - 3000),
+      duration: new BN(1_000_000),
+      interval: new BN(100_000),
+      tgeBps: 2000,
+    });
+    await claimTokens(accounts);
+    const claimed = totalTokens * 0.2;
+
+    const newWallet = Keypair.generate();
+    const { allocations, allocationTokenAccounts } = getAllocationPDAs(mint, [newWallet.publicKey]);
+    await createAssociatedTokenAccountIdempotent(connection, signer.payer, mint, allocations[0], {}, TOKEN_PROGRAM_ID, ASSOCIATED_PROGRAM_ID, true);
+
+    try {
+      await transferAllocation(accounts, newWallet.publicKey, allocationTokenAccounts[0]);
+      expect.fail("transferred a non-transferable allocation");
+    } catch (error) {
+      expect((error as AnchorError).error.errorCode.code).to.equal("AllocationNotTransferable");
+    }
+
+    await setTransferable(accounts.allocation, true);
+    await transferAllocation(accounts, newWallet.publicKey, allocationTokenAccounts[0]);
+
+    expect(await connection.getAccountInfo(accounts.allocation)).to.equal(null);
+    expect(await connection.getAccountInfo(accounts.allocationVault)).to.equal(null);
+    expect(await balanceOf(allocationTokenAccounts[0])).to.equal(totalTokens - claimed);
+
+    const allocation = await program.account.allocation.fetch(allocations[0]);
+    expect(allocation.wallet.toBase58()).to.equal(newWallet.publicKey.toBase58());
+    expect(allocation.claimedTokens.toNumber()).to.equal(claimed);
+    expect(allocation.vesting.tgeBps).to.equal(2000);
+  });
+
+  it("moves a shared vault allocation without moving tokens", async () => {
+    const [accounts] = await createAllocationsBatch(1, null, true);
+    const vaultBefore = await balanceOf(accounts.allocationVault);
+    await setTransferable(accounts.allocation, true);
+
+    const newWallet = Keypair.generate();
+    await transferAllocation(accounts, newWallet.publicKey, null);
+    expect(await balanceOf(accounts.allocationVault)).to.equal(vaultBefore);
+
+    const { allocations } = getAllocationPDAs(mint, [newWallet.publicKey]);
+    const allocation = await program.account.allocation.fetch(allocations[0]);
+    expect(allocation.sharedVault).to.equal(true);
+    expect(allocation.fundedTokens.toNumber()).to.equal(totalTokens);
+  });
+});
@@ -0,0 +1,316 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import { createBatchAuctionTestLaunch, fundWallet, getBatchAuctionPDAs } from "./utils";
+
+describe("batch auction", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const bidders = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  const unit = 1_000_000;                     // 6 decimals
+  const minPrice = 1_000_000;                 // reserve price, lamports per whole token
+  const tickSize = 100_000;
+  const totalTokens = 100 * unit;             // 0.1 SOL at the reserve price
+  const sol = (amount: number) => amount * LAMPORTS_PER_SOL;
+
+  type Auction = Awaited<ReturnType<typeof createBatchAuctionTestLaunch>>;
+  let full: Auction;
+  let partial: Auction;
+  let undersubscribed: Auction;
+  let endTime: number;
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+  const tokenBalance = async (account: anchor.web3.PublicKey) => Number((await getAccount(connection, account)).amount);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function bid(auction: Auction, bidder: Keypair, maxPrice: number, amount: number) {
+    return program.methods
+      .bidBatchAuction(new BN(maxPrice), new BN(amount))
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        bid: getBatchAuctionPDAs(auction.mint, bidder.publicKey).bid,
+        contributionVault: auction.contributionVault,
+        bidder: bidder.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bidder])
+      .rpc();
+  }
+
+  function crank(auction: Auction) {
+    return program.methods
+      .crankBatchAuction()
+      .accountsStrict({ auctionData: auction.auctionData, tokenMint: auction.mint, payer: signer.publicKey })
+      .rpc();
+  }
+
+  function refund(auction: Auction, bidder: Keypair) {
+    return program.methods
+      .refundBatchAuctionBid()
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        bid: getBatchAuctionPDAs(auction.mint, bidder.publicKey).bid,
+        contributionVault: auction.contributionVault,
+        bidder: bidder.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bidder])
+      .rpc();
+  }
+
+  // Claims and returns the tokens received and the SOL refunded
+  async function claim(auction: Auction, bidder: Keypair) {
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, auction.mint, bidder.publicKey);
+    const tokensBefore = await tokenBalance(tokenAccount.address);
+    const balanceBefore = await connection.getBalance(bidder.publicKey);
+    await program.methods
+      .claimBatchAuction()
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        bid: getBatchAuctionPDAs(auction.mint, bidder.publicKey).bid,
+        tokenMint: auction.mint,
+        launchpadVault: auction.launchpadTokenAccount,
+        bidderTokenAccount: tokenAccount.address,
+        contributionVault: auction.contributionVault,
+        bidder: bidder.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bidder])
+      .rpc();
+    return {
+      tokens: (await tokenBalance(tokenAccount.address)) - tokensBefore,
+      refund: (await connection.getBalance(bidder.publicKey)) - balanceBefore,
+    };
+  }
+
+  async function withdrawProceeds(auction: Auction) {
+    await program.methods
+      .withdrawBatchAuctionProceeds()
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        contributionVault: auction.contributionVault,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    // every lamport escrowed went out as proceeds or refunds, only the vault rent is left
+    expect(await connection.getBalance(auction.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+  }
+
+  before(async () => {
+    for (const bidder of bidders) {
+      await fundWallet(bidder.publicKey);
+    }
+
+    const now = Math.floor(Date.now() / 1000);
+    const startTime = now + 15;
+    endTime = now + 30;
+    const params = { startTime, endTime, minPrice, tickSize, minBid: 10_000, totalTokens };
+    full = await createBatchAuctionTestLaunch(params);
+    partial = await createBatchAuctionTestLaunch(params);
+    undersubscribed = await createBatchAuctionTestLaunch(params);
+  });
+
+  it("only takes bids on a price tick", async () => {
+    const data = await program.account.batchAuctionData.fetch(full.auctionData);
+    await sleepUntil(data.startTime.toNumber() + 2);
+
+    await expectError(bid(full, bidders[0], minPrice - tickSize, sol(0.01)), "InvalidAuctionPrice");
+    await expectError(bid(full, bidders[0], minPrice + tickSize / 2, sol(0.01)), "InvalidAuctionPrice");
+    // 64 ticks, the highest one is min_price + 63 ticks
+    await expectError(bid(full, bidders[0], minPrice + 64 * tickSize, sol(0.01)), "InvalidAuctionPrice");
+  });
+
+  it("counts each bid in the demand of its tick whatever the order", async () => {
+    // lowest price first: with a sorted crank this order used to matter
+    await bid(full, bidders[1], minPrice, sol(0.05));
+    await bid(full, bidders[0], 1_500_000, sol(0.125));
+
+    await bid(partial, bidders[0], 1_500_000, sol(0.09));
+    await bid(partial, bidders[1], 1_200_000, sol(0.04));
+    await bid(partial, bidders[2], 1_200_000, sol(0.02));
+
+    await bid(undersubscribed, bidders[1], minPrice, sol(0.02));
+    await bid(undersubscribed, bidders[0], 1_500_000, sol(0.03));
+
+    const data = await program.account.batchAuctionData.fetch(partial.auctionData);
+    expect(data.tickDemand[2].toNumber()).to.equal(sol(0.06));
+    expect(data.tickDemand[5].toNumber()).to.equal(sol(0.09));
+    expect(data.totalEscrowed.toNumber()).to.equal(sol(0.15));
+  });
+
+  it("finalizes in a single crank once the auction ended", async () => {
+    await expectError(crank(full), "SaleNotEnded");
+    await sleepUntil(endTime + 2);
+
+    for (const auction of [full, partial, undersubscribed]) {
+      await crank(auction);
+      await expectError(crank(auction), "AlreadyFinalized");
+    }
+  });
+
+  it("fills the whole supply above the cutoff tick at a price between ticks", async () => {
+    const data = await program.account.batchAuctionData.fetch(full.auctionData);
+    // 0.125 SOL buys the 100 tokens at 1.25 SOL per 1000 tokens, between the 1.2 and 1.3 ticks
+    expect(data.clearingPrice.toNumber()).to.equal(1_250_000);
+    expect(data.tokensSold.toNumber()).to.equal(totalTokens);
+
+    const winner = await claim(full, bidders[0]);
+    expect(winner.tokens).to.equal(totalTokens);
+    expect(winner.refund).to.equal(0);
+
+    // the reserve price bid sits below the cutoff and gets everything back
+    const loser = await claim(full, bidders[1]);
+    expect(loser.tokens).to.equal(0);
+    expect(loser.refund).to.equal(sol(0.05));
+    await expectError(claim(full, bidders[1]), "AlreadyClaimed");
+
+    await withdrawProceeds(full);
+  });
+
+  it("shares the rest of the supply pro rata between the bids at the cutoff tick", async () => {
+    const data = await program.account.batchAuctionData.fetch(partial.auctionData);
+    expect(data.clearingPrice.toNumber()).to.equal(1_200_000);
+    expect(data.cutoffPrice.toNumber()).to.equal(1_200_000);
+    // the 1.5 tick buys 0.09 / 1.2 = 75 tokens, the 1.2 tick shares the last 25
+    expect(data.marginalTokens.toNumber()).to.equal(25 * unit);
+
+    const above = await claim(partial, bidders[0]);
+    expect(above.tokens).to.equal(75 * unit);
+    expect(above.refund).to.equal(0);
+
+    const larger = await claim(partial, bidders[1]);
+    expect(larger.tokens).to.equal(16_666_666);
+    expect(larger.refund).to.equal(sol(0.04) - 20_000_000);
+
+    const smaller = await claim(partial, bidders[2]);
+    expect(smaller.tokens).to.equal(8_333_333);
+    expect(smaller.refund).to.equal(sol(0.02) - 10_000_000);
+
+    await withdrawProceeds(partial);
+  });
+
+  it("fills every bid at the reserve price when undersubscribed", async () => {
+    const data = await program.account.batchAuctionData.fetch(undersubscribed.auctionData);
+    expect(data.clearingPrice.toNumber()).to.equal(minPrice);
+    expect(data.cutoffPrice.toNumber()).to.equal(0);
+    expect(data.tokensSold.toNumber()).to.equal(50 * unit);
+
+    // a bid is never filled above the reserve price
+    const high = await claim(undersubscribed, bidders[0]);
+    expect(high.tokens).to.equal(30 * unit);
+    expect(high.refund).to.equal(0);
+    const low = await claim(undersubscribed, bidders[1]);
+    expect(low.tokens).to.equal(20 * unit);
+
+    const authorityBefore = await tokenBalance(undersubscribed.authorityTokenAccount);
+    await program.methods
+      .withdrawUnsoldBatchAuctionTokens()
+      .accountsStrict({
+        auctionData: undersubscribed.auctionData,
+        tokenMint: undersubscribed.mint,
+        launchpadVault: undersubscribed.launchpadTokenAccount,
+        authorityTokenAccount: undersubscribed.authorityTokenAccount,
+        authority: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    expect((await tokenBalance(undersubscribed.authorityTokenAccount)) - authorityBefore).to.equal(50 * unit);
+
+    await withdrawProceeds(undersubscribed);
+  });
+
+  it("keeps the escrow refund closed while the auction can still be finalized", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const pending = await createBatchAuctionTestLaunch({
+      startTime: now + 8,
+      endTime: now + 12,
+      minPrice,
+      tickSize,
+      minBid: 10_000,
+      totalTokens,
+    });
+    await sleepUntil(now + 10);
+    await bid(pending, bidders[0], minPrice, sol(0.01));
+    await sleepUntil(now + 14);
+
+    await expectError(refund(pending, bidders[0]), "FinalizationWindowOpen");
+
+    await crank(pending);
+    await expectError(refund(pending, bidders[0]), "AlreadyFinalized");
+  });
+
+  it("returns the sale tokens on cancellation and refunds the escrow right away", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const endTime = now + 16;
+    const cancelled = await createBatchAuctionTestLaunch({
+      startTime: now + 8,
+      endTime,
+      minPrice,
+      tickSize,
+      minBid: 10_000,
+      totalTokens,
+    });
+    await sleepUntil(now + 10);
+    await bid(cancelled, bidders[1], minPrice, sol(0.02));
+    await expectError(refund(cancelled, bidders[1]), "FinalizationWindowOpen");
+
+    const cancel = () => program.methods
+      .cancelLaunch()
+      .accountsStrict({
+        whitelistData: null,
+        fairLaunchData: null,
+        auctionData: null,
+        batchAuctionData: cancelled.auctionData,
+        tokenMint: cancelled.mint,
+        launchpadVault: cancelled.launchpadTokenAccount,
+        authorityTokenAccount: cancelled.authorityTokenAccount,
+        authority: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    const authorityBefore = await tokenBalance(cancelled.authorityTokenAccount);
+    await cancel();
+    expect((await tokenBalance(cancelled.authorityTokenAccount)) - authorityBefore).to.equal(totalTokens);
+    expect(await tokenBalance(cancelled.launchpadTokenAccount)).to.equal(0);
+    await expectError(bid(cancelled, bidders[2], minPrice, sol(0.02)), "LaunchCancelled");
+    await expectError(cancel(), "LaunchCancelled");
+
+    const balanceBefore = await connection.getBalance(bidders[1].publicKey);
+    await refund(cancelled, bidders[1]);
+    expect((await connection.getBalance(bidders[1].publicKey)) - balanceBefore).to.equal(sol(0.02));
+    await expectError(refund(cancelled, bidders[1]), "AlreadyClaimed");
+    expect(await connection.getBalance(cancelled.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+
+    await sleepUntil(endTime + 2);
+    await expectError(crank(cancelled), "LaunchCancelled");
+    const data = await program.account.batchAuctionData.fetch(cancelled.auctionData);
+    expect(data.cancelled).to.equal(true);
+    expect(data.expired).to.equal(false);
+  });
+});
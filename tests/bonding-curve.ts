@@ -66,6 +66,7 @@ describe("bonding_curve", () => {
       //     share: 10000,
       //     amount: new BN(0),
       //     lockingPeriod: new BN(60000),
+      //     kind: { wallet: {} },
       //   },
       // ]
   //     const {curveConfig, bondingCurve, poolTokenAccount, poolSolVault, userTokenAccount } = getPDAs(signer.publicKey, mintLatest)
@@ -274,6 +275,7 @@ describe("bonding_curve", () => {
   //         share: 4000,
   //         amount: new BN(0),
   //         lockingPeriod: new BN(60000),
+  //         kind: { wallet: {} },
   //       },
   //       {
   //         address: feeRecipient2.publicKey,
@@ -281,6 +283,7 @@ describe("bonding_curve", () => {
   //         share: 4000,
   //         amount: new BN(0),
   //         lockingPeriod: new BN(60000),
+  //         kind: { wallet: {} },
   //       },
   //       {
   //         address: multisig,
@@ -288,6 +291,7 @@ describe("bonding_curve", () => {
   //         share: 2000,
   //         amount: new BN(0),
   //         lockingPeriod: new BN(60000),
+  //         kind: { wallet: {} },
   //       }
   //     ]
   //     const tx = new Transaction()
@@ -1144,6 +1148,7 @@ describe("bonding_curve", () => {
   //     let maxContribution = new BN(2_000_000_000); // 2 SOL
   //     let maxTokensPerWallet = new BN(1000);
   //     let distributionDelay = new BN(3600); // 1 hour
+  //     let totalTokens = new BN(1_000_000_000_000);
   //     let currentTime = Math.floor(Date.now() / 1000);
   //     let startTime = new BN(currentTime + 60); // 1 min from now
   //     let endTime = new BN(currentTime + 3600); // 1 hour from now
@@ -1158,7 +1163,8 @@ describe("bonding_curve", () => {
   //           minContribution,
   //           maxContribution,
   //           maxTokensPerWallet,
-  //           distributionDelay
+  //           distributionDelay,
+  //           totalTokens
   //         )
   //         .accountsStrict({
   //           fairLaunchData: fairLaunchData,
@@ -1248,28 +1254,28 @@ describe("bonding_curve", () => {
   //     let user2Keypair = getKeypairFromFile(`${os.homedir()}/.config/solana/id3.json`);
   //     const { allocations, allocationTokenAccounts } = getAllocationPDAs(mint, [user1Keypair.publicKey, user2Keypair.publicKey])
   //     let category = "Team"
-  //     let percentage = new BN(10)
   //     let totalTokens = new BN(1000000000000)
   //     let currentTime = Math.floor(Date.now() / 1000);
   //     let startTime = new BN(currentTime).add(new BN(1000));
   //     let cliffPeriod = new BN(1000);
   //     let duration = new BN(1000);
   //     let interval = new BN(1000);
-  //     let released = new BN(0);
+  //     let tgeBps = 0;
 
   //     let vesting = {
   //       cliffPeriod: cliffPeriod,
   //       startTime: startTime,
   //       duration: duration,
   //       interval: interval,
-  //       released: released,
+  //       tgeBps: tgeBps,
   //     }
 
   //     const instructions = [
   //       await program.methods
-  //           .createAllocation(category, percentage, totalTokens, vesting)
+  //           .createAllocation({ team: {} }, totalTokens, vesting, false)
   //           .accountsStrict({
   //             allocation: allocations[0],
+  //             tokenomicsPlan: getTokenomicsPlanPDA(mint),
   //             wallet: user1Keypair.publicKey,
   //             tokenMint: mint,
   //             allocationVault: allocationTokenAccounts[0],
@@ -1281,9 +1287,10 @@ describe("bonding_curve", () => {
   //           })
   //           .instruction(),
   //       await program.methods
-  //           .createAllocation(category, percentage, totalTokens, vesting)
+  //           .createAllocation({ team: {} }, totalTokens, vesting, false)
   //           .accountsStrict({
   //             allocation: allocations[1],
+  //             tokenomicsPlan: getTokenomicsPlanPDA(mint),
   //             wallet: user2Keypair.publicKey,
   //             tokenMint: mint,
   //             allocationVault: allocationTokenAccounts[1],
@@ -1316,7 +1323,6 @@ describe("bonding_curve", () => {
 
   //     let user1Keypair = getKeypairFromFile(`${os.homedir()}/.config/solana/id2.json`);
   //     const { allocations, allocationTokenAccounts, userTokenAccounts } = getAllocationPDAs(mint, [user1Keypair.publicKey])
-  //     let now = Math.floor(Date.now() / 1000);
   //     console.log("User token account : ", userTokenAccounts[0])
       
 
@@ -1329,7 +1335,7 @@ describe("bonding_curve", () => {
   //     const tx = new Transaction()
   //       tx.add(
   //         await program.methods
-  //           .claimTokens(new BN(now))
+  //           .claimTokens()
   //           .accountsStrict({
   //             allocation: allocations[0],
   //             wallet: user1Keypair.publicKey,
@@ -0,0 +1,134 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotent,
+  getMint,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import { fundWallet, getPlatformConfigPDA, initializePlatformConfigIfNeeded, launchTestToken } from "./utils";
+
+describe("buyback and burn", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const platformConfig = getPlatformConfigPDA();
+  const mint = Keypair.generate();
+  const buyer = Keypair.generate();
+  const cranker = Keypair.generate();
+  const buyAmount = 0.5 * LAMPORTS_PER_SOL;
+  const poolFeeBps = 200;
+
+  let pool: Awaited<ReturnType<typeof launchTestToken>>;
+
+  const buybackRecipient = async () => {
+    const config = await program.account.curveConfiguration.fetch(pool.curveConfig);
+    return config.feeRecipients.find((r) => "buybackBurn" in r.kind);
+  };
+
+  function executeBuyback(minTokensOut: number) {
+    return program.methods
+      .executeBuyback(new BN(minTokensOut))
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: mint.publicKey,
+        poolTokenAccount: pool.poolTokenAccount,
+        poolSolVault: pool.poolSolVault,
+        feeVault: pool.feeVault,
+        platformConfig,
+        cranker: cranker.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cranker])
+      .rpc();
+  }
+
+  before(async () => {
+    await initializePlatformConfigIfNeeded(signer.publicKey);
+    await fundWallet(buyer.publicKey);
+    await fundWallet(cranker.publicKey, 0.05 * LAMPORTS_PER_SOL);
+
+    pool = await launchTestToken(signer.publicKey, mint, poolFeeBps, null, [
+      { address: signer.publicKey, share: 5000, amount: new BN(0), lockingPeriod: new BN(0), kind: { wallet: {} } },
+      { address: Keypair.generate().publicKey, share: 5000, amount: new BN(0), lockingPeriod: new BN(0), kind: { buybackBurn: {} } },
+    ]);
+
+    const buyerTokenAccount = await createAssociatedTokenAccountIdempotent(
+      connection, signer.payer, mint.publicKey, buyer.publicKey, {}, TOKEN_2022_PROGRAM_ID
+    );
+    await program.methods
+      .buy(new BN(buyAmount))
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: mint.publicKey,
+        poolTokenAccount: pool.poolTokenAccount,
+        poolSolVault: pool.poolSolVault,
+        feeVault: pool.feeVault,
+        platformConfig,
+        referralAccount: null,
+        userTokenAccount: buyerTokenAccount,
+        user: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([buyer])
+      .rpc();
+  });
+
+  it("accrues the buyback share of the pool fee", async () => {
+    const poolFee = Math.floor((buyAmount * poolFeeBps) / 10000);
+    expect((await buybackRecipient()).amount.toNumber()).to.equal(Math.floor((poolFee * 5000) / 10000));
+  });
+
+  it("rejects a buyback below the slippage floor", async () => {
+    try {
+      await executeBuyback(Number.MAX_SAFE_INTEGER);
+      expect.fail("bought back under the slippage floor");
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal("SlippageExceeded");
+    }
+  });
+
+  it("buys the token from the curve with the buyback budget and burns it", async () => {
+    const budget = (await buybackRecipient()).amount.toNumber();
+    const curveBefore = await program.account.bondingCurve.fetch(pool.bondingCurve);
+    const supplyBefore = (await getMint(connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID)).supply;
+    const solVaultBefore = await connection.getBalance(pool.poolSolVault);
+
+    await executeBuyback(1);
+
+    const curve = await program.account.bondingCurve.fetch(pool.bondingCurve);
+    const burned = curve.totalBurned.sub(curveBefore.totalBurned).toNumber();
+    expect(burned).to.be.greaterThan(0);
+    expect(curve.buybackSolSpent.sub(curveBefore.buybackSolSpent).toNumber()).to.equal(budget);
+    expect(curve.reserveBalance.sub(curveBefore.reserveBalance).toNumber()).to.equal(budget);
+    expect((await connection.getBalance(pool.poolSolVault)) - solVaultBefore).to.equal(budget);
+
+    const supply = (await getMint(connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID)).supply;
+    expect(Number(supplyBefore - supply)).to.equal(burned);
+    expect((await buybackRecipient()).amount.toNumber()).to.equal(0);
+  });
+
+  it("rejects a buyback with an empty budget", async () => {
+    try {
+      await executeBuyback(1);
+      expect.fail("bought back without a budget");
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal("NoFeesToClaim");
+    }
+  });
+});
@@ -0,0 +1,252 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  createFairLaunchTestLaunch,
+  createWhitelistTestLaunch,
+  fundWallet,
+  getFairLaunchPDAs,
+  getPlatformConfigPDA,
+  getWhitelistLaunchPDAs,
+} from "./utils";
+
+describe("buyer vesting", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const buyer = Keypair.generate();
+  const totalTokens = 10_000_000;
+  const purchased = 1_000_000;                // 1 token at 1_000_000 lamports
+  // 20% at the end of the sale, the rest over 20s in two 10s steps
+  const tgeShare = 0.2;
+  const interval = 10;
+  const duration = 20;
+
+  let whitelist: Awaited<ReturnType<typeof createWhitelistTestLaunch>>;
+  let fair: Awaited<ReturnType<typeof createFairLaunchTestLaunch>>;
+  let whitelistBuyer: PublicKey;
+  let fairBuyer: PublicKey;
+  let whitelistTokenAccount: PublicKey;
+  let fairTokenAccount: PublicKey;
+  let endTime: number;
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+  const tokenBalance = async (account: PublicKey) => Number((await getAccount(connection, account)).amount);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function claimWhitelist() {
+    return program.methods
+      .claimWhitelistTokens()
+      .accountsStrict({
+        whitelistData: whitelist.whitelistData,
+        buyerAccount: whitelistBuyer,
+        tokenMint: whitelist.mint,
+        launchpadVault: whitelist.launchpadTokenAccount,
+        buyerTokenAccount: whitelistTokenAccount,
+        buyer: buyer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  function distribute() {
+    return program.methods
+      .distributeTokens()
+      .accountsStrict({
+        fairLaunchData: fair.fairLaunchData,
+        buyerAccount: fairBuyer,
+        tokenMint: fair.mint,
+        launchpadVault: fair.launchpadTokenAccount,
+        recipientTokenAccount: fairTokenAccount,
+        contributionVault: fair.contributionVault,
+        recipient: buyer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  before(async () => {
+    await fundWallet(buyer.publicKey);
+
+    const now = Math.floor(Date.now() / 1000);
+    const startTime = now + 10;
+    endTime = now + 16;
+    whitelist = await createWhitelistTestLaunch({
+      tokenPrice: 1_000_000,
+      purchaseLimit: totalTokens,
+      totalSupply: totalTokens,
+      startTime,
+      endTime,
+    });
+    fair = await createFairLaunchTestLaunch({
+      softCap: 0.05 * LAMPORTS_PER_SOL,
+      hardCap: LAMPORTS_PER_SOL,
+      startTime,
+      endTime,
+      minContribution: 0.01 * LAMPORTS_PER_SOL,
+      maxContribution: LAMPORTS_PER_SOL,
+      maxTokensPerWallet: totalTokens,
+      totalTokens,
+    });
+
+    const vesting = {
+      cliffPeriod: new BN(0),
+      startTime: new BN(endTime),
+      duration: new BN(duration),
+      interval: new BN(interval),
+      tgeBps: tgeShare * 10000,
+    };
+    await program.methods
+      .setWhitelistVesting(vesting)
+      .accountsStrict({ whitelistData: whitelist.whitelistData, authority: signer.publicKey })
+      .rpc();
+    await program.methods
+      .setFairLaunchVesting(vesting)
+      .accountsStrict({ fairLaunchData: fair.fairLaunchData, authority: signer.publicKey })
+      .rpc();
+
+    whitelistBuyer = getWhitelistLaunchPDAs(signer.publicKey, whitelist.mint, buyer.publicKey).buyerAccount;
+    fairBuyer = getFairLaunchPDAs(fair.mint, buyer.publicKey).buyerAccount;
+    whitelistTokenAccount = (await getOrCreateAssociatedTokenAccount(connection, signer.payer, whitelist.mint, buyer.publicKey)).address;
+    fairTokenAccount = (await getOrCreateAssociatedTokenAccount(connection, signer.payer, fair.mint, buyer.publicKey)).address;
+    await program.methods
+      .addWhitelist(buyer.publicKey)
+      .accountsStrict({
+        whitelistData: whitelist.whitelistData,
+        authority: signer.publicKey,
+        buyerAccount: whitelistBuyer,
+        user: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await sleepUntil(startTime + 1);
+    await program.methods
+      .purchaseWhitelist(new BN(purchased), null)
+      .accountsStrict({
+        whitelistData: whitelist.whitelistData,
+        buyerAccount: whitelistBuyer,
+        tokenMint: whitelist.mint,
+        whitelistVault: whitelist.whitelistVault,
+        buyer: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+    await program.methods
+      .contributeFairLaunch(new BN(0.1 * LAMPORTS_PER_SOL))
+      .accountsStrict({
+        fairLaunchData: fair.fairLaunchData,
+        buyerAccount: fairBuyer,
+        contributionVault: fair.contributionVault,
+        contributor: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  });
+
+  it("rejects a schedule once the sale started", async () => {
+    await expectError(
+      program.methods
+        .setWhitelistVesting(null)
+        .accountsStrict({ whitelistData: whitelist.whitelistData, authority: signer.publicKey })
+        .rpc(),
+      "SaleAlreadyStarted"
+    );
+  });
+
+  it("releases only the TGE share right after the sale", async () => {
+    await sleepUntil(endTime + 1);
+    const config = await program.account.platformConfig.fetch(getPlatformConfigPDA());
+    await program.methods
+      .finalizeFairLaunch()
+      .accountsStrict({
+        fairLaunchData: fair.fairLaunchData,
+        tokenMint: fair.mint,
+        launchpadVault: fair.launchpadTokenAccount,
+        contributionVault: fair.contributionVault,
+        platformConfig: getPlatformConfigPDA(),
+        treasury: config.treasury,
+        authority: signer.publicKey,
+        bondingCurveConfiguration: null,
+        bondingCurveAccount: null,
+        poolTokenAccount: null,
+        poolSolVault: null,
+        payer: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await claimWhitelist();
+    await distribute();
+    expect(await tokenBalance(whitelistTokenAccount)).to.equal(purchased * tgeShare);
+    expect(await tokenBalance(fairTokenAccount)).to.equal(totalTokens * tgeShare);
+
+    const account = await program.account.buyerAccount.fetch(whitelistBuyer);
+    expect(account.released.toNumber()).to.equal(purchased * tgeShare);
+    expect(account.claimed).to.equal(false);
+
+    // nothing more vests until the first interval
+    await expectError(claimWhitelist(), "NothingToClaim");
+    await expectError(distribute(), "NothingToClaim");
+  });
+
+  it("releases each interval step on top of what was already released", async () => {
+    await sleepUntil(endTime + interval + 1);
+    await claimWhitelist();
+    await distribute();
+
+    // 20% + 80% * 10 / 20
+    expect(await tokenBalance(whitelistTokenAccount)).to.equal(purchased * 0.6);
+    expect(await tokenBalance(fairTokenAccount)).to.equal(totalTokens * 0.6);
+    const account = await program.account.buyerAccount.fetch(fairBuyer);
+    expect(account.released.toNumber()).to.equal(totalTokens * 0.6);
+    expect(account.claimed).to.equal(false);
+  });
+
+  it("releases the rest once fully vested and then closes the claim", async () => {
+    await sleepUntil(endTime + duration + 1);
+    await claimWhitelist();
+    await distribute();
+
+    expect(await tokenBalance(whitelistTokenAccount)).to.equal(purchased);
+    expect(await tokenBalance(fairTokenAccount)).to.equal(totalTokens);
+    const whitelistAccount = await program.account.buyerAccount.fetch(whitelistBuyer);
+    expect(whitelistAccount.released.toNumber()).to.equal(purchased);
+    expect(whitelistAccount.claimed).to.equal(true);
+    const fairAccount = await program.account.buyerAccount.fetch(fairBuyer);
+    expect(fairAccount.released.toNumber()).to.equal(totalTokens);
+    expect(fairAccount.claimed).to.equal(true);
+
+    await expectError(claimWhitelist(), "AlreadyClaimed");
+    await expectError(distribute(), "AlreadyClaimed");
+  });
+});
@@ -0,0 +1,365 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import { createDutchAuctionTestLaunch, fundWallet, getDutchAuctionPDAs } from "./utils";
+
+describe("dutch auction", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const bidders = [Keypair.generate(), Keypair.generate()];
+  const unit = 1_000_000;                     // 6 decimals
+  const startPrice = 2_000_000;               // lamports per whole token
+  const floorPrice = 1_000_000;
+
+  type Auction = Awaited<ReturnType<typeof createDutchAuctionTestLaunch>>;
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+  const costOf = (amount: number, price: number) => Math.floor((amount * price) / unit);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  // Mirror of DutchAuctionData::price_at
+  function priceAt(
+    auction: { startTime: number, endTime: number, halfLife?: number },
+    now: number,
+  ) {
+    if (now <= auction.startTime) {
+      return startPrice;
+    }
+    const elapsed = Math.min(now, auction.endTime) - auction.startTime;
+    const span = startPrice - floorPrice;
+    if (auction.halfLife === undefined) {
+      return floorPrice + span - Math.floor((span * elapsed) / (auction.endTime - auction.startTime));
+    }
+    const halvings = Math.floor(elapsed / auction.halfLife);
+    if (halvings >= 64) {
+      return floorPrice;
+    }
+    const current = Math.floor(span / 2 ** halvings);
+    return floorPrice + current - Math.floor((Math.floor(current / 2) * (elapsed % auction.halfLife)) / auction.halfLife);
+  }
+
+  async function bid(auction: Auction, bidder: Keypair, amount: number, maxPrice = startPrice) {
+    const { buyerAccount } = getDutchAuctionPDAs(auction.mint, bidder.publicKey);
+    const signature = await program.methods
+      .bidDutchAuction(new BN(amount), new BN(maxPrice))
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        buyerAccount,
+        tokenMint: auction.mint,
+        contributionVault: auction.contributionVault,
+        bidder: bidder.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bidder])
+      .rpc({ commitment: "confirmed" });
+    const tx = await connection.getTransaction(signature, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+    return tx.blockTime;
+  }
+
+  function settle(auction: Auction) {
+    return program.methods
+      .settleDutchAuction()
+      .accountsStrict({ auctionData: auction.auctionData, payer: signer.publicKey })
+      .rpc();
+  }
+
+  async function claim(auction: Auction, bidder: Keypair) {
+    const { buyerAccount } = getDutchAuctionPDAs(auction.mint, bidder.publicKey);
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, auction.mint, bidder.publicKey);
+    await program.methods
+      .claimDutchAuction()
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        buyerAccount,
+        tokenMint: auction.mint,
+        launchpadVault: auction.launchpadTokenAccount,
+        bidderTokenAccount: tokenAccount.address,
+        contributionVault: auction.contributionVault,
+        bidder: bidder.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bidder])
+      .rpc();
+    return tokenAccount.address;
+  }
+
+  function withdrawProceeds(auction: Auction) {
+    return program.methods
+      .withdrawDutchAuctionProceeds()
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        contributionVault: auction.contributionVault,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  function cancel(auction: Auction, authority: Keypair = signer.payer, authorityTokenAccount: PublicKey = auction.authorityTokenAccount) {
+    return program.methods
+      .cancelLaunch()
+      .accountsStrict({
+        whitelistData: null,
+        fairLaunchData: null,
+        auctionData: auction.auctionData,
+        batchAuctionData: null,
+        tokenMint: auction.mint,
+        launchpadVault: auction.launchpadTokenAccount,
+        authorityTokenAccount,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  function refund(auction: Auction, bidder: Keypair) {
+    const { buyerAccount } = getDutchAuctionPDAs(auction.mint, bidder.publicKey);
+    return program.methods
+      .refundDutchAuctionBid()
+      .accountsStrict({
+        auctionData: auction.auctionData,
+        buyerAccount,
+        contributionVault: auction.contributionVault,
+        bidder: bidder.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bidder])
+      .rpc();
+  }
+
+  const committedOf = async (auction: Auction, bidder: Keypair) =>
+    (await program.account.buyerAccount.fetch(getDutchAuctionPDAs(auction.mint, bidder.publicKey).buyerAccount)).committed.toNumber();
+
+  before(async () => {
+    for (const bidder of bidders) {
+      await fundWallet(bidder.publicKey);
+    }
+  });
+
+  describe("linear decay selling out", () => {
+    const totalTokens = 3_000_000;
+    // odd token amounts so every cost rounds
+    const amounts = [1_333_333, 1_666_667];
+    let auction: Auction;
+    let window: { startTime: number, endTime: number };
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+      window = { startTime: now + 8, endTime: now + 40 };
+      auction = await createDutchAuctionTestLaunch({
+        ...window,
+        startPrice,
+        floorPrice,
+        decay: { linear: {} },
+        totalTokens,
+      });
+    });
+
+    it("funds the contribution vault rent at creation", async () => {
+      expect(await connection.getBalance(auction.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+    });
+
+    it("rejects a bid before the start", async () => {
+      await expectError(bid(auction, bidders[0], amounts[0]), "SaleNotStarted");
+    });
+
+    it("locks the SOL at the linearly decaying price", async () => {
+      await sleepUntil(window.startTime + 2);
+      const blockTime = await bid(auction, bidders[0], amounts[0]);
+
+      const committed = await committedOf(auction, bidders[0]);
+      expect(committed).to.be.at.most(costOf(amounts[0], priceAt(window, blockTime - 1)));
+      expect(committed).to.be.at.least(costOf(amounts[0], priceAt(window, blockTime + 1)));
+      expect(committed).to.be.greaterThan(costOf(amounts[0], floorPrice));
+    });
+
+    it("rejects a bid above the max price", async () => {
+      await expectError(bid(auction, bidders[1], amounts[1], floorPrice), "SlippageExceeded");
+    });
+
+    it("settles at the price of the bid that sells out the supply", async () => {
+      await sleepUntil(window.startTime + 6);
+      const blockTime = await bid(auction, bidders[1], amounts[1]);
+
+      const data = await program.account.dutchAuctionData.fetch(auction.auctionData);
+      expect(data.settled).to.equal(true);
+      expect(data.tokensBid.toNumber()).to.equal(totalTokens);
+      const clearingPrice = data.clearingPrice.toNumber();
+      expect(clearingPrice).to.be.at.most(priceAt(window, blockTime - 1));
+      expect(clearingPrice).to.be.at.least(priceAt(window, blockTime + 1));
+      expect(await committedOf(auction, bidders[1])).to.equal(costOf(amounts[1], clearingPrice));
+
+      await expectError(settle(auction), "AuctionSettled");
+      await expectError(cancel(auction), "AuctionSettled");
+    });
+
+    it("pays the tokens and refunds everything above the clearing price", async () => {
+      const data = await program.account.dutchAuctionData.fetch(auction.auctionData);
+      const clearingPrice = data.clearingPrice.toNumber();
+      await expectError(withdrawProceeds(auction), "NothingToClaim");
+
+      let refunded = 0;
+      for (const [i, bidder] of bidders.entries()) {
+        const committed = await committedOf(auction, bidder);
+        const balanceBefore = await connection.getBalance(bidder.publicKey);
+        const tokenAccount = await claim(auction, bidder);
+
+        const refund = committed - costOf(amounts[i], clearingPrice);
+        expect((await connection.getBalance(bidder.publicKey)) - balanceBefore).to.equal(refund);
+        expect(Number((await getAccount(connection, tokenAccount)).amount)).to.equal(amounts[i]);
+        refunded += refund;
+      }
+      expect(refunded).to.be.greaterThan(0);
+      await expectError(claim(auction, bidders[0]), "AlreadyClaimed");
+
+      // the proceeds and the refunds add up to exactly the SOL the bids locked
+      const authorityBefore = await connection.getBalance(signer.publicKey);
+      await withdrawProceeds(auction);
+      const after = await program.account.dutchAuctionData.fetch(auction.auctionData);
+      expect(after.proceedsWithdrawn.toNumber()).to.equal(after.proceedsAccrued.toNumber());
+      expect(after.proceedsAccrued.toNumber() + refunded).to.equal(data.totalCommitted.toNumber());
+      expect((await connection.getBalance(signer.publicKey)) - authorityBefore).to.be.greaterThan(after.proceedsAccrued.toNumber() - 10_000);
+      expect(await connection.getBalance(auction.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+      await expectError(withdrawProceeds(auction), "NothingToClaim");
+    });
+  });
+
+  describe("exponential decay ending undersold", () => {
+    const totalTokens = 3_000_000;
+    const amount = 1_000_000;
+    const halfLife = 4;
+    let auction: Auction;
+    let window: { startTime: number, endTime: number, halfLife: number };
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+      window = { startTime: now + 8, endTime: now + 18, halfLife };
+      auction = await createDutchAuctionTestLaunch({
+        startTime: window.startTime,
+        endTime: window.endTime,
+        startPrice,
+        floorPrice,
+        decay: { exponential: { halfLife: new BN(halfLife) } },
+        totalTokens,
+      });
+    });
+
+    it("locks the SOL at the exponentially decaying price", async () => {
+      await sleepUntil(window.startTime + 2);
+      const blockTime = await bid(auction, bidders[0], amount);
+
+      const committed = await committedOf(auction, bidders[0]);
+      expect(committed).to.be.at.most(costOf(amount, priceAt(window, blockTime - 1)));
+      expect(committed).to.be.at.least(costOf(amount, priceAt(window, blockTime + 1)));
+    });
+
+    it("settles at the price reached at the end time", async () => {
+      await expectError(settle(auction), "SaleNotEnded");
+      await expectError(claim(auction, bidders[0]), "AuctionNotSettled");
+      await sleepUntil(window.endTime + 2);
+      await settle(auction);
+
+      const data = await program.account.dutchAuctionData.fetch(auction.auctionData);
+      // two full halvings and half of the third: 1_000_000 + 250_000 - 62_500
+      expect(data.clearingPrice.toNumber()).to.equal(priceAt(window, window.endTime));
+      expect(data.clearingPrice.toNumber()).to.equal(1_187_500);
+    });
+
+    it("refunds the bidder and returns the unsold tokens", async () => {
+      const committed = await committedOf(auction, bidders[0]);
+      const balanceBefore = await connection.getBalance(bidders[0].publicKey);
+      await claim(auction, bidders[0]);
+      expect((await connection.getBalance(bidders[0].publicKey)) - balanceBefore).to.equal(committed - costOf(amount, 1_187_500));
+
+      const authorityBefore = Number((await getAccount(connection, auction.authorityTokenAccount)).amount);
+      await program.methods
+        .withdrawUnsoldDutchAuctionTokens()
+        .accountsStrict({
+          auctionData: auction.auctionData,
+          tokenMint: auction.mint,
+          launchpadVault: auction.launchpadTokenAccount,
+          authorityTokenAccount: auction.authorityTokenAccount,
+          authority: signer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      expect(Number((await getAccount(connection, auction.authorityTokenAccount)).amount) - authorityBefore).to.equal(totalTokens - amount);
+
+      await withdrawProceeds(auction);
+      expect(await connection.getBalance(auction.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+    });
+  });
+
+  describe("cancelled", () => {
+    const totalTokens = 3_000_000;
+    const amount = 500_000;
+    let auction: Auction;
+    let endTime: number;
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+      endTime = now + 20;
+      auction = await createDutchAuctionTestLaunch({
+        startTime: now + 8,
+        endTime,
+        startPrice,
+        floorPrice,
+        decay: { linear: {} },
+        totalTokens,
+      });
+      await sleepUntil(now + 10);
+      await bid(auction, bidders[0], amount);
+    });
+
+    it("only lets the authority cancel and returns the sale tokens", async () => {
+      const attackerTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, auction.mint, bidders[1].publicKey);
+      await expectError(refund(auction, bidders[0]), "LaunchNotCancelled");
+      await expectError(cancel(auction, bidders[1], attackerTokenAccount.address), "InvalidAuthority");
+
+      const authorityBefore = Number((await getAccount(connection, auction.authorityTokenAccount)).amount);
+      await cancel(auction);
+      expect(Number((await getAccount(connection, auction.authorityTokenAccount)).amount) - authorityBefore).to.equal(totalTokens);
+
+      await expectError(bid(auction, bidders[1], amount), "LaunchCancelled");
+      await expectError(cancel(auction), "LaunchCancelled");
+    });
+
+    it("refunds every locked lamport and never settles", async () => {
+      const committed = await committedOf(auction, bidders[0]);
+      const balanceBefore = await connection.getBalance(bidders[0].publicKey);
+      await refund(auction, bidders[0]);
+      expect((await connection.getBalance(bidders[0].publicKey)) - balanceBefore).to.equal(committed);
+      await expectError(refund(auction, bidders[0]), "NothingToClaim");
+      expect(await connection.getBalance(auction.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+
+      await sleepUntil(endTime + 2);
+      await expectError(settle(auction), "LaunchCancelled");
+    });
+  });
+});
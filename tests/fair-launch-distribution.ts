@@ -0,0 +1,179 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  createFairLaunchTestLaunch,
+  fundWallet,
+  getFairLaunchPDAs,
+  getPlatformConfigPDA,
+  initializePlatformConfigIfNeeded,
+} from "./utils";
+
+describe("fair launch distribution", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const platformConfig = getPlatformConfigPDA();
+  const whale = Keypair.generate();
+  const minnow = Keypair.generate();
+  const totalTokens = 10_000_000;
+  const maxTokensPerWallet = 7_000_000;
+  const contributions = new Map<Keypair, number>([
+    [minnow, 0.1 * LAMPORTS_PER_SOL],
+    [whale, 0.2 * LAMPORTS_PER_SOL],
+  ]);
+  const finalRaised = 0.3 * LAMPORTS_PER_SOL;
+
+  let launch: Awaited<ReturnType<typeof createFairLaunchTestLaunch>>;
+  let endTime: number;
+  let treasury: PublicKey;
+  let launchFeeBps: number;
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function contribute(contributor: Keypair, amount: number) {
+    const { buyerAccount } = getFairLaunchPDAs(launch.mint, contributor.publicKey);
+    return program.methods
+      .contributeFairLaunch(new BN(amount))
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        buyerAccount,
+        contributionVault: launch.contributionVault,
+        contributor: contributor.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+  }
+
+  async function distribute(recipient: Keypair) {
+    const { buyerAccount } = getFairLaunchPDAs(launch.mint, recipient.publicKey);
+    const recipientTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, launch.mint, recipient.publicKey);
+    await program.methods
+      .distributeTokens()
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        buyerAccount,
+        tokenMint: launch.mint,
+        launchpadVault: launch.launchpadTokenAccount,
+        recipientTokenAccount: recipientTokenAccount.address,
+        contributionVault: launch.contributionVault,
+        recipient: recipient.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([recipient])
+      .rpc();
+    return Number((await getAccount(connection, recipientTokenAccount.address)).amount);
+  }
+
+  before(async () => {
+    const config = await initializePlatformConfigIfNeeded(signer.publicKey);
+    treasury = config.treasury;
+    await fundWallet(whale.publicKey);
+    await fundWallet(minnow.publicKey);
+
+    const now = Math.floor(Date.now() / 1000);
+    endTime = now + 16;
+    launch = await createFairLaunchTestLaunch({
+      softCap: 0.2 * LAMPORTS_PER_SOL,
+      hardCap: LAMPORTS_PER_SOL,
+      startTime: now + 8,
+      endTime,
+      minContribution: 0.01 * LAMPORTS_PER_SOL,
+      maxContribution: 0.5 * LAMPORTS_PER_SOL,
+      maxTokensPerWallet,
+      totalTokens,
+    });
+    launchFeeBps = (await program.account.fairLaunchData.fetch(launch.fairLaunchData)).launchFeeBps;
+  });
+
+  it("caps contributions at the most tokens a wallet can end up with", async () => {
+    await sleepUntil(endTime - 8 + 1);
+    await contribute(minnow, contributions.get(minnow));
+
+    // 0.3 SOL out of 0.4 SOL raised would be 7.5M tokens
+    await expectError(contribute(whale, 0.3 * LAMPORTS_PER_SOL), "MaxTokensPerWalletExceeded");
+    await contribute(whale, contributions.get(whale));
+  });
+
+  it("snapshots the token pool and the raise on finalization", async () => {
+    await sleepUntil(endTime + 2);
+    await expectError(distribute(whale), "NotFinalized");
+
+    const authorityBefore = await connection.getBalance(signer.publicKey);
+    const treasuryBefore = await connection.getBalance(treasury);
+    await program.methods
+      .finalizeFairLaunch()
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        tokenMint: launch.mint,
+        launchpadVault: launch.launchpadTokenAccount,
+        contributionVault: launch.contributionVault,
+        platformConfig,
+        treasury,
+        authority: signer.publicKey,
+        bondingCurveConfiguration: null,
+        bondingCurveAccount: null,
+        poolTokenAccount: null,
+        poolSolVault: null,
+        payer: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const data = await program.account.fairLaunchData.fetch(launch.fairLaunchData);
+    expect(data.finalized).to.equal(true);
+    expect(data.tokenPool.toNumber()).to.equal(totalTokens);
+    expect(data.finalRaised.toNumber()).to.equal(finalRaised);
+    // only the rent the vault keeps for good is left
+    expect(await connection.getBalance(launch.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+
+    // the provider wallet pays the fee, so only check it got the proceeds when it isn't the treasury
+    const platformFee = Math.floor((finalRaised * launchFeeBps) / 10000);
+    if (!treasury.equals(signer.publicKey)) {
+      expect((await connection.getBalance(treasury)) - treasuryBefore).to.equal(platformFee);
+      expect((await connection.getBalance(signer.publicKey)) - authorityBefore).to.be.greaterThan(finalRaised - platformFee - 10_000);
+    }
+  });
+
+  it("pays every contributor against the snapshot whatever the claim order", async () => {
+    for (const contributor of [whale, minnow]) {
+      const share = Math.floor((contributions.get(contributor) * totalTokens) / finalRaised);
+      expect(await distribute(contributor)).to.equal(share);
+
+      const { buyerAccount } = getFairLaunchPDAs(launch.mint, contributor.publicKey);
+      const account = await program.account.buyerAccount.fetch(buyerAccount);
+      expect(account.claimed).to.equal(true);
+      expect(account.amount.toNumber()).to.equal(contributions.get(contributor));
+    }
+    expect(Number((await getAccount(connection, launch.launchpadTokenAccount)).amount)).to.be.lessThan(2);
+
+    await expectError(distribute(whale), "AlreadyClaimed");
+  });
+});
@@ -0,0 +1,231 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { ASSOCIATED_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  createFairLaunchTestLaunch,
+  fundWallet,
+  getFairLaunchPDAs,
+  getPDAs,
+  getPlatformConfigPDA,
+  initializePlatformConfigIfNeeded,
+} from "./utils";
+
+describe("fair launch liquidity", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const platformConfig = getPlatformConfigPDA();
+  const contributor = Keypair.generate();
+  const totalTokens = 10_000_000;
+  const liquidityTokens = 5_000_000;
+  const liquidityBps = 5000;
+  const contribution = 0.3 * LAMPORTS_PER_SOL;
+  const initialSupply = 100_000_000_000;
+
+  let launch: Awaited<ReturnType<typeof createFairLaunchTestLaunch>>;
+  let pool: ReturnType<typeof getPDAs>;
+  let endTime: number;
+  let treasury: PublicKey;
+  let isAdmin: boolean;
+  let previousLaunchFeeBps: number;
+  let snapshotFeeBps: number;
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function setLaunchFee(bps: number) {
+    return program.methods
+      .setLaunchFee(bps)
+      .accountsStrict({ platformConfig, admin: signer.publicKey })
+      .rpc();
+  }
+
+  function setLiquidity(bps: number, tokens: number, withCurve = true) {
+    return program.methods
+      .setFairLaunchLiquidity(bps, new BN(tokens), null)
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        bondingCurveConfiguration: withCurve ? pool.curveConfig : null,
+        authority: signer.publicKey,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    const config = await initializePlatformConfigIfNeeded(signer.publicKey);
+    treasury = config.treasury;
+    isAdmin = config.admin.equals(signer.publicKey);
+    previousLaunchFeeBps = config.launchFeeBps;
+    if (isAdmin) {
+      await setLaunchFee(500);
+    }
+    await fundWallet(contributor.publicKey);
+
+    const now = Math.floor(Date.now() / 1000);
+    endTime = now + 30;
+    launch = await createFairLaunchTestLaunch({
+      softCap: 0.2 * LAMPORTS_PER_SOL,
+      hardCap: LAMPORTS_PER_SOL,
+      startTime: now + 20,
+      endTime,
+      minContribution: 0.01 * LAMPORTS_PER_SOL,
+      maxContribution: 0.5 * LAMPORTS_PER_SOL,
+      maxTokensPerWallet: totalTokens,
+      totalTokens,
+    });
+    snapshotFeeBps = (await program.account.fairLaunchData.fetch(launch.fairLaunchData)).launchFeeBps;
+    pool = getPDAs(signer.publicKey, launch.mint);
+  });
+
+  after(async () => {
+    if (isAdmin) {
+      await setLaunchFee(previousLaunchFeeBps);
+    }
+  });
+
+  it("snapshots the platform launch fee on creation", async () => {
+    expect(snapshotFeeBps).to.equal(isAdmin ? 500 : previousLaunchFeeBps);
+  });
+
+  it("requires the bonding curve before a liquidity share is set", async () => {
+    await expectError(setLiquidity(liquidityBps, liquidityTokens, false), "MissingLiquidityAccounts");
+
+    // the authority creates its curve with create_pool first
+    await mintTo(connection, signer.payer, launch.mint, launch.authorityTokenAccount, signer.payer, initialSupply);
+    await program.methods
+      .createPool(
+        signer.publicKey,
+        100,                            // fee percentage
+        new BN(500),                    // initial quorum
+        new BN(1_000_000_000_000),      // target liquidity
+        signer.publicKey,               // governance
+        500,                            // dao quorum
+        0,                              // linear curve
+        new BN(10_000_000_000),         // max token supply
+        new BN(60),                     // liquidity lock period
+        50,                             // liquidity pool percentage
+        new BN(100),                    // initial price
+        new BN(initialSupply),
+        [{ address: signer.publicKey, share: 10000, amount: new BN(0), lockingPeriod: new BN(0), kind: { wallet: {} } }],
+        5000,                           // reserve ratio
+        null,
+      )
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: launch.mint,
+        poolTokenAccount: pool.poolTokenAccount,
+        poolSolVault: pool.poolSolVault,
+        feeVault: pool.feeVault,
+        userTokenAccount: launch.authorityTokenAccount,
+        platformConfig,
+        admin: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it("rejects a liquidity share leaving no room for the launch fee", async () => {
+    if (snapshotFeeBps > 0) {
+      await expectError(setLiquidity(10000 - snapshotFeeBps + 1, liquidityTokens), "InvalidFee");
+    }
+    await setLiquidity(liquidityBps, liquidityTokens);
+
+    // the liquidity tokens are deposited on top of the tokens for sale
+    await program.methods
+      .fundFairLaunch(new BN(liquidityTokens))
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        tokenMint: launch.mint,
+        launchpadVault: launch.launchpadTokenAccount,
+        authorityTokenAccount: launch.authorityTokenAccount,
+        authority: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it("seeds the curve and pays the snapshotted fee even after the platform fee changed", async () => {
+    if (isAdmin) {
+      await setLaunchFee(10000);
+    }
+
+    const { buyerAccount } = getFairLaunchPDAs(launch.mint, contributor.publicKey);
+    await sleepUntil(endTime - 10 + 1);
+    await program.methods
+      .contributeFairLaunch(new BN(contribution))
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        buyerAccount,
+        contributionVault: launch.contributionVault,
+        contributor: contributor.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+
+    await sleepUntil(endTime + 2);
+    const curveBefore = await program.account.bondingCurve.fetch(pool.bondingCurve);
+    const solVaultBefore = await connection.getBalance(pool.poolSolVault);
+    const treasuryBefore = await connection.getBalance(treasury);
+
+    await program.methods
+      .finalizeFairLaunch()
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        tokenMint: launch.mint,
+        launchpadVault: launch.launchpadTokenAccount,
+        contributionVault: launch.contributionVault,
+        platformConfig,
+        treasury,
+        authority: signer.publicKey,
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        poolTokenAccount: pool.poolTokenAccount,
+        poolSolVault: pool.poolSolVault,
+        payer: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const liquiditySol = Math.floor((contribution * liquidityBps) / 10000);
+    const curve = await program.account.bondingCurve.fetch(pool.bondingCurve);
+    expect(curve.reserveBalance.sub(curveBefore.reserveBalance).toNumber()).to.equal(liquiditySol);
+    expect(curve.reserveToken.sub(curveBefore.reserveToken).toNumber()).to.equal(liquidityTokens);
+    expect((await connection.getBalance(pool.poolSolVault)) - solVaultBefore).to.equal(liquiditySol);
+    // only the rent the vault keeps for good is left
+    expect(await connection.getBalance(launch.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+
+    if (!treasury.equals(signer.publicKey)) {
+      const platformFee = Math.floor((contribution * snapshotFeeBps) / 10000);
+      expect((await connection.getBalance(treasury)) - treasuryBefore).to.equal(platformFee);
+    }
+  });
+});
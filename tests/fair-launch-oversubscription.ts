@@ -0,0 +1,314 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  createFairLaunchTestLaunch,
+  fundWallet,
+  getFairLaunchPDAs,
+  getPlatformConfigPDA,
+  initializePlatformConfigIfNeeded,
+} from "./utils";
+
+describe("oversubscribed fair launch", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const totalTokens = 10_000_000;
+  const hardCap = 0.5 * LAMPORTS_PER_SOL;
+  const contributors = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  // 0.7 SOL raised against a 0.5 SOL cap, each refund is 2/7 of the contribution and rounds down
+  const amounts = [0.3 * LAMPORTS_PER_SOL, 0.3 * LAMPORTS_PER_SOL, 0.1 * LAMPORTS_PER_SOL];
+  const finalRaised = amounts.reduce((total, amount) => total + amount, 0);
+
+  let launch: Awaited<ReturnType<typeof createFairLaunchTestLaunch>>;
+  let endTime: number;
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function contribute(contributor: Keypair, amount: number) {
+    const { buyerAccount } = getFairLaunchPDAs(launch.mint, contributor.publicKey);
+    return program.methods
+      .contributeFairLaunch(new BN(amount))
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        buyerAccount,
+        contributionVault: launch.contributionVault,
+        contributor: contributor.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+  }
+
+  before(async () => {
+    await initializePlatformConfigIfNeeded(signer.publicKey);
+    for (const contributor of contributors) {
+      await fundWallet(contributor.publicKey);
+    }
+
+    const now = Math.floor(Date.now() / 1000);
+    endTime = now + 16;
+    launch = await createFairLaunchTestLaunch({
+      softCap: 0.2 * LAMPORTS_PER_SOL,
+      hardCap,
+      startTime: now + 8,
+      endTime,
+      minContribution: 0.01 * LAMPORTS_PER_SOL,
+      maxContribution: 0.4 * LAMPORTS_PER_SOL,
+      maxTokensPerWallet: totalTokens,
+      totalTokens,
+    });
+  });
+
+  it("funds the contribution vault rent at creation", async () => {
+    expect(await connection.getBalance(launch.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+  });
+
+  it("accepts contributions above the hard cap in overflow mode", async () => {
+    await program.methods
+      .setFairLaunchOversubscription(true)
+      .accountsStrict({ fairLaunchData: launch.fairLaunchData, authority: signer.publicKey })
+      .rpc();
+
+    await sleepUntil(endTime - 8 + 1);
+    for (const [i, contributor] of contributors.entries()) {
+      await contribute(contributor, amounts[i]);
+    }
+    const data = await program.account.fairLaunchData.fetch(launch.fairLaunchData);
+    expect(data.totalRaised.toNumber()).to.equal(finalRaised);
+  });
+
+  it("keeps the hard cap on finalization and holds the excess for the refunds", async () => {
+    await sleepUntil(endTime + 2);
+    // the excess is refunded by the claims, a raise above the soft cap is never refunded in full
+    await expectError(
+      program.methods
+        .refundContribution()
+        .accountsStrict({
+          fairLaunchData: launch.fairLaunchData,
+          buyerAccount: getFairLaunchPDAs(launch.mint, contributors[0].publicKey).buyerAccount,
+          contributionVault: launch.contributionVault,
+          contributor: contributors[0].publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([contributors[0]])
+        .rpc(),
+      "SoftCapReached"
+    );
+    const config = await program.account.platformConfig.fetch(getPlatformConfigPDA());
+    await program.methods
+      .finalizeFairLaunch()
+      .accountsStrict({
+        fairLaunchData: launch.fairLaunchData,
+        tokenMint: launch.mint,
+        launchpadVault: launch.launchpadTokenAccount,
+        contributionVault: launch.contributionVault,
+        platformConfig: getPlatformConfigPDA(),
+        treasury: config.treasury,
+        authority: signer.publicKey,
+        bondingCurveConfiguration: null,
+        bondingCurveAccount: null,
+        poolTokenAccount: null,
+        poolSolVault: null,
+        payer: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const data = await program.account.fairLaunchData.fetch(launch.fairLaunchData);
+    expect(data.acceptedRaised.toNumber()).to.equal(hardCap);
+    const rent = await connection.getMinimumBalanceForRentExemption(0);
+    expect(await connection.getBalance(launch.contributionVault)).to.equal(rent + finalRaised - hardCap);
+  });
+
+  it("pays every contributor its tokens and a rounded down refund, the last one included", async () => {
+    let refunded = 0;
+    for (const [i, contributor] of contributors.entries()) {
+      const { buyerAccount } = getFairLaunchPDAs(launch.mint, contributor.publicKey);
+      const tokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, launch.mint, contributor.publicKey);
+      const balanceBefore = await connection.getBalance(contributor.publicKey);
+
+      await program.methods
+        .distributeTokens()
+        .accountsStrict({
+          fairLaunchData: launch.fairLaunchData,
+          buyerAccount,
+          tokenMint: launch.mint,
+          launchpadVault: launch.launchpadTokenAccount,
+          recipientTokenAccount: tokenAccount.address,
+          contributionVault: launch.contributionVault,
+          recipient: contributor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([contributor])
+        .rpc();
+
+      const refund = Math.floor((amounts[i] * (finalRaised - hardCap)) / finalRaised);
+      expect((await connection.getBalance(contributor.publicKey)) - balanceBefore).to.equal(refund);
+      expect(Number((await getAccount(connection, tokenAccount.address)).amount))
+        .to.equal(Math.floor((amounts[i] * totalTokens) / finalRaised));
+      refunded += refund;
+    }
+
+    // the rounding dust stays on top of the rent the vault keeps
+    const rent = await connection.getMinimumBalanceForRentExemption(0);
+    const dust = finalRaised - hardCap - refunded;
+    expect(dust).to.be.greaterThan(0);
+    expect(await connection.getBalance(launch.contributionVault)).to.equal(rent + dust);
+  });
+
+  it("refunds the excess on the first claim while the tokens are still under a cliff", async () => {
+    const contributor = contributors[0];
+    const now = Math.floor(Date.now() / 1000);
+    const vestingEnd = now + 16;
+    const vested = await createFairLaunchTestLaunch({
+      softCap: 0.05 * LAMPORTS_PER_SOL,
+      hardCap: 0.1 * LAMPORTS_PER_SOL,
+      startTime: now + 8,
+      endTime: vestingEnd,
+      minContribution: 0.01 * LAMPORTS_PER_SOL,
+      maxContribution: 0.4 * LAMPORTS_PER_SOL,
+      maxTokensPerWallet: totalTokens,
+      totalTokens,
+    });
+    await program.methods
+      .setFairLaunchOversubscription(true)
+      .accountsStrict({ fairLaunchData: vested.fairLaunchData, authority: signer.publicKey })
+      .rpc();
+    // nothing unlocks for a day after the sale
+    await program.methods
+      .setFairLaunchVesting({
+        cliffPeriod: new BN(86_400),
+        startTime: new BN(vestingEnd),
+        duration: new BN(86_400),
+        interval: new BN(0),
+        tgeBps: 0,
+      })
+      .accountsStrict({ fairLaunchData: vested.fairLaunchData, authority: signer.publicKey })
+      .rpc();
+
+    const { buyerAccount } = getFairLaunchPDAs(vested.mint, contributor.publicKey);
+    await sleepUntil(now + 9);
+    await program.methods
+      .contributeFairLaunch(new BN(0.15 * LAMPORTS_PER_SOL))
+      .accountsStrict({
+        fairLaunchData: vested.fairLaunchData,
+        buyerAccount,
+        contributionVault: vested.contributionVault,
+        contributor: contributor.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+
+    await sleepUntil(vestingEnd + 2);
+    const config = await program.account.platformConfig.fetch(getPlatformConfigPDA());
+    await program.methods
+      .finalizeFairLaunch()
+      .accountsStrict({
+        fairLaunchData: vested.fairLaunchData,
+        tokenMint: vested.mint,
+        launchpadVault: vested.launchpadTokenAccount,
+        contributionVault: vested.contributionVault,
+        platformConfig: getPlatformConfigPDA(),
+        treasury: config.treasury,
+        authority: signer.publicKey,
+        bondingCurveConfiguration: null,
+        bondingCurveAccount: null,
+        poolTokenAccount: null,
+        poolSolVault: null,
+        payer: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, vested.mint, contributor.publicKey);
+    const distribute = () => program.methods
+      .distributeTokens()
+      .accountsStrict({
+        fairLaunchData: vested.fairLaunchData,
+        buyerAccount,
+        tokenMint: vested.mint,
+        launchpadVault: vested.launchpadTokenAccount,
+        recipientTokenAccount: tokenAccount.address,
+        contributionVault: vested.contributionVault,
+        recipient: contributor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+
+    // the whole contribution above the 0.1 SOL cap comes back, no token is released yet
+    const balanceBefore = await connection.getBalance(contributor.publicKey);
+    await distribute();
+    expect((await connection.getBalance(contributor.publicKey)) - balanceBefore).to.equal(0.05 * LAMPORTS_PER_SOL);
+    expect(Number((await getAccount(connection, tokenAccount.address)).amount)).to.equal(0);
+
+    const account = await program.account.buyerAccount.fetch(buyerAccount);
+    expect(account.refunded).to.equal(true);
+    expect(account.released.toNumber()).to.equal(0);
+    expect(account.claimed).to.equal(false);
+
+    // the refund is paid once, the tokens wait for the cliff
+    await expectError(distribute(), "NothingToClaim");
+  });
+
+  it("rejects contributions above the hard cap without overflow mode", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const capped = await createFairLaunchTestLaunch({
+      softCap: 0.1 * LAMPORTS_PER_SOL,
+      hardCap: 0.2 * LAMPORTS_PER_SOL,
+      startTime: now + 8,
+      endTime: now + 60,
+      minContribution: 0.01 * LAMPORTS_PER_SOL,
+      maxContribution: 0.3 * LAMPORTS_PER_SOL,
+      maxTokensPerWallet: totalTokens,
+      totalTokens,
+    });
+    await sleepUntil(now + 9);
+
+    const { buyerAccount } = getFairLaunchPDAs(capped.mint, contributors[0].publicKey);
+    await expectError(
+      program.methods
+        .contributeFairLaunch(new BN(0.25 * LAMPORTS_PER_SOL))
+        .accountsStrict({
+          fairLaunchData: capped.fairLaunchData,
+          buyerAccount,
+          contributionVault: capped.contributionVault,
+          contributor: contributors[0].publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([contributors[0]])
+        .rpc(),
+      "HardCapReached"
+    );
+  });
+});
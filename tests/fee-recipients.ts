@@ -0,0 +1,175 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { expect } from "chai";
+import { buyTestToken, fundWallet, initializePlatformConfigIfNeeded, launchTestToken } from "./utils";
+
+describe("fee recipients", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const mint = Keypair.generate();
+  const buyer = Keypair.generate();
+  const secondRecipient = Keypair.generate();
+  const poolFeeBps = 200;
+  const buyAmount = 0.5 * LAMPORTS_PER_SOL;
+
+  let pool: Awaited<ReturnType<typeof launchTestToken>>;
+
+  const recipient = (address: PublicKey, share: number) =>
+    ({ address, share, amount: new BN(0), lockingPeriod: new BN(0), kind: { wallet: {} } });
+
+  const fetchConfig = () => program.account.curveConfiguration.fetch(pool.curveConfig);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function addFeeRecipients(recipients: object[]) {
+    return program.methods
+      .addFeeRecipients(recipients as any)
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        feeAdmin: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  function updateRecipientShare(shares: { address: PublicKey, share: number }[]) {
+    return program.methods
+      .updateRecipientShare(shares)
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        feeAdmin: signer.publicKey,
+      })
+      .rpc();
+  }
+
+  function removeFeeRecipient(address: PublicKey) {
+    return program.methods
+      .removeFeeRecipient(address)
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        feeAdmin: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    await initializePlatformConfigIfNeeded(signer.publicKey);
+    await fundWallet(buyer.publicKey, 2 * LAMPORTS_PER_SOL);
+    await fundWallet(secondRecipient.publicKey, 0.05 * LAMPORTS_PER_SOL);
+
+    pool = await launchTestToken(signer.publicKey, mint, poolFeeBps, null, [
+      recipient(signer.publicKey, 5000),
+      recipient(secondRecipient.publicKey, 5000),
+    ]);
+    await buyTestToken(pool, mint.publicKey, buyer, buyAmount);
+  });
+
+  it("splits the pool fee by share", async () => {
+    const share = Math.floor((Math.floor((buyAmount * poolFeeBps) / 10000) * 5000) / 10000);
+    const config = await fetchConfig();
+    expect(config.feeRecipients.map((r) => r.amount.toNumber())).to.deep.equal([share, share]);
+  });
+
+  it("updates shares as long as they add up to 100%", async () => {
+    await expectError(
+      updateRecipientShare([{ address: signer.publicKey, share: 6000 }]),
+      "InvalidSharePercentage"
+    );
+    // 60000 + 15536 wraps around to 10000 in a u16 sum
+    await expectError(
+      updateRecipientShare([
+        { address: signer.publicKey, share: 60000 },
+        { address: secondRecipient.publicKey, share: 15536 },
+      ]),
+      "InvalidSharePercentage"
+    );
+
+    await updateRecipientShare([
+      { address: signer.publicKey, share: 7000 },
+      { address: secondRecipient.publicKey, share: 3000 },
+    ]);
+    const config = await fetchConfig();
+    expect(config.feeRecipients.map((r) => r.share)).to.deep.equal([7000, 3000]);
+  });
+
+  it("caps the number of fee recipients", async () => {
+    const recipients = Array.from({ length: 11 }, (_, i) =>
+      recipient(Keypair.generate().publicKey, i == 0 ? 10000 - 10 * 900 : 900)
+    );
+    await expectError(addFeeRecipients(recipients), "TooManyFeeRecipients");
+  });
+
+  it("grows the configuration account past the initial recipient slots and keeps balances", async () => {
+    const before = await fetchConfig();
+    const sizeBefore = (await connection.getAccountInfo(pool.curveConfig)).data.length;
+
+    const extra = Array.from({ length: 6 }, () => recipient(Keypair.generate().publicKey, 1000));
+    await addFeeRecipients([
+      recipient(signer.publicKey, 2000),
+      recipient(secondRecipient.publicKey, 2000),
+      ...extra,
+    ]);
+
+    const config = await fetchConfig();
+    expect(config.feeRecipients.length).to.equal(8);
+    expect((await connection.getAccountInfo(pool.curveConfig)).data.length).to.be.greaterThan(sizeBefore);
+    // recipients kept in the new list keep what they accrued
+    expect(config.feeRecipients[0].amount.toNumber()).to.equal(before.feeRecipients[0].amount.toNumber());
+    expect(config.feeRecipients[1].amount.toNumber()).to.equal(before.feeRecipients[1].amount.toNumber());
+  });
+
+  it("spreads a removed share over the others and keeps the removed balance claimable", async () => {
+    const before = await fetchConfig();
+    const owed = before.feeRecipients[1].amount.toNumber();
+
+    await removeFeeRecipient(secondRecipient.publicKey);
+
+    const config = await fetchConfig();
+    expect(config.feeRecipients.length).to.equal(7);
+    expect(config.feeRecipients.reduce((total, r) => total + r.share, 0)).to.equal(10000);
+    expect(config.removedFeeRecipients.map((r) => r.address.toBase58())).to.include(secondRecipient.publicKey.toBase58());
+
+    const feeVaultBefore = await connection.getBalance(pool.feeVault);
+    await program.methods
+      .claimFees()
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: mint.publicKey,
+        feeVault: pool.feeVault,
+        recipient: secondRecipient.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([secondRecipient])
+      .rpc();
+    expect(feeVaultBefore - (await connection.getBalance(pool.feeVault))).to.equal(owed);
+
+    // a fully claimed removed recipient is pruned
+    expect((await fetchConfig()).removedFeeRecipients.length).to.equal(0);
+  });
+
+  it("refuses to remove the last recipient", async () => {
+    const config = await fetchConfig();
+    for (const r of config.feeRecipients.slice(1)) {
+      await removeFeeRecipient(r.address);
+    }
+    await expectError(removeFeeRecipient(signer.publicKey), "LastFeeRecipient");
+  });
+});
@@ -0,0 +1,277 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  createFairLaunchTestLaunch,
+  createWhitelistTestLaunch,
+  fundWallet,
+  getFairLaunchPDAs,
+  getPlatformConfigPDA,
+  getWhitelistLaunchPDAs,
+} from "./utils";
+
+describe("launch cancellation", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const buyer = Keypair.generate();
+  const totalTokens = 10_000_000;
+  const tokenPrice = 1_000_000;               // lamports per whole token
+  const purchase = 2_000_000;                 // 2 tokens, 2_000_000 lamports
+  const contribution = 0.1 * LAMPORTS_PER_SOL;
+
+  let whitelist: Awaited<ReturnType<typeof createWhitelistTestLaunch>>;
+  let fair: Awaited<ReturnType<typeof createFairLaunchTestLaunch>>;
+  let whitelistBuyer: PublicKey;
+  let fairBuyer: PublicKey;
+  let endTime: number;
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+  const tokenBalance = async (account: PublicKey) => Number((await getAccount(connection, account)).amount);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function cancel(launch: { mint: PublicKey, launchpadTokenAccount: PublicKey, authorityTokenAccount: PublicKey }, accounts: { whitelistData?: PublicKey, fairLaunchData?: PublicKey }) {
+    return program.methods
+      .cancelLaunch()
+      .accountsStrict({
+        whitelistData: accounts.whitelistData ?? null,
+        fairLaunchData: accounts.fairLaunchData ?? null,
+        auctionData: null,
+        batchAuctionData: null,
+        tokenMint: launch.mint,
+        launchpadVault: launch.launchpadTokenAccount,
+        authorityTokenAccount: launch.authorityTokenAccount,
+        authority: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  function purchaseWhitelist() {
+    return program.methods
+      .purchaseWhitelist(new BN(purchase), null)
+      .accountsStrict({
+        whitelistData: whitelist.whitelistData,
+        buyerAccount: whitelistBuyer,
+        tokenMint: whitelist.mint,
+        whitelistVault: whitelist.whitelistVault,
+        buyer: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  function contribute() {
+    return program.methods
+      .contributeFairLaunch(new BN(contribution))
+      .accountsStrict({
+        fairLaunchData: fair.fairLaunchData,
+        buyerAccount: fairBuyer,
+        contributionVault: fair.contributionVault,
+        contributor: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  before(async () => {
+    await fundWallet(buyer.publicKey);
+
+    const now = Math.floor(Date.now() / 1000);
+    endTime = now + 16;
+    whitelist = await createWhitelistTestLaunch({
+      tokenPrice,
+      purchaseLimit: totalTokens,
+      totalSupply: totalTokens,
+      startTime: now + 8,
+      endTime,
+    });
+    fair = await createFairLaunchTestLaunch({
+      softCap: 0.05 * LAMPORTS_PER_SOL,
+      hardCap: LAMPORTS_PER_SOL,
+      startTime: now + 8,
+      endTime,
+      minContribution: 0.01 * LAMPORTS_PER_SOL,
+      maxContribution: LAMPORTS_PER_SOL,
+      maxTokensPerWallet: totalTokens,
+      totalTokens,
+    });
+
+    whitelistBuyer = getWhitelistLaunchPDAs(signer.publicKey, whitelist.mint, buyer.publicKey).buyerAccount;
+    fairBuyer = getFairLaunchPDAs(fair.mint, buyer.publicKey).buyerAccount;
+    await program.methods
+      .addWhitelist(buyer.publicKey)
+      .accountsStrict({
+        whitelistData: whitelist.whitelistData,
+        authority: signer.publicKey,
+        buyerAccount: whitelistBuyer,
+        user: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await sleepUntil(now + 9);
+    await purchaseWhitelist();
+    await contribute();
+  });
+
+  it("returns the whitelist sale tokens to the authority and stops the sale", async () => {
+    const authorityBefore = await tokenBalance(whitelist.authorityTokenAccount);
+    await cancel(whitelist, { whitelistData: whitelist.whitelistData });
+
+    expect((await tokenBalance(whitelist.authorityTokenAccount)) - authorityBefore).to.equal(totalTokens);
+    expect(await tokenBalance(whitelist.launchpadTokenAccount)).to.equal(0);
+    const data = await program.account.whitelistLaunchData.fetch(whitelist.whitelistData);
+    expect(data.cancelled).to.equal(true);
+    expect(data.tokensDeposited.toNumber()).to.equal(0);
+
+    await expectError(purchaseWhitelist(), "LaunchCancelled");
+    await expectError(cancel(whitelist, { whitelistData: whitelist.whitelistData }), "LaunchCancelled");
+  });
+
+  it("refunds a whitelist purchase right away and never releases the tokens", async () => {
+    const refund = () => program.methods
+      .refundWhitelistPurchase()
+      .accountsStrict({
+        whitelistData: whitelist.whitelistData,
+        buyerAccount: whitelistBuyer,
+        whitelistVault: whitelist.whitelistVault,
+        buyer: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+
+    const balanceBefore = await connection.getBalance(buyer.publicKey);
+    await refund();
+    expect((await connection.getBalance(buyer.publicKey)) - balanceBefore).to.equal(purchase * tokenPrice / 1_000_000);
+    await expectError(refund(), "NothingToClaim");
+
+    const account = await program.account.buyerAccount.fetch(whitelistBuyer);
+    expect(account.amount.toNumber()).to.equal(0);
+    expect(account.committed.toNumber()).to.equal(0);
+
+    const buyerTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, whitelist.mint, buyer.publicKey);
+    await expectError(
+      program.methods
+        .claimWhitelistTokens()
+        .accountsStrict({
+          whitelistData: whitelist.whitelistData,
+          buyerAccount: whitelistBuyer,
+          tokenMint: whitelist.mint,
+          launchpadVault: whitelist.launchpadTokenAccount,
+          buyerTokenAccount: buyerTokenAccount.address,
+          buyer: buyer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc(),
+      "LaunchCancelled"
+    );
+  });
+
+  it("returns the fair launch tokens and refunds contributions before the sale ends", async () => {
+    const refund = () => program.methods
+      .refundContribution()
+      .accountsStrict({
+        fairLaunchData: fair.fairLaunchData,
+        buyerAccount: fairBuyer,
+        contributionVault: fair.contributionVault,
+        contributor: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+    await expectError(refund(), "SaleNotEnded");
+
+    const authorityBefore = await tokenBalance(fair.authorityTokenAccount);
+    await cancel(fair, { fairLaunchData: fair.fairLaunchData });
+
+    expect((await tokenBalance(fair.authorityTokenAccount)) - authorityBefore).to.equal(totalTokens);
+    expect(await tokenBalance(fair.launchpadTokenAccount)).to.equal(0);
+    await expectError(contribute(), "LaunchCancelled");
+    await expectError(cancel(fair, { fairLaunchData: fair.fairLaunchData }), "LaunchCancelled");
+
+    // the soft cap was reached, the refund is open only because of the cancellation
+    const balanceBefore = await connection.getBalance(buyer.publicKey);
+    await refund();
+    expect((await connection.getBalance(buyer.publicKey)) - balanceBefore).to.equal(contribution);
+    expect(await connection.getBalance(fair.contributionVault)).to.equal(await connection.getMinimumBalanceForRentExemption(0));
+
+    const data = await program.account.fairLaunchData.fetch(fair.fairLaunchData);
+    expect(data.totalRaised.toNumber()).to.equal(0);
+  });
+
+  it("neither finalizes nor distributes a cancelled fair launch", async () => {
+    await sleepUntil(endTime + 2);
+    const config = await program.account.platformConfig.fetch(getPlatformConfigPDA());
+    await expectError(
+      program.methods
+        .finalizeFairLaunch()
+        .accountsStrict({
+          fairLaunchData: fair.fairLaunchData,
+          tokenMint: fair.mint,
+          launchpadVault: fair.launchpadTokenAccount,
+          contributionVault: fair.contributionVault,
+          platformConfig: getPlatformConfigPDA(),
+          treasury: config.treasury,
+          authority: signer.publicKey,
+          bondingCurveConfiguration: null,
+          bondingCurveAccount: null,
+          poolTokenAccount: null,
+          poolSolVault: null,
+          payer: signer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc(),
+      "LaunchCancelled"
+    );
+
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, fair.mint, buyer.publicKey);
+    await expectError(
+      program.methods
+        .distributeTokens()
+        .accountsStrict({
+          fairLaunchData: fair.fairLaunchData,
+          buyerAccount: fairBuyer,
+          tokenMint: fair.mint,
+          launchpadVault: fair.launchpadTokenAccount,
+          recipientTokenAccount: tokenAccount.address,
+          contributionVault: fair.contributionVault,
+          recipient: buyer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc(),
+      "LaunchCancelled"
+    );
+    expect(await tokenBalance(tokenAccount.address)).to.equal(0);
+  });
+});
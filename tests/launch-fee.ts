@@ -0,0 +1,116 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js"
+import { BN } from "bn.js";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { expect } from "chai";
+import { getPlatformConfigPDA, initializePlatformConfigIfNeeded, launchTestToken } from "./utils";
+
+describe("launch fee schedule", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const platformConfig = getPlatformConfigPDA();
+  const baseFeeBps = 100;
+  const amount = LAMPORTS_PER_SOL;
+  let protocolFeeBps: number;
+  let referrerFeeBps: number;
+
+  const bpsOf = (value: number, bps: number) => Math.floor((value * bps) / 10000);
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  const pools = new Map<string, Awaited<ReturnType<typeof launchTestToken>>>();
+  function poolOf(mint: Keypair) {
+    return pools.get(mint.publicKey.toBase58());
+  }
+
+  function quote(mint: Keypair, withReferrer = false) {
+    const { curveConfig, bondingCurve } = poolOf(mint);
+    return program.methods
+      .quote(new BN(amount), true, withReferrer)
+      .accountsStrict({
+        bondingCurveConfiguration: curveConfig,
+        bondingCurveAccount: bondingCurve,
+        platformConfig,
+        tokenMint: mint.publicKey,
+      })
+      .view();
+  }
+
+  async function launch(schedule: object | null) {
+    const mint = Keypair.generate();
+    pools.set(mint.publicKey.toBase58(), await launchTestToken(signer.publicKey, mint, baseFeeBps, schedule));
+    return mint;
+  }
+
+  before(async () => {
+    const config = await initializePlatformConfigIfNeeded(signer.publicKey);
+    protocolFeeBps = config.protocolFeeBps;
+    referrerFeeBps = config.referrerFeeBps;
+  });
+
+  it("quotes the base fee and every layer without a schedule", async () => {
+    const mint = await launch(null);
+    const result = await quote(mint, true);
+
+    expect(result.amountIn.toNumber()).to.equal(amount);
+    expect(result.amountOut.toNumber()).to.be.greaterThan(0);
+    expect(result.effectiveFeeBps).to.equal(baseFeeBps);
+    expect(result.totalFeeBps).to.equal(baseFeeBps + protocolFeeBps + referrerFeeBps);
+    expect(result.fees.poolFee.toNumber()).to.equal(bpsOf(amount, baseFeeBps));
+    expect(result.fees.protocolFee.toNumber()).to.equal(bpsOf(amount, protocolFeeBps));
+    expect(result.fees.referrerFee.toNumber()).to.equal(bpsOf(amount, referrerFeeBps));
+    expect(result.fees.creatorFee.toNumber()).to.equal(0);
+  });
+
+  it("decays a linear launch fee towards the base fee", async () => {
+    const mint = await launch({ initialFeeBps: 5000, decayWindow: new BN(3600), decay: { linear: {} } });
+    const first = await quote(mint);
+
+    // a few seconds into a one hour window the fee has barely moved
+    expect(first.effectiveFeeBps).to.be.at.most(5000);
+    expect(first.effectiveFeeBps).to.be.greaterThan(4900);
+    expect(first.fees.poolFee.toNumber()).to.equal(bpsOf(amount, first.effectiveFeeBps));
+    expect(first.totalFeeBps).to.equal(first.effectiveFeeBps + protocolFeeBps);
+  });
+
+  it("holds a stepped launch fee until the first step and falls back to the base fee after the window", async () => {
+    const mint = await launch({ initialFeeBps: 3000, decayWindow: new BN(10), decay: { stepped: { steps: 1 } } });
+    expect((await quote(mint)).effectiveFeeBps).to.equal(3000);
+
+    await sleep(12000);
+    expect((await quote(mint)).effectiveFeeBps).to.equal(baseFeeBps);
+  });
+
+  it("rejects a launch fee leaving no room for the platform and referrer layers", async () => {
+    const initialFeeBps = 10000 - protocolFeeBps - referrerFeeBps + 1;
+    try {
+      await launch({ initialFeeBps, decayWindow: new BN(3600), decay: { linear: {} } });
+      expect.fail("launched with a launch fee over the fee budget");
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidFee");
+    }
+  });
+
+  it("rejects a creator fee pushing the launch fee over 100%", async () => {
+    const mint = await launch({ initialFeeBps: 9000, decayWindow: new BN(3600), decay: { linear: {} } });
+    try {
+      await program.methods
+        .setCreatorFee(10000 - 9000 - protocolFeeBps - referrerFeeBps + 1)
+        .accountsStrict({
+          bondingCurveConfiguration: poolOf(mint).curveConfig,
+          platformConfig,
+          feeAdmin: signer.publicKey,
+        })
+        .rpc();
+      expect.fail("creator fee set over the fee budget");
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidFee");
+    }
+  });
+});
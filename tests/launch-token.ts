@@ -0,0 +1,123 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js"
+import { BN } from "bn.js";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+  getTokenMetadata,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import { deriveMintMetadata, getPDAs, getPlatformConfigPDA, initializePlatformConfigIfNeeded, METAPLEX_PROGRAM } from "./utils";
+
+describe("launch token", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const initialSupply = 100_000_000_000;
+  const metadata = { name: "Launch Token", symbol: "LAUNCH", uri: "https://example.com/launch.json" };
+
+  before(async () => {
+    await initializePlatformConfigIfNeeded(signer.publicKey);
+  });
+
+  function launchToken(mint: Keypair, tokenProgram: PublicKey, tokenMetadata = metadata) {
+    const { curveConfig, bondingCurve, poolSolVault, feeVault } = getPDAs(signer.publicKey, mint.publicKey);
+    const poolTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, bondingCurve, true, tokenProgram);
+    const isSpl = tokenProgram.equals(TOKEN_PROGRAM_ID);
+
+    return program.methods
+      .launchToken(
+        6,
+        tokenMetadata,
+        signer.publicKey,
+        100,                      // fee percentage
+        new BN(500),              // initial quorum
+        new BN(1_000_000_000),    // target liquidity
+        signer.publicKey,         // governance
+        500,                      // dao quorum
+        0,                        // linear curve
+        new BN(10_000_000_000),   // max token supply
+        new BN(60),               // liquidity lock period
+        50,                       // liquidity pool percentage
+        new BN(100),              // initial price, 0.005 SOL reserve
+        new BN(initialSupply),
+        [{ address: signer.publicKey, share: 10000, amount: new BN(0), lockingPeriod: new BN(60000), kind: { wallet: {} } }],
+        5000,                     // reserve ratio
+        null,
+      )
+      .accountsStrict({
+        bondingCurveConfiguration: curveConfig,
+        bondingCurveAccount: bondingCurve,
+        tokenMint: mint.publicKey,
+        poolTokenAccount,
+        poolSolVault,
+        feeVault,
+        tokenMetadata: isSpl ? deriveMintMetadata(mint.publicKey)[0] : null,
+        metadataProgram: isSpl ? METAPLEX_PROGRAM : null,
+        platformConfig: getPlatformConfigPDA(),
+        admin: signer.publicKey,
+        tokenProgram,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+  }
+
+  it("launches a Token-2022 token with metadata and a fixed supply", async () => {
+    const mint = Keypair.generate();
+    await launchToken(mint, TOKEN_2022_PROGRAM_ID);
+
+    const { bondingCurve } = getPDAs(signer.publicKey, mint.publicKey);
+    const mintInfo = await getMint(connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(mintInfo.mintAuthority).to.equal(null);
+    expect(mintInfo.freezeAuthority).to.equal(null);
+    expect(Number(mintInfo.supply)).to.equal(initialSupply);
+
+    const poolTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, bondingCurve, true, TOKEN_2022_PROGRAM_ID);
+    const pool = await getAccount(connection, poolTokenAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(Number(pool.amount)).to.equal(initialSupply);
+
+    const tokenMetadata = await getTokenMetadata(connection, mint.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(tokenMetadata.name).to.equal(metadata.name);
+    expect(tokenMetadata.symbol).to.equal(metadata.symbol);
+    expect(tokenMetadata.uri).to.equal(metadata.uri);
+    expect(tokenMetadata.updateAuthority).to.equal(undefined);
+
+    const curve = await program.account.bondingCurve.fetch(bondingCurve);
+    expect(curve.reserveToken.toNumber()).to.equal(initialSupply);
+  });
+
+  it("launches an SPL token with Metaplex metadata", async () => {
+    // the Metaplex program is cloned onto the test validator in Anchor.toml
+    const mint = Keypair.generate();
+    await launchToken(mint, TOKEN_PROGRAM_ID);
+
+    const mintInfo = await getMint(connection, mint.publicKey);
+    expect(mintInfo.mintAuthority).to.equal(null);
+    expect(mintInfo.freezeAuthority).to.equal(null);
+    expect(Number(mintInfo.supply)).to.equal(initialSupply);
+    expect(await connection.getAccountInfo(deriveMintMetadata(mint.publicKey)[0])).to.not.equal(null);
+  });
+
+  it("rejects metadata over the Metaplex limits", async () => {
+    try {
+      await launchToken(Keypair.generate(), TOKEN_2022_PROGRAM_ID, { ...metadata, symbol: "TOOLONGSYMBOL" });
+      expect.fail("launched with an invalid symbol");
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidTokenMetadata");
+    }
+  });
+});
@@ -0,0 +1,281 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, Transaction, LAMPORTS_PER_SOL } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { ASSOCIATED_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { createMint, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import { getWhitelistLaunchPDAs, getFairLaunchPDAs, getPlatformConfigPDA, initializePlatformConfigIfNeeded } from "./utils";
+
+describe("launchpad authority", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const attacker = Keypair.generate();
+  const manager = Keypair.generate();
+  const user = Keypair.generate();
+  let mint: PublicKey;
+  let whitelistData: PublicKey;
+  let fairLaunchData: PublicKey;
+
+  // Every unauthorized call must fail on the authority constraint, not on something else
+  async function expectUnauthorized(call: Promise<string>) {
+    try {
+      await call;
+      expect.fail("unauthorized call succeeded");
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal("InvalidAuthority");
+    }
+  }
+
+  function addWhitelist(caller: Keypair, wallet: PublicKey) {
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, mint, wallet);
+    return program.methods
+      .addWhitelist(wallet)
+      .accountsStrict({
+        whitelistData,
+        authority: caller.publicKey,
+        buyerAccount,
+        user: wallet,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([caller])
+      .rpc();
+  }
+
+  function removeWhitelist(caller: Keypair, wallet: PublicKey) {
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, mint, wallet);
+    return program.methods
+      .removeWhitelist(wallet)
+      .accountsStrict({
+        whitelistData,
+        authority: caller.publicKey,
+        buyerAccount,
+        user: wallet,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([caller])
+      .rpc();
+  }
+
+  function pauseLaunchpad(caller: Keypair, launch: { whitelistData?: PublicKey, fairLaunchData?: PublicKey }) {
+    return program.methods
+      .pauseLaunchpad()
+      .accountsStrict({
+        whitelistData: launch.whitelistData ?? null,
+        fairLaunchData: launch.fairLaunchData ?? null,
+        auctionData: null,
+        batchAuctionData: null,
+        authority: caller.publicKey,
+      })
+      .signers([caller])
+      .rpc();
+  }
+
+  function unpauseLaunchpad(caller: Keypair, launch: { whitelistData?: PublicKey, fairLaunchData?: PublicKey }) {
+    return program.methods
+      .unpauseLaunchpad()
+      .accountsStrict({
+        whitelistData: launch.whitelistData ?? null,
+        fairLaunchData: launch.fairLaunchData ?? null,
+        auctionData: null,
+        batchAuctionData: null,
+        authority: caller.publicKey,
+      })
+      .signers([caller])
+      .rpc();
+  }
+
+  before(async () => {
+    // Fund the non-authority wallets from the provider wallet
+    const tx = new Transaction();
+    for (const wallet of [attacker, manager]) {
+      tx.add(
+        SystemProgram.transfer({
+          fromPubkey: signer.publicKey,
+          toPubkey: wallet.publicKey,
+          lamports: 0.1 * LAMPORTS_PER_SOL,
+        })
+      );
+    }
+    await provider.sendAndConfirm(tx);
+
+    mint = await createMint(connection, signer.payer, signer.publicKey, null, 6);
+    const now = Math.floor(Date.now() / 1000);
+
+    const whitelistPDAs = getWhitelistLaunchPDAs(signer.publicKey, mint, user.publicKey);
+    whitelistData = whitelistPDAs.whitelistData;
+    await program.methods
+      .createWhitelistLaunch(
+        new BN(1_000_000),        // token price
+        new BN(1_000_000_000),    // purchase limit per wallet
+        new BN(10_000_000_000),   // total supply
+        new BN(3600),             // whitelist duration
+        new BN(now + 600),        // start time
+        new BN(now + 7200),       // end time
+      )
+      .accountsStrict({
+        whitelistData,
+        tokenMint: mint,
+        launchpadVault: whitelistPDAs.launchpadTokenAccount,
+        whitelistVault: whitelistPDAs.whitelistVault,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    await initializePlatformConfigIfNeeded(signer.publicKey);
+    const fairLaunchPDAs = getFairLaunchPDAs(mint, user.publicKey);
+    fairLaunchData = fairLaunchPDAs.fairLaunchData;
+    await program.methods
+      .createFairLaunch(
+        new BN(1_000_000_000),    // soft cap
+        new BN(5_000_000_000),    // hard cap
+        new BN(now + 600),        // start time
+        new BN(now + 7200),       // end time
+        new BN(10_000_000),       // min contribution
+        new BN(1_000_000_000),    // max contribution
+        new BN(1_000_000_000),    // max tokens per wallet
+        new BN(0),                // distribution delay
+        new BN(10_000_000_000),   // total tokens
+      )
+      .accountsStrict({
+        fairLaunchData,
+        tokenMint: mint,
+        launchpadVault: fairLaunchPDAs.launchpadTokenAccount,
+        contributionVault: fairLaunchPDAs.contributionVault,
+        platformConfig: getPlatformConfigPDA(),
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+  });
+
+  it("rejects pausing a whitelist launch by another wallet", async () => {
+    await expectUnauthorized(pauseLaunchpad(attacker, { whitelistData }));
+  });
+
+  it("rejects pausing a fair launch by another wallet", async () => {
+    await expectUnauthorized(pauseLaunchpad(attacker, { fairLaunchData }));
+  });
+
+  it("rejects unpausing by another wallet", async () => {
+    await pauseLaunchpad(signer.payer, { whitelistData, fairLaunchData });
+    await expectUnauthorized(unpauseLaunchpad(attacker, { whitelistData, fairLaunchData }));
+
+    const whitelist = await program.account.whitelistLaunchData.fetch(whitelistData);
+    expect(whitelist.paused).to.equal(true);
+
+    await unpauseLaunchpad(signer.payer, { whitelistData, fairLaunchData });
+  });
+
+  it("rejects a wallet whitelisting itself", async () => {
+    await expectUnauthorized(addWhitelist(attacker, attacker.publicKey));
+  });
+
+  it("rejects setting the buyer tier by another wallet", async () => {
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, mint, attacker.publicKey);
+    await expectUnauthorized(
+      program.methods
+        .setBuyerTier(1)
+        .accountsStrict({
+          whitelistData,
+          buyerAccount,
+          user: attacker.publicKey,
+          authority: attacker.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([attacker])
+        .rpc()
+    );
+  });
+
+  it("rejects setting the whitelist manager by another wallet", async () => {
+    await expectUnauthorized(
+      program.methods
+        .setWhitelistManager(attacker.publicKey)
+        .accountsStrict({ whitelistData, authority: attacker.publicKey })
+        .signers([attacker])
+        .rpc()
+    );
+  });
+
+  it("rejects setting the merkle root by another wallet", async () => {
+    await expectUnauthorized(
+      program.methods
+        .setWhitelistMerkleRoot(Array(32).fill(1))
+        .accountsStrict({ whitelistData, authority: attacker.publicKey })
+        .signers([attacker])
+        .rpc()
+    );
+  });
+
+  it("rejects cancelling a launch by another wallet", async () => {
+    const attackerTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, attacker.publicKey);
+    const { launchpadTokenAccount } = getFairLaunchPDAs(mint, attacker.publicKey);
+    await expectUnauthorized(
+      program.methods
+        .cancelLaunch()
+        .accountsStrict({
+          whitelistData: null,
+          fairLaunchData,
+          auctionData: null,
+          batchAuctionData: null,
+          tokenMint: mint,
+          launchpadVault: launchpadTokenAccount,
+          authorityTokenAccount: attackerTokenAccount.address,
+          authority: attacker.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([attacker])
+        .rpc()
+    );
+  });
+
+  it("lets a delegated whitelist manager add and remove buyers", async () => {
+    await program.methods
+      .setWhitelistManager(manager.publicKey)
+      .accountsStrict({ whitelistData, authority: signer.publicKey })
+      .rpc();
+
+    await addWhitelist(manager, user.publicKey);
+    let whitelist = await program.account.whitelistLaunchData.fetch(whitelistData);
+    expect(whitelist.whitelistedUsers.map((key) => key.toBase58())).to.include(user.publicKey.toBase58());
+
+    await removeWhitelist(manager, user.publicKey);
+    whitelist = await program.account.whitelistLaunchData.fetch(whitelistData);
+    expect(whitelist.whitelistedUsers.map((key) => key.toBase58())).to.not.include(user.publicKey.toBase58());
+  });
+
+  it("keeps the whitelist manager out of the other admin instructions", async () => {
+    await expectUnauthorized(pauseLaunchpad(manager, { whitelistData }));
+    await expectUnauthorized(
+      program.methods
+        .setWhitelistManager(attacker.publicKey)
+        .accountsStrict({ whitelistData, authority: manager.publicKey })
+        .signers([manager])
+        .rpc()
+    );
+  });
+
+  it("rejects a former whitelist manager", async () => {
+    await program.methods
+      .setWhitelistManager(signer.publicKey)
+      .accountsStrict({ whitelistData, authority: signer.publicKey })
+      .rpc();
+
+    await expectUnauthorized(addWhitelist(manager, user.publicKey));
+  });
+});
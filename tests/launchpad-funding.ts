@@ -0,0 +1,248 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  createFairLaunchTestLaunch,
+  createWhitelistTestLaunch,
+  fundWallet,
+  getFairLaunchPDAs,
+  getWhitelistLaunchPDAs,
+} from "./utils";
+
+describe("launchpad funding", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const buyer = Keypair.generate();
+  const outsider = Keypair.generate();
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+  const balanceOf = async (account: PublicKey) => Number((await getAccount(connection, account)).amount);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  before(async () => {
+    await fundWallet(buyer.publicKey);
+    await fundWallet(outsider.publicKey, 0.1 * LAMPORTS_PER_SOL);
+  });
+
+  describe("whitelist sale", () => {
+    const totalSupply = 10_000_000;
+    const sold = 1_000_000;
+    let launch: Awaited<ReturnType<typeof createWhitelistTestLaunch>>;
+    let endTime: number;
+
+    function fund(amount: number, authority: Keypair = signer.payer) {
+      return program.methods
+        .fundWhitelistLaunch(new BN(amount))
+        .accountsStrict({
+          whitelistData: launch.whitelistData,
+          tokenMint: launch.mint,
+          launchpadVault: launch.launchpadTokenAccount,
+          authorityTokenAccount: launch.authorityTokenAccount,
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    function purchase(amount: number) {
+      const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, buyer.publicKey);
+      return program.methods
+        .purchaseWhitelist(new BN(amount), null)
+        .accountsStrict({
+          whitelistData: launch.whitelistData,
+          buyerAccount,
+          tokenMint: launch.mint,
+          whitelistVault: launch.whitelistVault,
+          buyer: buyer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+    }
+
+    function withdrawUnsold() {
+      return program.methods
+        .withdrawUnsoldWhitelistTokens()
+        .accountsStrict({
+          whitelistData: launch.whitelistData,
+          tokenMint: launch.mint,
+          launchpadVault: launch.launchpadTokenAccount,
+          authorityTokenAccount: launch.authorityTokenAccount,
+          authority: signer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+      endTime = now + 19;
+      launch = await createWhitelistTestLaunch({
+        tokenPrice: 1_000_000,
+        purchaseLimit: 2_000_000,
+        totalSupply,
+        startTime: now + 10,
+        endTime,
+        fund: false,
+      });
+
+      const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, buyer.publicKey);
+      await program.methods
+        .addWhitelist(buyer.publicKey)
+        .accountsStrict({
+          whitelistData: launch.whitelistData,
+          authority: signer.publicKey,
+          buyerAccount,
+          user: buyer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("only lets the authority fund the vault", async () => {
+      await expectError(fund(totalSupply, outsider), "InvalidAuthority");
+    });
+
+    it("blocks purchases until the vault holds the sale supply", async () => {
+      await expectError(purchase(sold), "LaunchpadNotFunded");
+      await fund(totalSupply / 2);
+      await expectError(purchase(sold), "LaunchpadNotFunded");
+      await fund(totalSupply / 2);
+
+      const data = await program.account.whitelistLaunchData.fetch(launch.whitelistData);
+      expect(data.tokensDeposited.toNumber()).to.equal(totalSupply);
+      expect(await balanceOf(launch.launchpadTokenAccount)).to.equal(totalSupply);
+
+      await sleepUntil(data.startTime.toNumber() + 1);
+      await purchase(sold);
+    });
+
+    it("returns the unsold tokens once the sale ended and keeps the sold ones", async () => {
+      await expectError(withdrawUnsold(), "SaleNotEnded");
+      await sleepUntil(endTime + 2);
+
+      const authorityBefore = await balanceOf(launch.authorityTokenAccount);
+      await withdrawUnsold();
+      expect((await balanceOf(launch.authorityTokenAccount)) - authorityBefore).to.equal(totalSupply - sold);
+      expect(await balanceOf(launch.launchpadTokenAccount)).to.equal(sold);
+      await expectError(withdrawUnsold(), "NothingToClaim");
+
+      // the buyer still claims what they paid for
+      const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, buyer.publicKey);
+      const buyerTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, launch.mint, buyer.publicKey);
+      await program.methods
+        .claimWhitelistTokens()
+        .accountsStrict({
+          whitelistData: launch.whitelistData,
+          buyerAccount,
+          tokenMint: launch.mint,
+          launchpadVault: launch.launchpadTokenAccount,
+          buyerTokenAccount: buyerTokenAccount.address,
+          buyer: buyer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc();
+      expect(await balanceOf(buyerTokenAccount.address)).to.equal(sold);
+    });
+  });
+
+  describe("fair launch", () => {
+    const totalTokens = 10_000_000;
+    let launch: Awaited<ReturnType<typeof createFairLaunchTestLaunch>>;
+    let endTime: number;
+
+    function contribute(amount: number) {
+      const { buyerAccount } = getFairLaunchPDAs(launch.mint, buyer.publicKey);
+      return program.methods
+        .contributeFairLaunch(new BN(amount))
+        .accountsStrict({
+          fairLaunchData: launch.fairLaunchData,
+          buyerAccount,
+          contributionVault: launch.contributionVault,
+          contributor: buyer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+    }
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+      endTime = now + 14;
+      launch = await createFairLaunchTestLaunch({
+        softCap: LAMPORTS_PER_SOL,
+        hardCap: 2 * LAMPORTS_PER_SOL,
+        startTime: now + 8,
+        endTime,
+        minContribution: 10_000_000,
+        maxContribution: LAMPORTS_PER_SOL,
+        maxTokensPerWallet: totalTokens,
+        totalTokens,
+        fund: false,
+      });
+    });
+
+    it("blocks contributions until the vault holds the tokens for sale", async () => {
+      await expectError(contribute(10_000_000), "LaunchpadNotFunded");
+      await program.methods
+        .fundFairLaunch(new BN(totalTokens))
+        .accountsStrict({
+          fairLaunchData: launch.fairLaunchData,
+          tokenMint: launch.mint,
+          launchpadVault: launch.launchpadTokenAccount,
+          authorityTokenAccount: launch.authorityTokenAccount,
+          authority: signer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const data = await program.account.fairLaunchData.fetch(launch.fairLaunchData);
+      await sleepUntil(data.startTime.toNumber() + 1);
+      await contribute(10_000_000);
+    });
+
+    it("returns every token of a sale that missed the soft cap", async () => {
+      await sleepUntil(endTime + 2);
+      const authorityBefore = await balanceOf(launch.authorityTokenAccount);
+      await program.methods
+        .withdrawUnsoldFairLaunchTokens()
+        .accountsStrict({
+          fairLaunchData: launch.fairLaunchData,
+          tokenMint: launch.mint,
+          launchpadVault: launch.launchpadTokenAccount,
+          authorityTokenAccount: launch.authorityTokenAccount,
+          authority: signer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      expect((await balanceOf(launch.authorityTokenAccount)) - authorityBefore).to.equal(totalTokens);
+      expect(await balanceOf(launch.launchpadTokenAccount)).to.equal(0);
+    });
+  });
+});
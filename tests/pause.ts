@@ -0,0 +1,127 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js"
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { expect } from "chai";
+import { buyTestToken, fundWallet, getPlatformConfigPDA, initializePlatformConfigIfNeeded, launchTestToken } from "./utils";
+
+describe("pool pause and kill switch", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const platformConfig = getPlatformConfigPDA();
+  const mint = Keypair.generate();
+  const buyer = Keypair.generate();
+  const pauser = Keypair.generate();
+  const buyAmount = 0.1 * LAMPORTS_PER_SOL;
+
+  let pool: Awaited<ReturnType<typeof launchTestToken>>;
+  let previousPauser: PublicKey;
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function setPauser(newPauser: PublicKey) {
+    return program.methods
+      .setPauser(newPauser)
+      .accountsStrict({ platformConfig, admin: signer.publicKey })
+      .rpc();
+  }
+
+  function setPaused(paused: boolean, by: Keypair = pauser) {
+    const method = paused ? program.methods.pausePool() : program.methods.unpausePool();
+    return method
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        tokenMint: mint.publicKey,
+        platformConfig,
+        pauser: by.publicKey,
+      })
+      .signers([by])
+      .rpc();
+  }
+
+  function setKillSwitch(halted: boolean) {
+    return program.methods
+      .setKillSwitch(halted)
+      .accountsStrict({ platformConfig, pauser: pauser.publicKey })
+      .signers([pauser])
+      .rpc();
+  }
+
+  before(async () => {
+    const config = await initializePlatformConfigIfNeeded(signer.publicKey);
+    previousPauser = config.pauser;
+    await fundWallet(buyer.publicKey);
+    await fundWallet(pauser.publicKey, 0.05 * LAMPORTS_PER_SOL);
+
+    pool = await launchTestToken(signer.publicKey, mint);
+    await buyTestToken(pool, mint.publicKey, buyer, buyAmount);
+    await setPauser(pauser.publicKey);
+  });
+
+  after(async () => {
+    // leave trading open and the original pauser in place for the other specs
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    if (config.tradingHalted) {
+      await setKillSwitch(false);
+    }
+    if (previousPauser.equals(signer.publicKey)) {
+      await setPauser(previousPauser);
+    }
+  });
+
+  it("only lets the pauser pause a pool", async () => {
+    const outsider = Keypair.generate();
+    await fundWallet(outsider.publicKey, 0.05 * LAMPORTS_PER_SOL);
+    await expectError(setPaused(true, outsider), "InvalidAuthority");
+  });
+
+  it("blocks trading on a paused pool while fee claims keep working", async () => {
+    await setPaused(true);
+    expect((await program.account.curveConfiguration.fetch(pool.curveConfig)).paused).to.equal(true);
+
+    await expectError(buyTestToken(pool, mint.publicKey, buyer, buyAmount), "PoolPaused");
+    await expectError(setPaused(true), "PoolPaused");
+
+    await program.methods
+      .claimFees()
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: mint.publicKey,
+        feeVault: pool.feeVault,
+        recipient: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("reopens trading once unpaused", async () => {
+    await setPaused(false);
+    await expectError(setPaused(false), "PoolNotPaused");
+    await buyTestToken(pool, mint.publicKey, buyer, buyAmount);
+  });
+
+  it("halts trading on every pool with the kill switch", async () => {
+    const otherMint = Keypair.generate();
+    const otherPool = await launchTestToken(signer.publicKey, otherMint);
+
+    await setKillSwitch(true);
+    await expectError(buyTestToken(pool, mint.publicKey, buyer, buyAmount), "TradingHalted");
+    await expectError(buyTestToken(otherPool, otherMint.publicKey, buyer, buyAmount), "TradingHalted");
+
+    await setKillSwitch(false);
+    await buyTestToken(otherPool, otherMint.publicKey, buyer, buyAmount);
+  });
+});
@@ -0,0 +1,263 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotent,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  fundWallet,
+  getPlatformConfigPDA,
+  getProgramDataPDA,
+  getReferralPDA,
+  initializePlatformConfigIfNeeded,
+  launchTestToken,
+} from "./utils";
+
+describe("platform fees", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const platformConfig = getPlatformConfigPDA();
+  const mint = Keypair.generate();
+  const buyer = Keypair.generate();
+  const referrer = Keypair.generate();
+  const referralAccount = getReferralPDA(referrer.publicKey);
+  const buyAmount = 0.1 * LAMPORTS_PER_SOL;
+  const poolFeeBps = 100;
+  const creatorFeeBps = 200;
+
+  let pool: Awaited<ReturnType<typeof launchTestToken>>;
+  let protocolFeeBps: number;
+  let referrerFeeBps: number;
+
+  const bpsOf = (amount: number, bps: number) => Math.floor((amount * bps) / 10000);
+
+  before(async () => {
+    await fundWallet(buyer.publicKey);
+    await fundWallet(referrer.publicKey, 0.05 * LAMPORTS_PER_SOL);
+  });
+
+  it("only lets the upgrade authority initialize the platform config", async () => {
+    const impostor = Keypair.generate();
+    await fundWallet(impostor.publicKey, 0.05 * LAMPORTS_PER_SOL);
+
+    try {
+      await program.methods
+        .initializePlatformConfig(impostor.publicKey, 100, 50)
+        .accountsStrict({
+          platformConfig,
+          program: program.programId,
+          programData: getProgramDataPDA(),
+          admin: impostor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([impostor])
+        .rpc();
+      expect.fail("platform config initialized by a non upgrade authority");
+    } catch (error) {
+      expect(error.message).to.not.include("platform config initialized");
+    }
+
+    const config = await initializePlatformConfigIfNeeded(signer.publicKey);
+    expect(config.admin.toBase58()).to.equal(signer.publicKey.toBase58());
+    protocolFeeBps = config.protocolFeeBps;
+    referrerFeeBps = config.referrerFeeBps;
+  });
+
+  it("splits a buy fee between the pool, protocol, creator and referrer layers", async () => {
+    pool = await launchTestToken(signer.publicKey, mint, poolFeeBps);
+
+    await program.methods
+      .setCreatorFee(creatorFeeBps)
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        platformConfig,
+        feeAdmin: signer.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .registerReferrer()
+      .accountsStrict({
+        referralAccount,
+        referrer: referrer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([referrer])
+      .rpc();
+
+    const buyerTokenAccount = await createAssociatedTokenAccountIdempotent(
+      connection, signer.payer, mint.publicKey, buyer.publicKey, {}, TOKEN_2022_PROGRAM_ID
+    );
+    const feeVaultBefore = await connection.getBalance(pool.feeVault);
+    const referralBefore = await connection.getBalance(referralAccount);
+
+    await program.methods
+      .buy(new BN(buyAmount))
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: mint.publicKey,
+        poolTokenAccount: pool.poolTokenAccount,
+        poolSolVault: pool.poolSolVault,
+        feeVault: pool.feeVault,
+        platformConfig,
+        referralAccount,
+        userTokenAccount: buyerTokenAccount,
+        user: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([buyer])
+      .rpc();
+
+    const poolFee = bpsOf(buyAmount, poolFeeBps);
+    const protocolFee = bpsOf(buyAmount, protocolFeeBps);
+    const creatorFee = bpsOf(buyAmount, creatorFeeBps);
+    const referrerFee = bpsOf(buyAmount, referrerFeeBps);
+
+    const config = await program.account.curveConfiguration.fetch(pool.curveConfig);
+    expect(config.feeRecipients[0].amount.toNumber()).to.equal(poolFee);
+    expect(config.protocolFeesAccrued.toNumber()).to.equal(protocolFee);
+    expect(config.creatorFeesAccrued.toNumber()).to.equal(creatorFee);
+
+    // the referrer share is held on the referral account, everything else in the fee vault
+    expect((await connection.getBalance(pool.feeVault)) - feeVaultBefore).to.equal(poolFee + protocolFee + creatorFee);
+    expect((await connection.getBalance(referralAccount)) - referralBefore).to.equal(referrerFee);
+
+    const referral = await program.account.referralAccount.fetch(referralAccount);
+    expect(referral.totalVolume.toNumber()).to.equal(buyAmount);
+    expect(referral.tradeCount.toNumber()).to.equal(1);
+    expect(referral.feesEarned.toNumber()).to.equal(referrerFee);
+  });
+
+  it("rejects a trader referring themselves", async () => {
+    const buyerTokenAccount = await createAssociatedTokenAccountIdempotent(
+      connection, signer.payer, mint.publicKey, referrer.publicKey, {}, TOKEN_2022_PROGRAM_ID
+    );
+
+    try {
+      await program.methods
+        .buy(new BN(buyAmount))
+        .accountsStrict({
+          bondingCurveConfiguration: pool.curveConfig,
+          bondingCurveAccount: pool.bondingCurve,
+          tokenMint: mint.publicKey,
+          poolTokenAccount: pool.poolTokenAccount,
+          poolSolVault: pool.poolSolVault,
+          feeVault: pool.feeVault,
+          platformConfig,
+          referralAccount,
+          userTokenAccount: buyerTokenAccount,
+          user: referrer.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([referrer])
+        .rpc();
+      expect.fail("referrer bought with their own referral");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidReferrer");
+    }
+  });
+
+  it("pays each fee layer out to its claimer", async () => {
+    const config = await program.account.curveConfiguration.fetch(pool.curveConfig);
+    const poolFee = config.feeRecipients[0].amount.toNumber();
+    const creatorFee = config.creatorFeesAccrued.toNumber();
+    const protocolFee = config.protocolFeesAccrued.toNumber();
+
+    let feeVaultBefore = await connection.getBalance(pool.feeVault);
+    await program.methods
+      .claimFees()
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: mint.publicKey,
+        feeVault: pool.feeVault,
+        recipient: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    expect(feeVaultBefore - (await connection.getBalance(pool.feeVault))).to.equal(poolFee);
+
+    feeVaultBefore = await connection.getBalance(pool.feeVault);
+    await program.methods
+      .claimCreatorFees()
+      .accountsStrict({
+        bondingCurveConfiguration: pool.curveConfig,
+        bondingCurveAccount: pool.bondingCurve,
+        tokenMint: mint.publicKey,
+        feeVault: pool.feeVault,
+        creator: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    expect(feeVaultBefore - (await connection.getBalance(pool.feeVault))).to.equal(creatorFee);
+
+    // the treasury is the provider wallet when this spec created the platform config
+    const platform = await program.account.platformConfig.fetch(platformConfig);
+    if (platform.treasury.equals(signer.publicKey)) {
+      feeVaultBefore = await connection.getBalance(pool.feeVault);
+      await program.methods
+        .claimProtocolFees()
+        .accountsStrict({
+          platformConfig,
+          bondingCurveConfiguration: pool.curveConfig,
+          bondingCurveAccount: pool.bondingCurve,
+          tokenMint: mint.publicKey,
+          feeVault: pool.feeVault,
+          treasury: signer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect(feeVaultBefore - (await connection.getBalance(pool.feeVault))).to.equal(protocolFee);
+    }
+
+    const referralBefore = await connection.getBalance(referralAccount);
+    const referral = await program.account.referralAccount.fetch(referralAccount);
+    await program.methods
+      .claimReferralFees()
+      .accountsStrict({
+        referralAccount,
+        referrer: referrer.publicKey,
+      })
+      .signers([referrer])
+      .rpc();
+    expect(referralBefore - (await connection.getBalance(referralAccount))).to.equal(referral.feesEarned.toNumber());
+
+    const after = await program.account.curveConfiguration.fetch(pool.curveConfig);
+    expect(after.feeRecipients[0].amount.toNumber()).to.equal(0);
+    expect(after.creatorFeesAccrued.toNumber()).to.equal(0);
+  });
+
+  it("rejects a second claim with nothing accrued", async () => {
+    try {
+      await program.methods
+        .claimCreatorFees()
+        .accountsStrict({
+          bondingCurveConfiguration: pool.curveConfig,
+          bondingCurveAccount: pool.bondingCurve,
+          tokenMint: mint.publicKey,
+          feeVault: pool.feeVault,
+          creator: signer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("claimed creator fees twice");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("NoFeesToClaim");
+    }
+  });
+});
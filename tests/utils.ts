@@ -1,8 +1,9 @@
-import { clusterApiUrl, Connection, Keypair, PublicKey, Signer, SystemProgram, TransactionInstruction } from "@solana/web3.js";
+import { BPF_LOADER_UPGRADEABLE_PROGRAM_ID, clusterApiUrl, Connection, Keypair, LAMPORTS_PER_SOL, PublicKey, Signer, SystemProgram, SYSVAR_RENT_PUBKEY, Transaction, TransactionInstruction } from "@solana/web3.js";
 import * as anchor from "@coral-xyz/anchor";
 import { AnchorProvider, Program, Idl } from "@coral-xyz/anchor";
 import { BondingCurve } from "../target/types/bonding_curve"
-import { ASSOCIATED_TOKEN_PROGRAM_ID, createAssociatedTokenAccountInstruction, getAssociatedTokenAddress, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, createAssociatedTokenAccountInstruction, createMint, getAssociatedTokenAddress, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { keccak_256 } from "@noble/hashes/sha3";
 import fs from "fs";
 import { VaultMeteora, IDL } from "../idls/vault_meteora";
 import {
@@ -16,6 +17,10 @@ export const CURVE_CONFIGURATION_SEED = "curve_configuration"
 const POOL_SEED_PREFIX = "bonding_curve"
 const SOL_VAULT_PREFIX = "liquidity_sol_vault"
 const TOKEN_VAULT_PREFIX = "token_vault"
+const FEE_POOL_VAULT_PREFIX = "fee_pool_vault"
+// Platform & fees
+export const PLATFORM_CONFIG_SEED = "platform_config"
+export const REFERRAL_SEED_PREFIX = "referral"
 // Meteora 
 const POOL_METEORA_PREFIX = "pool"
 const PROTOCOL_FEE_PREFIX = "fee"
@@ -35,12 +40,17 @@ export const TEST_CONFIG = new PublicKey("BdfD7rrTZEWmf8UbEBPVpvM3wUqyrR8swjAy5S
 export const LAUNCHPAD_SEED_PREFIX = "launchpad";
 export const WHITELIST_DATA_SEED_PREFIX = "whitelist_data";
 export const FAIR_LAUNCH_DATA_SEED_PREFIX = "fair_launch_data";
+export const DUTCH_AUCTION_DATA_SEED_PREFIX = "dutch_auction_data";
+export const BATCH_AUCTION_DATA_SEED_PREFIX = "batch_auction_data";
+export const BATCH_BID_SEED_PREFIX = "batch_bid";
 export const CONTRIBUTION_VAULT_SEED_PREFIX = "fair_launch_vault";
+export const WHITELIST_VAULT_SEED_PREFIX = "whitelist_vault";
 export const BUYER_SEED_PREFIX = "buyer";
 
 
 // Allocation
 const ALLOCATION_SEED_PREFIX = "allocation"
+const TOKENOMICS_PLAN_SEED_PREFIX = "tokenomics_plan"
 
 // PumpSwap
 const POOL_PUMP_SWAP_PREFIX = "pool"
@@ -68,6 +78,11 @@ export function getPDAs(user: PublicKey, mint: PublicKey) {
     program.programId
   );
 
+  const [feeVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from(FEE_POOL_VAULT_PREFIX), mint.toBuffer()],
+    program.programId
+  );
+
   const poolTokenAccount = getAssociatedTokenAddressSync(
     mint, bondingCurve, true
   )
@@ -82,15 +97,163 @@ export function getPDAs(user: PublicKey, mint: PublicKey) {
     bondingCurve,
     poolSolVault,
     poolSolVaultBump,
+    feeVault,
     poolTokenAccount,
   };
 }
 
+export function getPlatformConfigPDA() {
+  const [platformConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PLATFORM_CONFIG_SEED)],
+    program.programId
+  );
+  return platformConfig;
+}
+
+export function getReferralPDA(referrer: PublicKey) {
+  const [referralAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from(REFERRAL_SEED_PREFIX), referrer.toBuffer()],
+    program.programId
+  );
+  return referralAccount;
+}
+
+export function getProgramDataPDA() {
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  );
+  return programData;
+}
+
+// The platform config is a singleton, only the program upgrade authority can create it
+export async function initializePlatformConfigIfNeeded(admin: PublicKey, protocolFeeBps = 100, referrerFeeBps = 50) {
+  const platformConfig = getPlatformConfigPDA();
+  if (!(await program.provider.connection.getAccountInfo(platformConfig))) {
+    await program.methods
+      .initializePlatformConfig(admin, protocolFeeBps, referrerFeeBps)
+      .accountsStrict({
+        platformConfig,
+        program: program.programId,
+        programData: getProgramDataPDA(),
+        admin,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+  return program.account.platformConfig.fetch(platformConfig);
+}
+
+// Launch a Token-2022 token and its bonding curve pool, `creator` must be the provider wallet
+export async function launchTestToken(
+  creator: PublicKey,
+  mint: Keypair,
+  feePercentage = 100,
+  launchFeeSchedule: object | null = null,
+  recipients: object[] = [{ address: creator, share: 10000, amount: new anchor.BN(0), lockingPeriod: new anchor.BN(0), kind: { wallet: {} } }],
+) {
+  const { curveConfig, bondingCurve, poolSolVault, feeVault } = getPDAs(creator, mint.publicKey);
+  const poolTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, bondingCurve, true, TOKEN_2022_PROGRAM_ID);
+
+  await program.methods
+    .launchToken(
+      6,
+      { name: "Test Token", symbol: "TEST", uri: "https://example.com/test.json" },
+      creator,
+      feePercentage,
+      new anchor.BN(500),              // initial quorum
+      new anchor.BN(1_000_000_000_000), // target liquidity
+      creator,                         // governance
+      500,                             // dao quorum
+      0,                               // linear curve
+      new anchor.BN(10_000_000_000),   // max token supply
+      new anchor.BN(60),               // liquidity lock period
+      50,                              // liquidity pool percentage
+      new anchor.BN(100),              // initial price
+      new anchor.BN(100_000_000_000),  // initial supply
+      recipients,
+      5000,                            // reserve ratio
+      launchFeeSchedule,
+    )
+    .accountsStrict({
+      bondingCurveConfiguration: curveConfig,
+      bondingCurveAccount: bondingCurve,
+      tokenMint: mint.publicKey,
+      poolTokenAccount,
+      poolSolVault,
+      feeVault,
+      tokenMetadata: null,
+      metadataProgram: null,
+      platformConfig: getPlatformConfigPDA(),
+      admin: creator,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([mint])
+    .rpc();
+
+  return { curveConfig, bondingCurve, poolSolVault, feeVault, poolTokenAccount };
+}
+
+// Buy `lamports` worth of a token launched with `launchTestToken`, creating the buyer ATA first
+export async function buyTestToken(
+  pool: { curveConfig: PublicKey, bondingCurve: PublicKey, poolSolVault: PublicKey, feeVault: PublicKey, poolTokenAccount: PublicKey },
+  mint: PublicKey,
+  buyer: Keypair,
+  lamports: number,
+  referralAccount: PublicKey | null = null,
+) {
+  const payer = (program.provider as AnchorProvider).wallet.payer;
+  const userTokenAccount = (await getOrCreateAssociatedTokenAccount(
+    program.provider.connection, payer, mint, buyer.publicKey, true, "confirmed", null, TOKEN_2022_PROGRAM_ID
+  )).address;
+
+  await program.methods
+    .buy(new anchor.BN(lamports))
+    .accountsStrict({
+      bondingCurveConfiguration: pool.curveConfig,
+      bondingCurveAccount: pool.bondingCurve,
+      tokenMint: mint,
+      poolTokenAccount: pool.poolTokenAccount,
+      poolSolVault: pool.poolSolVault,
+      feeVault: pool.feeVault,
+      platformConfig: getPlatformConfigPDA(),
+      referralAccount,
+      userTokenAccount,
+      user: buyer.publicKey,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([buyer])
+    .rpc();
+  return userTokenAccount;
+}
+
+// Send SOL from the provider wallet so test keypairs can pay for their own transactions
+export async function fundWallet(to: PublicKey, lamports = LAMPORTS_PER_SOL) {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({
+      fromPubkey: program.provider.publicKey,
+      toPubkey: to,
+      lamports,
+    })
+  );
+  await program.provider.sendAndConfirm(tx);
+}
+
 
 export function getWhitelistLaunchPDAs(authority: PublicKey, mint: PublicKey, buyer: PublicKey) {
 
   const [whitelistData] = PublicKey.findProgramAddressSync(
-    [Buffer.from(WHITELIST_DATA_SEED_PREFIX), authority.toBuffer()],
+    [Buffer.from(WHITELIST_DATA_SEED_PREFIX), mint.toBuffer()],
+    program.programId
+  );
+
+  const [whitelistVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from(WHITELIST_VAULT_SEED_PREFIX), whitelistData.toBuffer()],
     program.programId
   );
 
@@ -105,12 +268,177 @@ export function getWhitelistLaunchPDAs(authority: PublicKey, mint: PublicKey, bu
 
   return {
     whitelistData,
+    whitelistVault,
     launchpadTokenAccount,
     buyerAccount,
   };
 }
 
 
+// Create an SPL token whitelist launch owned by the provider wallet and deposit the full sale supply unless `fund` is false
+export async function createWhitelistTestLaunch(params: {
+  tokenPrice: number,
+  purchaseLimit: number,
+  totalSupply: number,
+  startTime: number,
+  endTime: number,
+  fund?: boolean,
+  decimals?: number,
+}) {
+  const provider = program.provider as AnchorProvider;
+  const payer = provider.wallet.payer;
+  const authority = provider.wallet.publicKey;
+
+  const mint = await createMint(provider.connection, payer, authority, null, params.decimals ?? 6);
+  const authorityTokenAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, authority)).address;
+  await mintTo(provider.connection, payer, mint, authorityTokenAccount, payer, params.totalSupply);
+
+  const { whitelistData, whitelistVault, launchpadTokenAccount } = getWhitelistLaunchPDAs(authority, mint, authority);
+  await program.methods
+    .createWhitelistLaunch(
+      new anchor.BN(params.tokenPrice),
+      new anchor.BN(params.purchaseLimit),
+      new anchor.BN(params.totalSupply),
+      new anchor.BN(3600),             // whitelist duration
+      new anchor.BN(params.startTime),
+      new anchor.BN(params.endTime),
+    )
+    .accountsStrict({
+      whitelistData,
+      tokenMint: mint,
+      launchpadVault: launchpadTokenAccount,
+      whitelistVault,
+      authority,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .rpc();
+
+  if (params.fund ?? true) {
+    await program.methods
+      .fundWhitelistLaunch(new anchor.BN(params.totalSupply))
+      .accountsStrict({
+        whitelistData,
+        tokenMint: mint,
+        launchpadVault: launchpadTokenAccount,
+        authorityTokenAccount,
+        authority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  return { mint, whitelistData, whitelistVault, launchpadTokenAccount, authorityTokenAccount };
+}
+
+// Create an SPL token fair launch owned by the provider wallet and deposit the tokens for sale unless `fund` is false.
+// The authority is minted twice the tokens for sale so it can also deposit liquidity tokens
+export async function createFairLaunchTestLaunch(params: {
+  softCap: number,
+  hardCap: number,
+  startTime: number,
+  endTime: number,
+  minContribution: number,
+  maxContribution: number,
+  maxTokensPerWallet: number,
+  totalTokens: number,
+  fund?: boolean,
+  decimals?: number,
+}) {
+  const provider = program.provider as AnchorProvider;
+  const payer = provider.wallet.payer;
+  const authority = provider.wallet.publicKey;
+
+  const mint = await createMint(provider.connection, payer, authority, null, params.decimals ?? 6);
+  const authorityTokenAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, authority)).address;
+  await mintTo(provider.connection, payer, mint, authorityTokenAccount, payer, BigInt(params.totalTokens) * BigInt(2));
+
+  const { fairLaunchData, contributionVault, launchpadTokenAccount } = getFairLaunchPDAs(mint, authority);
+  // the platform launch fee is snapshotted when the fair launch is created
+  await initializePlatformConfigIfNeeded(authority);
+  await program.methods
+    .createFairLaunch(
+      new anchor.BN(params.softCap),
+      new anchor.BN(params.hardCap),
+      new anchor.BN(params.startTime),
+      new anchor.BN(params.endTime),
+      new anchor.BN(params.minContribution),
+      new anchor.BN(params.maxContribution),
+      new anchor.BN(params.maxTokensPerWallet),
+      new anchor.BN(0),                // distribution delay
+      new anchor.BN(params.totalTokens),
+    )
+    .accountsStrict({
+      fairLaunchData,
+      tokenMint: mint,
+      launchpadVault: launchpadTokenAccount,
+      contributionVault,
+      platformConfig: getPlatformConfigPDA(),
+      authority,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .rpc();
+
+  if (params.fund ?? true) {
+    await program.methods
+      .fundFairLaunch(new anchor.BN(params.totalTokens))
+      .accountsStrict({
+        fairLaunchData,
+        tokenMint: mint,
+        launchpadVault: launchpadTokenAccount,
+        authorityTokenAccount,
+        authority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  return { mint, fairLaunchData, contributionVault, launchpadTokenAccount, authorityTokenAccount };
+}
+
+// Whitelist leaf: keccak256(wallet || allocation as little endian u64), as in utils/merkle.rs
+export function whitelistLeaf(wallet: PublicKey, allocation: number) {
+  return Buffer.from(keccak_256(Buffer.concat([wallet.toBuffer(), new anchor.BN(allocation).toArrayLike(Buffer, "le", 8)])));
+}
+
+// Merkle tree over whitelist leaves, each pair hashed in sorted order and an odd node carried up as is
+export function buildWhitelistMerkleTree(entries: { wallet: PublicKey, allocation: number }[]) {
+  const levels: Buffer[][] = [entries.map((e) => whitelistLeaf(e.wallet, e.allocation))];
+  while (levels[levels.length - 1].length > 1) {
+    const nodes = levels[levels.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < nodes.length; i += 2) {
+      if (i + 1 == nodes.length) {
+        next.push(nodes[i]);
+        continue;
+      }
+      const [a, b] = Buffer.compare(nodes[i], nodes[i + 1]) <= 0 ? [nodes[i], nodes[i + 1]] : [nodes[i + 1], nodes[i]];
+      next.push(Buffer.from(keccak_256(Buffer.concat([a, b]))));
+    }
+    levels.push(next);
+  }
+
+  const proofOf = (index: number) => {
+    const proof: number[][] = [];
+    for (const nodes of levels.slice(0, -1)) {
+      const sibling = index % 2 == 0 ? index + 1 : index - 1;
+      if (sibling < nodes.length) {
+        proof.push(Array.from(nodes[sibling]));
+      }
+      index = Math.floor(index / 2);
+    }
+    return proof;
+  };
+
+  return { root: Array.from(levels[levels.length - 1][0]), proofOf };
+}
+
+
 export function getFairLaunchPDAs(mint: PublicKey, buyer: PublicKey) {
 
   const [fairLaunchData] = PublicKey.findProgramAddressSync(
@@ -146,6 +474,162 @@ export function getFairLaunchPDAs(mint: PublicKey, buyer: PublicKey) {
   };
 }
 
+export function getDutchAuctionPDAs(mint: PublicKey, bidder: PublicKey) {
+  const [auctionData] = PublicKey.findProgramAddressSync(
+    [Buffer.from(DUTCH_AUCTION_DATA_SEED_PREFIX), mint.toBuffer()],
+    program.programId
+  );
+
+  const [contributionVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from(CONTRIBUTION_VAULT_SEED_PREFIX), auctionData.toBuffer()],
+    program.programId
+  );
+
+  const [buyerAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from(BUYER_SEED_PREFIX), auctionData.toBuffer(), bidder.toBuffer()],
+    program.programId
+  );
+
+  const launchpadTokenAccount = getAssociatedTokenAddressSync(mint, auctionData, true);
+
+  return { auctionData, contributionVault, buyerAccount, launchpadTokenAccount };
+}
+
+// Create an SPL token Dutch auction owned by the provider wallet and deposit the tokens for sale unless `fund` is false.
+// `decay` is `{ linear: {} }` or `{ exponential: { halfLife } }`
+export async function createDutchAuctionTestLaunch(params: {
+  startTime: number,
+  endTime: number,
+  startPrice: number,
+  floorPrice: number,
+  decay: any,
+  totalTokens: number,
+  fund?: boolean,
+  decimals?: number,
+}) {
+  const provider = program.provider as AnchorProvider;
+  const payer = provider.wallet.payer;
+  const authority = provider.wallet.publicKey;
+
+  const mint = await createMint(provider.connection, payer, authority, null, params.decimals ?? 6);
+  const authorityTokenAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, authority)).address;
+  await mintTo(provider.connection, payer, mint, authorityTokenAccount, payer, params.totalTokens);
+
+  const { auctionData, contributionVault, launchpadTokenAccount } = getDutchAuctionPDAs(mint, authority);
+  await program.methods
+    .createDutchAuction(
+      new anchor.BN(params.startTime),
+      new anchor.BN(params.endTime),
+      new anchor.BN(params.startPrice),
+      new anchor.BN(params.floorPrice),
+      params.decay,
+      new anchor.BN(params.totalTokens),
+    )
+    .accountsStrict({
+      auctionData,
+      tokenMint: mint,
+      launchpadVault: launchpadTokenAccount,
+      contributionVault,
+      authority,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+    })
+    .rpc();
+
+  if (params.fund ?? true) {
+    await program.methods
+      .fundDutchAuction(new anchor.BN(params.totalTokens))
+      .accountsStrict({
+        auctionData,
+        tokenMint: mint,
+        launchpadVault: launchpadTokenAccount,
+        authorityTokenAccount,
+        authority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  return { mint, auctionData, contributionVault, launchpadTokenAccount, authorityTokenAccount };
+}
+
+export function getBatchAuctionPDAs(mint: PublicKey, bidder: PublicKey) {
+  const [auctionData] = PublicKey.findProgramAddressSync(
+    [Buffer.from(BATCH_AUCTION_DATA_SEED_PREFIX), mint.toBuffer()],
+    program.programId
+  );
+
+  const [contributionVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from(CONTRIBUTION_VAULT_SEED_PREFIX), auctionData.toBuffer()],
+    program.programId
+  );
+
+  const [bid] = PublicKey.findProgramAddressSync(
+    [Buffer.from(BATCH_BID_SEED_PREFIX), auctionData.toBuffer(), bidder.toBuffer()],
+    program.programId
+  );
+
+  const launchpadTokenAccount = getAssociatedTokenAddressSync(mint, auctionData, true);
+
+  return { auctionData, contributionVault, bid, launchpadTokenAccount };
+}
+
+// Create an SPL token batch auction owned by the provider wallet and deposit the tokens for sale
+export async function createBatchAuctionTestLaunch(params: {
+  startTime: number,
+  endTime: number,
+  minPrice: number,
+  tickSize: number,
+  minBid: number,
+  totalTokens: number,
+  decimals?: number,
+}) {
+  const provider = program.provider as AnchorProvider;
+  const payer = provider.wallet.payer;
+  const authority = provider.wallet.publicKey;
+
+  const mint = await createMint(provider.connection, payer, authority, null, params.decimals ?? 6);
+  const authorityTokenAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, authority)).address;
+  await mintTo(provider.connection, payer, mint, authorityTokenAccount, payer, params.totalTokens);
+
+  const { auctionData, contributionVault, launchpadTokenAccount } = getBatchAuctionPDAs(mint, authority);
+  await program.methods
+    .createBatchAuction(
+      new anchor.BN(params.startTime),
+      new anchor.BN(params.endTime),
+      new anchor.BN(params.minPrice),
+      new anchor.BN(params.tickSize),
+      new anchor.BN(params.minBid),
+      new anchor.BN(params.totalTokens),
+    )
+    .accountsStrict({
+      auctionData,
+      tokenMint: mint,
+      launchpadVault: launchpadTokenAccount,
+      contributionVault,
+      authority,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+    })
+    .rpc();
+
+  await program.methods
+    .fundBatchAuction(new anchor.BN(params.totalTokens))
+    .accountsStrict({
+      auctionData,
+      tokenMint: mint,
+      launchpadVault: launchpadTokenAccount,
+      authorityTokenAccount,
+      authority,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .rpc();
+
+  return { mint, auctionData, contributionVault, launchpadTokenAccount, authorityTokenAccount };
+}
+
 export function getHybridLaunchPDAs(authority: PublicKey, mint: PublicKey, buyer: PublicKey) {
   const [launchpad] = PublicKey.findProgramAddressSync(
     [Buffer.from(LAUNCHPAD_SEED_PREFIX), authority.toBuffer()],
@@ -210,13 +694,21 @@ export function getPauseLaunchPDAs(authority: PublicKey) {
 }
 
 
+export function getTokenomicsPlanPDA(mint: PublicKey) {
+  const [tokenomicsPlan] = PublicKey.findProgramAddressSync(
+    [Buffer.from(TOKENOMICS_PLAN_SEED_PREFIX), mint.toBuffer()],
+    program.programId
+  );
+  return tokenomicsPlan;
+}
+
 export function getAllocationPDAs(mint: PublicKey, wallet: PublicKey[]) {
   let allocations = []
   let allocationTokenAccounts = []
   let userTokenAccounts = []
   for (let i = 0; i < wallet.length; i++) {
     const [allocation] = PublicKey.findProgramAddressSync(
-      [Buffer.from(ALLOCATION_SEED_PREFIX), wallet[i].toBuffer()],
+      [Buffer.from(ALLOCATION_SEED_PREFIX), wallet[i].toBuffer(), mint.toBuffer()],
       program.programId
     );
     allocations.push(allocation)
@@ -0,0 +1,163 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js"
+import { BN } from "bn.js";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { expect } from "chai";
+import { buildWhitelistMerkleTree, createWhitelistTestLaunch, fundWallet, getWhitelistLaunchPDAs } from "./utils";
+
+describe("merkle-root whitelist", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const buyers = [Keypair.generate(), Keypair.generate()];
+  const allocations = [1_000_000, 500_000];
+  // filler wallets so the tree has several levels and an odd node
+  const entries = [
+    ...buyers.map((b, i) => ({ wallet: b.publicKey, allocation: allocations[i] })),
+    ...Array.from({ length: 3 }, () => ({ wallet: Keypair.generate().publicKey, allocation: 1_000_000 })),
+  ];
+  const tree = buildWhitelistMerkleTree(entries);
+
+  let launch: Awaited<ReturnType<typeof createWhitelistTestLaunch>>;
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function setRoot(root: number[] | null, authority: Keypair = signer.payer) {
+    return program.methods
+      .setWhitelistMerkleRoot(root)
+      .accountsStrict({ whitelistData: launch.whitelistData, authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+  }
+
+  function purchase(buyer: Keypair, amount: number, proof: { allocation: number, proof: number[][] } | null) {
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, buyer.publicKey);
+    return program.methods
+      .purchaseWhitelist(new BN(amount), proof && { allocation: new BN(proof.allocation), proof: proof.proof })
+      .accountsStrict({
+        whitelistData: launch.whitelistData,
+        buyerAccount,
+        tokenMint: launch.mint,
+        whitelistVault: launch.whitelistVault,
+        buyer: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  const proofOf = (i: number) => ({ allocation: allocations[i], proof: tree.proofOf(i) });
+
+  before(async () => {
+    for (const buyer of buyers) {
+      await fundWallet(buyer.publicKey, 0.5 * LAMPORTS_PER_SOL);
+    }
+    const now = Math.floor(Date.now() / 1000);
+    launch = await createWhitelistTestLaunch({
+      tokenPrice: 1_000_000,
+      purchaseLimit: 10_000_000,
+      totalSupply: 10_000_000,
+      startTime: now + 10,
+      endTime: now + 60,
+    });
+  });
+
+  it("only lets the authority set the root before the sale", async () => {
+    await expectError(setRoot(tree.root, buyers[0]), "InvalidAuthority");
+    await setRoot(tree.root);
+
+    const data = await program.account.whitelistLaunchData.fetch(launch.whitelistData);
+    expect(data.merkleRoot).to.deep.equal(tree.root);
+  });
+
+  it("rejects purchases without a valid proof", async () => {
+    const { startTime } = await program.account.whitelistLaunchData.fetch(launch.whitelistData);
+    await sleep(startTime.toNumber() * 1000 + 1000 - Date.now());
+    await expectError(purchase(buyers[0], 1, null), "InvalidMerkleProof");
+    // a proof for a larger allocation than the leaf
+    await expectError(purchase(buyers[0], 1, { ...proofOf(0), allocation: allocations[0] * 2 }), "InvalidMerkleProof");
+    // another wallet's proof
+    await expectError(purchase(buyers[0], 1, proofOf(1)), "InvalidMerkleProof");
+  });
+
+  it("caps each buyer at the allocation in their leaf without add_whitelist", async () => {
+    await purchase(buyers[0], allocations[0], proofOf(0));
+    await expectError(purchase(buyers[0], 1, proofOf(0)), "PurchaseLimitExceeded");
+
+    await expectError(purchase(buyers[1], allocations[1] + 1, proofOf(1)), "PurchaseLimitExceeded");
+    await purchase(buyers[1], allocations[1], proofOf(1));
+
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, buyers[1].publicKey);
+    const account = await program.account.buyerAccount.fetch(buyerAccount);
+    expect(account.buyer.toBase58()).to.equal(buyers[1].publicKey.toBase58());
+    expect(account.amount.toNumber()).to.equal(allocations[1]);
+  });
+
+  it("freezes the root once the sale started", async () => {
+    await expectError(setRoot(null), "WhitelistDurationOver");
+  });
+
+  it("takes purchases from more buyers than the sale account could list", async () => {
+    // the sale account is 5000 bytes, a list of buyers would overflow it after about 140 of them
+    const crowd = Array.from({ length: 160 }, () => Keypair.generate());
+    const allocation = 1_000;
+    const crowdTree = buildWhitelistMerkleTree(crowd.map((b) => ({ wallet: b.publicKey, allocation })));
+    const now = Math.floor(Date.now() / 1000);
+    const large = await createWhitelistTestLaunch({
+      tokenPrice: 1_000_000,
+      purchaseLimit: allocation,
+      totalSupply: crowd.length * allocation,
+      startTime: now + 20,
+      endTime: now + 600,
+    });
+    await program.methods
+      .setWhitelistMerkleRoot(crowdTree.root)
+      .accountsStrict({ whitelistData: large.whitelistData, authority: signer.publicKey })
+      .rpc();
+
+    for (let i = 0; i < crowd.length; i += 20) {
+      const tx = new Transaction();
+      for (const buyer of crowd.slice(i, i + 20)) {
+        tx.add(SystemProgram.transfer({ fromPubkey: signer.publicKey, toPubkey: buyer.publicKey, lamports: 0.01 * LAMPORTS_PER_SOL }));
+      }
+      await provider.sendAndConfirm(tx);
+    }
+
+    await sleep((now + 21) * 1000 - Date.now());
+    for (let i = 0; i < crowd.length; i += 20) {
+      await Promise.all(crowd.slice(i, i + 20).map((buyer, j) => {
+        const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, large.mint, buyer.publicKey);
+        return program.methods
+          .purchaseWhitelist(new BN(allocation), { allocation: new BN(allocation), proof: crowdTree.proofOf(i + j) })
+          .accountsStrict({
+            whitelistData: large.whitelistData,
+            buyerAccount,
+            tokenMint: large.mint,
+            whitelistVault: large.whitelistVault,
+            buyer: buyer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+      }));
+    }
+
+    const data = await program.account.whitelistLaunchData.fetch(large.whitelistData);
+    expect(data.soldTokens.toNumber()).to.equal(crowd.length * allocation);
+    expect(data.buyers.length).to.equal(0);
+  });
+});
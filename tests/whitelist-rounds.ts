@@ -0,0 +1,168 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js"
+import { BN } from "bn.js";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { expect } from "chai";
+import { buildWhitelistMerkleTree, createWhitelistTestLaunch, fundWallet, getWhitelistLaunchPDAs } from "./utils";
+
+describe("tiered sale rounds", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const guaranteed = Keypair.generate();  // tier 1
+  const fcfs = Keypair.generate();        // tier 2, only in the merkle tree
+  const publicBuyer = Keypair.generate(); // never whitelisted
+  const decimals = 6;
+  const fcfsAllocation = 400_000;
+  const tree = buildWhitelistMerkleTree([
+    { wallet: fcfs.publicKey, allocation: fcfsAllocation },
+    { wallet: Keypair.generate().publicKey, allocation: fcfsAllocation },
+  ]);
+
+  let launch: Awaited<ReturnType<typeof createWhitelistTestLaunch>>;
+  let rounds: {
+    startTime: BN, endTime: BN, tokenPrice: BN, purchaseLimitPerWallet: BN, eligibility: object, soldTokens: BN,
+  }[];
+
+  const sleepUntil = async (timestamp: number) => {
+    const wait = timestamp * 1000 - Date.now();
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+  const costOf = (amount: number, price: BN) => Math.floor((amount * price.toNumber()) / 10 ** decimals);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function setSaleRounds(value: typeof rounds) {
+    return program.methods
+      .setSaleRounds(value as any)
+      .accountsStrict({ whitelistData: launch.whitelistData, authority: signer.publicKey })
+      .rpc();
+  }
+
+  function setBuyerTier(buyer: Keypair, tier: number) {
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, buyer.publicKey);
+    return program.methods
+      .setBuyerTier(tier)
+      .accountsStrict({
+        whitelistData: launch.whitelistData,
+        buyerAccount,
+        user: buyer.publicKey,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  async function purchase(buyer: Keypair, amount: number, proof: { allocation: number, proof: number[][] } | null = null) {
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, buyer.publicKey);
+    const vaultBefore = await connection.getBalance(launch.whitelistVault);
+    await program.methods
+      .purchaseWhitelist(new BN(amount), proof && { allocation: new BN(proof.allocation), proof: proof.proof })
+      .accountsStrict({
+        whitelistData: launch.whitelistData,
+        buyerAccount,
+        tokenMint: launch.mint,
+        whitelistVault: launch.whitelistVault,
+        buyer: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+    return (await connection.getBalance(launch.whitelistVault)) - vaultBefore;
+  }
+
+  before(async () => {
+    for (const buyer of [guaranteed, fcfs, publicBuyer]) {
+      await fundWallet(buyer.publicKey, 0.5 * LAMPORTS_PER_SOL);
+    }
+    const now = Math.floor(Date.now() / 1000);
+    launch = await createWhitelistTestLaunch({
+      tokenPrice: 1_000_000,
+      purchaseLimit: 10_000_000,
+      totalSupply: 10_000_000,
+      startTime: now + 30,
+      endTime: now + 600,
+      decimals,
+    });
+    await setBuyerTier(guaranteed, 1);
+    await setBuyerTier(fcfs, 2);
+
+    const start = Math.floor(Date.now() / 1000) + 8;
+    const round = (offset: number, price: number, limit: number, eligibility: object) => ({
+      startTime: new BN(start + offset),
+      endTime: new BN(start + offset + 6),
+      tokenPrice: new BN(price),
+      purchaseLimitPerWallet: new BN(limit),
+      eligibility,
+      soldTokens: new BN(0),
+    });
+    rounds = [
+      round(0, 1_000_000, 500_000, { tier: { tier: 1 } }),
+      round(8, 1_500_000, 300_000, { merkleRoot: { root: tree.root } }),
+      round(16, 2_000_000, 200_000, { public: {} }),
+    ];
+  });
+
+  it("rejects overlapping rounds", async () => {
+    await expectError(setSaleRounds([rounds[0], { ...rounds[1], startTime: rounds[0].endTime }]), "InvalidSaleRounds");
+  });
+
+  it("spans the sale window over the rounds", async () => {
+    await setSaleRounds(rounds);
+    const data = await program.account.whitelistLaunchData.fetch(launch.whitelistData);
+    expect(data.rounds.length).to.equal(3);
+    expect(data.startTime.toNumber()).to.equal(rounds[0].startTime.toNumber());
+    expect(data.endTime.toNumber()).to.equal(rounds[2].endTime.toNumber());
+  });
+
+  it("sells the guaranteed round to its tier at the round price and cap", async () => {
+    await sleepUntil(rounds[0].startTime.toNumber() + 1);
+    await expectError(purchase(fcfs, 1), "NotWhitelisted");
+    await expectError(purchase(publicBuyer, 1), "NotWhitelisted");
+    await expectError(purchase(guaranteed, 500_001), "PurchaseLimitExceeded");
+
+    expect(await purchase(guaranteed, 500_000)).to.equal(costOf(500_000, rounds[0].tokenPrice));
+    await expectError(setSaleRounds(rounds), "SaleAlreadyStarted");
+  });
+
+  it("caps the merkle round at the lower of the round limit and the leaf allocation", async () => {
+    await sleepUntil(rounds[1].startTime.toNumber() + 1);
+    const proof = { allocation: fcfsAllocation, proof: tree.proofOf(0) };
+    await expectError(purchase(guaranteed, 1), "InvalidMerkleProof");
+    await expectError(purchase(fcfs, 300_001, proof), "PurchaseLimitExceeded");
+
+    expect(await purchase(fcfs, 300_000, proof)).to.equal(costOf(300_000, rounds[1].tokenPrice));
+  });
+
+  it("opens the public round to everyone with a fresh per wallet cap", async () => {
+    await sleepUntil(rounds[2].startTime.toNumber() + 1);
+    expect(await purchase(publicBuyer, 200_000)).to.equal(costOf(200_000, rounds[2].tokenPrice));
+    // the guaranteed buyer maxed out the first round but can still buy here
+    expect(await purchase(guaranteed, 200_000)).to.equal(costOf(200_000, rounds[2].tokenPrice));
+    await expectError(purchase(publicBuyer, 1), "PurchaseLimitExceeded");
+
+    const data = await program.account.whitelistLaunchData.fetch(launch.whitelistData);
+    expect(data.rounds.map((r) => r.soldTokens.toNumber())).to.deep.equal([500_000, 300_000, 400_000]);
+    expect(data.soldTokens.toNumber()).to.equal(1_200_000);
+
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, launch.mint, guaranteed.publicKey);
+    const account = await program.account.buyerAccount.fetch(buyerAccount);
+    expect(account.amount.toNumber()).to.equal(700_000);
+    expect(account.roundPurchased.slice(0, 3).map((n) => n.toNumber())).to.deep.equal([500_000, 0, 200_000]);
+  });
+});
@@ -0,0 +1,192 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, Program } from "@coral-xyz/anchor";
+import { BondingCurve } from "../target/types/bonding_curve"
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js"
+import { BN } from "bn.js";
+import { ASSOCIATED_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { createMint, getAccount, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { fundWallet, getWhitelistLaunchPDAs } from "./utils";
+
+describe("whitelist sale", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const signer = provider.wallet as NodeWallet;
+  const connection = provider.connection;
+  const program = anchor.workspace.BondingCurve as Program<BondingCurve>;
+
+  const buyer = Keypair.generate();
+  const outsider = Keypair.generate();
+  const decimals = 6;
+  const tokenPrice = 1_000_000;               // lamports per whole token
+  const purchaseLimit = 2_000_000;            // 2 tokens per wallet
+  const totalSupply = 10_000_000;             // 10 tokens for sale
+  // 0.1 token costs 100_000 lamports, well below the rent-exempt minimum of an empty account
+  const firstPurchase = 100_000;
+
+  let mint: PublicKey;
+  let pdas: ReturnType<typeof getWhitelistLaunchPDAs>;
+  let startTime: number;
+  let endTime: number;
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+  const costOf = (amount: number) => Math.floor((amount * tokenPrice) / 10 ** decimals);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (error) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  }
+
+  function purchase(wallet: Keypair, amount: number) {
+    const { buyerAccount } = getWhitelistLaunchPDAs(signer.publicKey, mint, wallet.publicKey);
+    return program.methods
+      .purchaseWhitelist(new BN(amount), null)
+      .accountsStrict({
+        whitelistData: pdas.whitelistData,
+        buyerAccount,
+        tokenMint: mint,
+        whitelistVault: pdas.whitelistVault,
+        buyer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([wallet])
+      .rpc();
+  }
+
+  async function claim() {
+    const buyerTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, buyer.publicKey);
+    await program.methods
+      .claimWhitelistTokens()
+      .accountsStrict({
+        whitelistData: pdas.whitelistData,
+        buyerAccount: pdas.buyerAccount,
+        tokenMint: mint,
+        launchpadVault: pdas.launchpadTokenAccount,
+        buyerTokenAccount: buyerTokenAccount.address,
+        buyer: buyer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([buyer])
+      .rpc();
+    return buyerTokenAccount.address;
+  }
+
+  before(async () => {
+    await fundWallet(buyer.publicKey);
+    await fundWallet(outsider.publicKey, 0.1 * LAMPORTS_PER_SOL);
+
+    mint = await createMint(connection, signer.payer, signer.publicKey, null, decimals);
+    const authorityTokenAccount = await getOrCreateAssociatedTokenAccount(connection, signer.payer, mint, signer.publicKey);
+    await mintTo(connection, signer.payer, mint, authorityTokenAccount.address, signer.payer, totalSupply);
+
+    pdas = getWhitelistLaunchPDAs(signer.publicKey, mint, buyer.publicKey);
+    const now = Math.floor(Date.now() / 1000);
+    startTime = now + 6;
+    endTime = now + 15;
+    await program.methods
+      .createWhitelistLaunch(
+        new BN(tokenPrice),
+        new BN(purchaseLimit),
+        new BN(totalSupply),
+        new BN(3600),
+        new BN(startTime),
+        new BN(endTime),
+      )
+      .accountsStrict({
+        whitelistData: pdas.whitelistData,
+        tokenMint: mint,
+        launchpadVault: pdas.launchpadTokenAccount,
+        whitelistVault: pdas.whitelistVault,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    await program.methods
+      .fundWhitelistLaunch(new BN(totalSupply))
+      .accountsStrict({
+        whitelistData: pdas.whitelistData,
+        tokenMint: mint,
+        launchpadVault: pdas.launchpadTokenAccount,
+        authorityTokenAccount: authorityTokenAccount.address,
+        authority: signer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    await program.methods
+      .addWhitelist(buyer.publicKey)
+      .accountsStrict({
+        whitelistData: pdas.whitelistData,
+        authority: signer.publicKey,
+        buyerAccount: pdas.buyerAccount,
+        user: buyer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("funds the SOL vault to the rent-exempt minimum at creation", async () => {
+    const rent = await connection.getMinimumBalanceForRentExemption(0);
+    expect(await connection.getBalance(pdas.whitelistVault)).to.equal(rent);
+  });
+
+  it("rejects a purchase before the sale starts", async () => {
+    await expectError(purchase(buyer, firstPurchase), "SaleNotStarted");
+  });
+
+  it("accepts a first purchase below the rent-exempt minimum", async () => {
+    await sleep((startTime + 1) * 1000 - Date.now());
+    const vaultBefore = await connection.getBalance(pdas.whitelistVault);
+    await purchase(buyer, firstPurchase);
+
+    expect((await connection.getBalance(pdas.whitelistVault)) - vaultBefore).to.equal(costOf(firstPurchase));
+    const account = await program.account.buyerAccount.fetch(pdas.buyerAccount);
+    expect(account.amount.toNumber()).to.equal(firstPurchase);
+    expect(account.committed.toNumber()).to.equal(costOf(firstPurchase));
+  });
+
+  it("enforces the per wallet limit and the whitelist", async () => {
+    await expectError(purchase(buyer, purchaseLimit - firstPurchase + 1), "PurchaseLimitExceeded");
+    await expectError(purchase(outsider, firstPurchase), "NotWhitelisted");
+
+    await purchase(buyer, purchaseLimit - firstPurchase);
+    const data = await program.account.whitelistLaunchData.fetch(pdas.whitelistData);
+    expect(data.soldTokens.toNumber()).to.equal(purchaseLimit);
+  });
+
+  it("releases the tokens and the proceeds once the sale ended", async () => {
+    await expectError(claim(), "SaleNotEnded");
+    await sleep((endTime + 2) * 1000 - Date.now());
+
+    const buyerTokenAccount = await claim();
+    expect(Number((await getAccount(connection, buyerTokenAccount)).amount)).to.equal(purchaseLimit);
+    await expectError(claim(), "AlreadyClaimed");
+
+    const vault = await connection.getBalance(pdas.whitelistVault);
+    const authorityBefore = await connection.getBalance(signer.publicKey);
+    await program.methods
+      .withdrawWhitelistProceeds()
+      .accountsStrict({
+        whitelistData: pdas.whitelistData,
+        whitelistVault: pdas.whitelistVault,
+        authority: signer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // the proceeds come back together with the vault rent the authority put up
+    expect(await connection.getBalance(pdas.whitelistVault)).to.equal(0);
+    expect(vault).to.equal(costOf(purchaseLimit) + (await connection.getMinimumBalanceForRentExemption(0)));
+    expect((await connection.getBalance(signer.publicKey)) - authorityBefore).to.be.greaterThan(vault - 10_000);
+  });
+});